@@ -0,0 +1,8 @@
+{
+  "targets": [
+    {
+      "target_name": "sochdb_external_memory",
+      "sources": ["external_memory.c"]
+    }
+  ]
+}
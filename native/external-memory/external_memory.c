@@ -0,0 +1,41 @@
+/*
+ * V8 external memory shim
+ *
+ * The storage engine is loaded through koffi, which has no napi_env, so its
+ * allocations are invisible to V8. This addon exposes
+ * napi_adjust_external_memory so the SDK can report them: they then show up
+ * in process.memoryUsage().external and count towards GC pressure.
+ */
+
+#include <node_api.h>
+
+/* adjust(deltaBytes: number): number - returns V8's new external total */
+static napi_value Adjust(napi_env env, napi_callback_info info) {
+    size_t argc = 1;
+    napi_value argv[1];
+    int64_t delta = 0;
+    int64_t total = 0;
+    napi_value result;
+
+    if (napi_get_cb_info(env, info, &argc, argv, NULL, NULL) != napi_ok || argc < 1) {
+        napi_throw_type_error(env, NULL, "adjust(deltaBytes) expects one argument");
+        return NULL;
+    }
+    if (napi_get_value_int64(env, argv[0], &delta) != napi_ok) {
+        napi_throw_type_error(env, NULL, "deltaBytes must be a number");
+        return NULL;
+    }
+    if (napi_adjust_external_memory(env, delta, &total) != napi_ok) {
+        napi_throw_error(env, NULL, "napi_adjust_external_memory failed");
+        return NULL;
+    }
+    napi_create_double(env, (double)total, &result);
+    return result;
+}
+
+NAPI_MODULE_INIT() {
+    napi_value fn;
+    napi_create_function(env, "adjust", NAPI_AUTO_LENGTH, Adjust, NULL, &fn);
+    napi_set_named_property(env, exports, "adjust", fn);
+    return exports;
+}
@@ -20,6 +20,8 @@ import * as path from 'path';
 import { DatabaseError, TransactionError } from './errors';
 import { IpcClient } from './ipc-client';
 import { Query } from './query';
+import { validatePath } from './embedded/path';
+import { Clock, systemClock } from './embedded/clock';
 import { startEmbeddedServer, stopEmbeddedServer } from './server-manager';
 
 /**
@@ -41,6 +43,8 @@ export interface DatabaseConfig {
    * Set to false if connecting to an existing external server
    */
   embedded?: boolean;
+  /** Time source for cache TTL expiry and trace timestamps (default: system clock) */
+  clock?: Clock;
 }
 
 /**
@@ -279,6 +283,13 @@ export class Database {
     return db;
   }
 
+  /**
+   * Current time in milliseconds from the configured clock
+   */
+  now(): number {
+    return (this._config.clock ?? systemClock).now();
+  }
+
   /**
    * Get a value by key.
    *
@@ -329,11 +340,16 @@ export class Database {
   /**
    * Put a value at a path.
    *
+   * The path must pass `validatePath`: paths with a leading "/", an empty
+   * segment or a literal "%" are rejected with InvalidPathError (they were
+   * accepted before 0.5). Build paths with `PathBuilder` to escape segments.
+   *
    * @param pathStr - The path (e.g., "users/alice/email")
    * @param value - The value (Buffer or string)
    */
   async putPath(pathStr: string, value: Buffer | string): Promise<void> {
     this._ensureOpen();
+    validatePath(pathStr);
     const valueBuf = typeof value === 'string' ? Buffer.from(value) : value;
     return this._client!.putPath(pathStr, valueBuf);
   }
@@ -823,7 +839,7 @@ export class Database {
     const cacheKey = `_cache/${cacheName}/${keyHash}`;
 
     const expiresAt = ttlSeconds > 0
-      ? Math.floor(Date.now() / 1000) + ttlSeconds
+      ? Math.floor(this.now() / 1000) + ttlSeconds
       : 0;
 
     const cacheValue = JSON.stringify({
@@ -866,7 +882,7 @@ export class Database {
     const prefix = `_cache/${cacheName}/`;
     const entries = await this.scan(prefix);
 
-    const now = Math.floor(Date.now() / 1000);
+    const now = Math.floor(this.now() / 1000);
     let bestMatch: { similarity: number; value: string } | null = null;
 
     for (const { value } of entries) {
@@ -925,7 +941,7 @@ export class Database {
 
     const traceId = `trace_${Date.now().toString(16)}${Math.random().toString(16).slice(2, 10)}`;
     const spanId = `span_${Date.now().toString(16)}${Math.random().toString(16).slice(2, 10)}`;
-    const now = Date.now() * 1000; // microseconds
+    const now = this.now() * 1000; // microseconds
 
     // Store trace
     const traceKey = `_traces/${traceId}`;
@@ -975,7 +991,7 @@ export class Database {
     this._ensureOpen();
 
     const spanId = `span_${Date.now().toString(16)}${Math.random().toString(16).slice(2, 10)}`;
-    const now = Date.now() * 1000;
+    const now = this.now() * 1000;
 
     const spanKey = `_traces/${traceId}/spans/${spanId}`;
     const spanValue = JSON.stringify({
@@ -1019,7 +1035,7 @@ export class Database {
     }
 
     const span = JSON.parse(spanData.toString());
-    const now = Date.now() * 1000;
+    const now = this.now() * 1000;
     const duration = now - span.start_us;
 
     const updatedSpan = {
@@ -0,0 +1,111 @@
+/**
+ * Soft-Limit Alerts - Embedded Mode
+ *
+ * Samples resource usage on a timer and emits an `'alert'` event on the
+ * database when a configured threshold is crossed (and again when usage
+ * drops back below it), so applications can shed load or page someone
+ * before the engine hits a hard limit.
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { DatabaseError } from '../errors';
+
+export type AlertMetric = 'walSizeBytes' | 'diskUsageBytes' | 'activeTransactions' | 'memtableSizeBytes';
+
+/** Soft limit per metric; unset metrics are not watched */
+export type AlertThresholds = Partial<Record<AlertMetric, number>>;
+
+export type AlertSample = Record<AlertMetric, number>;
+
+export interface AlertConfig {
+    thresholds: AlertThresholds;
+    /** How often usage is sampled (default: 5000ms) */
+    intervalMs?: number;
+}
+
+/**
+ * Payload of the database's `'alert'` event
+ */
+export interface AlertEvent {
+    metric: AlertMetric;
+    /** `raised` when usage reaches the threshold, `cleared` when it drops back below */
+    state: 'raised' | 'cleared';
+    value: number;
+    threshold: number;
+    /** Database path */
+    database: string;
+    /** Every metric from the same sample, for context */
+    sample: AlertSample;
+    /** Time of the sample, per the database clock */
+    timestamp: number;
+}
+
+export type AlertCallback = (event: AlertEvent) => void;
+
+export const DEFAULT_ALERT_INTERVAL_MS = 5000;
+
+/**
+ * Validate an alert sampling `intervalMs`
+ * @internal
+ */
+export function resolveAlertInterval(intervalMs?: number): number {
+    if (intervalMs === undefined) {
+        return DEFAULT_ALERT_INTERVAL_MS;
+    }
+    if (!Number.isFinite(intervalMs) || intervalMs <= 0) {
+        throw new DatabaseError(`intervalMs must be a positive number, got ${intervalMs}`);
+    }
+    return intervalMs;
+}
+
+/**
+ * Tracks which thresholds are currently exceeded and reports transitions
+ * @internal
+ */
+export class AlertTracker {
+    private raised = new Set<AlertMetric>();
+
+    constructor(public thresholds: AlertThresholds) {}
+
+    /**
+     * Compare a sample with the thresholds; each crossing is reported once
+     */
+    update(sample: AlertSample): Array<Pick<AlertEvent, 'metric' | 'state' | 'value' | 'threshold'>> {
+        const transitions: Array<Pick<AlertEvent, 'metric' | 'state' | 'value' | 'threshold'>> = [];
+        for (const [metric, threshold] of Object.entries(this.thresholds) as Array<[AlertMetric, number | undefined]>) {
+            if (threshold === undefined) continue;
+            const value = sample[metric];
+            const exceeded = value >= threshold;
+            if (exceeded && !this.raised.has(metric)) {
+                this.raised.add(metric);
+                transitions.push({ metric, state: 'raised', value, threshold });
+            } else if (!exceeded && this.raised.has(metric)) {
+                this.raised.delete(metric);
+                transitions.push({ metric, state: 'cleared', value, threshold });
+            }
+        }
+        // Forget metrics that are no longer watched
+        for (const metric of this.raised) {
+            if (this.thresholds[metric] === undefined) this.raised.delete(metric);
+        }
+        return transitions;
+    }
+}
+
+/**
+ * Total size of the files below `dir`
+ * @internal
+ */
+export async function directorySize(dir: string): Promise<number> {
+    let total = 0;
+    for (const entry of await fs.promises.readdir(dir, { withFileTypes: true })) {
+        const full = path.join(dir, entry.name);
+        if (entry.isDirectory()) {
+            total += await directorySize(full);
+        } else if (entry.isFile()) {
+            total += (await fs.promises.stat(full)).size;
+        }
+    }
+    return total;
+}
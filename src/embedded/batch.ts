@@ -0,0 +1,105 @@
+/**
+ * Write Batch - Embedded Mode
+ *
+ * Buffers puts and deletes (optionally targeting different keyspaces) and
+ * applies them in a single transaction, so either all of them become
+ * visible or none do.
+ */
+
+import { TransactionError } from '../errors';
+import type { EmbeddedDatabase, PutOptions } from './database';
+import type { Keyspace } from './keyspace';
+import { BytesLike, toBuffer } from './key-encoding';
+
+type BatchOp =
+    | { type: 'put'; key: Buffer; value: Buffer; options?: PutOptions }
+    | { type: 'delete'; key: Buffer };
+
+/**
+ * Atomic batch of writes
+ *
+ * @example
+ * ```typescript
+ * const batch = db.batch();
+ * batch.put(Buffer.from('img:1'), data, { keyspace: blobs });
+ * batch.put(Buffer.from('by_name:cat'), Buffer.from('img:1'), { keyspace: index });
+ * batch.delete(Buffer.from('pending:img:1'));
+ * await batch.write();
+ * ```
+ */
+export class WriteBatch {
+    private db: EmbeddedDatabase;
+    private ops: BatchOp[] = [];
+    private written = false;
+
+    constructor(db: EmbeddedDatabase) {
+        this.db = db;
+    }
+
+    /**
+     * Queue a put, optionally into a keyspace
+     */
+    put(key: BytesLike, value: BytesLike, options?: PutOptions & { keyspace?: Keyspace }): this {
+        this.ensurePending();
+        const { keyspace, ...putOptions } = options ?? {};
+        this.ops.push({
+            type: 'put',
+            key: keyspace ? keyspace.encodeKey(key) : toBuffer(key),
+            value: toBuffer(value),
+            options: putOptions,
+        });
+        return this;
+    }
+
+    /**
+     * Queue a delete, optionally from a keyspace
+     */
+    delete(key: BytesLike, options?: { keyspace?: Keyspace }): this {
+        this.ensurePending();
+        this.ops.push({
+            type: 'delete',
+            key: options?.keyspace ? options.keyspace.encodeKey(key) : toBuffer(key),
+        });
+        return this;
+    }
+
+    /**
+     * Number of queued operations
+     */
+    get length(): number {
+        return this.ops.length;
+    }
+
+    /**
+     * Discard all queued operations
+     */
+    clear(): void {
+        this.ensurePending();
+        this.ops = [];
+    }
+
+    /**
+     * Apply every queued operation in one transaction
+     */
+    async write(): Promise<void> {
+        this.ensurePending();
+
+        await this.db.withTransaction(async (txn) => {
+            for (const op of this.ops) {
+                if (op.type === 'put') {
+                    await txn.put(op.key, op.value, op.options);
+                } else {
+                    await txn.delete(op.key);
+                }
+            }
+        });
+        // Only a batch that committed is spent; a failed one can be retried
+        this.written = true;
+    }
+
+    private ensurePending(): void {
+        if (this.written) {
+            throw new TransactionError('Write batch has already been written');
+        }
+    }
+}
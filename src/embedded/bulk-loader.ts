@@ -0,0 +1,104 @@
+/**
+ * Bulk Loader - Embedded Mode
+ *
+ * Writes presorted entries directly into SST files and installs them in
+ * one step, bypassing the memtable and WAL. Much faster than `put` for
+ * initial loads, but entries must arrive in strictly increasing key order.
+ */
+
+import { DatabaseError } from '../errors';
+import { NativeBindings } from './ffi/bindings';
+import { BytesLike, toBuffer } from './key-encoding';
+import { SizeLimits, checkEntrySize } from './limits';
+
+export interface BulkLoadResult {
+    /** Entries written */
+    count: number;
+}
+
+/**
+ * Streaming loader for presorted data
+ *
+ * @example
+ * ```typescript
+ * const loader = db.bulkLoader();
+ * try {
+ *     for (const [key, value] of sortedEntries) {
+ *         loader.add(key, value);
+ *     }
+ *     const { count } = loader.finish();
+ * } catch (error) {
+ *     loader.abort();
+ *     throw error;
+ * }
+ * ```
+ */
+export class BulkLoader {
+    private bindings: NativeBindings;
+    private handle: any;
+    private sizeLimits: SizeLimits;
+    private lastKey: Buffer | null = null;
+    private count = 0;
+    private done = false;
+
+    /**
+     * @internal
+     */
+    constructor(bindings: NativeBindings, handle: any, sizeLimits: SizeLimits) {
+        this.bindings = bindings;
+        this.handle = handle;
+        this.sizeLimits = sizeLimits;
+    }
+
+    /**
+     * Add the next entry; keys must be strictly increasing
+     */
+    add(keyLike: BytesLike, valueLike: BytesLike): void {
+        this.ensurePending();
+        const key = toBuffer(keyLike);
+        const value = toBuffer(valueLike);
+        checkEntrySize(key, value, this.sizeLimits);
+
+        if (this.lastKey && Buffer.compare(this.lastKey, key) >= 0) {
+            throw new DatabaseError(
+                `Bulk load keys must be strictly increasing: '${key.toString()}' after '${this.lastKey.toString()}'`
+            );
+        }
+
+        const res = this.bindings.sochdb_bulk_loader_add(this.handle, key, key.length, value, value.length);
+        if (res !== 0) {
+            throw new DatabaseError(`Bulk load failed at key '${key.toString()}' (Code ${res})`);
+        }
+        this.lastKey = key;
+        this.count++;
+    }
+
+    /**
+     * Finalize the SST files and install them into the database
+     */
+    finish(): BulkLoadResult {
+        this.ensurePending();
+        this.done = true;
+
+        const res = this.bindings.sochdb_bulk_loader_finish(this.handle);
+        if (res !== 0) {
+            throw new DatabaseError(`Failed to install bulk-loaded files (Code ${res})`);
+        }
+        return { count: this.count };
+    }
+
+    /**
+     * Discard everything added so far
+     */
+    abort(): void {
+        if (this.done) return;
+        this.done = true;
+        this.bindings.sochdb_bulk_loader_abort(this.handle);
+    }
+
+    private ensurePending(): void {
+        if (this.done) {
+            throw new DatabaseError('Bulk loader has already been finished or aborted');
+        }
+    }
+}
@@ -0,0 +1,101 @@
+/**
+ * Clock Sources - Embedded Mode
+ *
+ * All TTL expiry checks and SDK-written timestamps read time through a
+ * `Clock`, so tests can fast-forward time and deployments with skewed
+ * hosts can apply a fixed correction.
+ */
+
+import { DatabaseError } from '../errors';
+
+export interface Clock {
+    /** Current time in milliseconds since the epoch */
+    now(): number;
+}
+
+/**
+ * Wall-clock time from `Date.now()`
+ */
+export const systemClock: Clock = {
+    now: () => Date.now(),
+};
+
+/**
+ * A clock that runs `offsetMs` ahead of (or, if negative, behind) `base`
+ *
+ * @example
+ * ```typescript
+ * // This host runs 1.5s fast compared to the rest of the cluster
+ * const db = Database.open('./data', { clock: offsetClock(-1500) });
+ * ```
+ */
+export function offsetClock(offsetMs: number, base: Clock = systemClock): Clock {
+    return { now: () => base.now() + offsetMs };
+}
+
+/**
+ * A clock that only moves when told to, for tests
+ *
+ * @example
+ * ```typescript
+ * const clock = new ManualClock();
+ * const db = Database.open('./data', { clock });
+ * await cache.put('q', 'a', embedding, 60);
+ * clock.advance(61_000);
+ * expect(await cache.get(embedding)).toBeNull();
+ * ```
+ */
+export class ManualClock implements Clock {
+    private current: number;
+
+    constructor(startMs: number = Date.now()) {
+        this.current = startMs;
+    }
+
+    now(): number {
+        return this.current;
+    }
+
+    /** Move time forward by `ms` */
+    advance(ms: number): void {
+        if (ms < 0) {
+            throw new RangeError('ManualClock cannot move backwards; use set() instead');
+        }
+        this.current += ms;
+    }
+
+    /** Jump to an absolute time */
+    set(ms: number): void {
+        this.current = ms;
+    }
+}
+
+/**
+ * Shorten a TTL by a random amount of up to `jitter` (a fraction of the TTL)
+ *
+ * Spreads out the expiry of keys written together with the same TTL so they
+ * don't all expire, and get reloaded, at the same instant. Jitter only ever
+ * shortens a TTL, so entries never outlive what the caller asked for. The
+ * result is rounded down to whole milliseconds, as `ttlMs` options require.
+ *
+ * @example
+ * ```typescript
+ * jitterTtl(60_000, 0.1); // somewhere in 54_000..60_000
+ * ```
+ */
+export function jitterTtl(ttlMs: number, jitter: number, random: () => number = Math.random): number {
+    if (jitter < 0 || jitter > 1) {
+        throw new RangeError(`TTL jitter must be between 0 and 1, got ${jitter}`);
+    }
+    return Math.floor(ttlMs - ttlMs * jitter * random());
+}
+
+/**
+ * Reject TTLs the native expiry deadline cannot represent
+ * @internal
+ */
+export function checkTtl(ttlMs: number): void {
+    if (!Number.isSafeInteger(ttlMs) || ttlMs < 0) {
+        throw new DatabaseError(`ttlMs must be a non-negative integer number of milliseconds, got ${ttlMs}`);
+    }
+}
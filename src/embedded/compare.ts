@@ -0,0 +1,84 @@
+/**
+ * Sampled Database Comparison - Embedded Mode
+ *
+ * Compares two databases (e.g. production and a restored backup) on a
+ * deterministic sample of keys. A key is in the sample when a hash of the
+ * key falls below `samplePercent`, so both sides pick the same keys without
+ * coordinating, and the same seed always checks the same keys.
+ */
+
+import * as crypto from 'crypto';
+import { DatabaseError } from '../errors';
+import type { BytesLike } from './key-encoding';
+
+export interface CompareOptions {
+    /** Share of keys to check, 0-100 (default: 1) */
+    samplePercent?: number;
+    /** Changes which keys are sampled (default: 0) */
+    seed?: number;
+    /** Only compare keys under this prefix (default: everything) */
+    prefix?: BytesLike;
+    /** Mismatches to report in detail; all are counted (default: 100) */
+    maxMismatches?: number;
+    signal?: AbortSignal;
+}
+
+export interface CompareMismatch {
+    key: Buffer;
+    problem: 'missing_in_a' | 'missing_in_b' | 'value_differs';
+}
+
+export interface CompareResult {
+    samplePercent: number;
+    /** Keys checked */
+    sampled: number;
+    matched: number;
+    mismatchCount: number;
+    /** First `maxMismatches` mismatches, in key order per side */
+    mismatches: CompareMismatch[];
+}
+
+/**
+ * Resolve and validate comparison options
+ * @internal
+ */
+export function resolveCompareOptions(options: CompareOptions = {}): Required<Omit<CompareOptions, 'signal'>> {
+    const samplePercent = options.samplePercent ?? 1;
+    if (!(samplePercent > 0 && samplePercent <= 100)) {
+        throw new DatabaseError(`samplePercent must be in (0, 100], got ${samplePercent}`);
+    }
+    return {
+        samplePercent,
+        seed: options.seed ?? 0,
+        prefix: options.prefix ?? '',
+        maxMismatches: options.maxMismatches ?? 100,
+    };
+}
+
+/**
+ * Whether `key` belongs to the sample
+ */
+export function isSampled(key: Buffer, samplePercent: number, seed = 0): boolean {
+    if (samplePercent >= 100) return true;
+    const seedBytes = Buffer.alloc(8);
+    seedBytes.writeBigUInt64LE(BigInt(seed));
+    const digest = crypto.createHash('sha256').update(seedBytes).update(key).digest();
+    return digest.readUInt32BE(0) / 0x1_0000_0000 * 100 < samplePercent;
+}
+
+/**
+ * Convert the native comparison JSON
+ * @internal
+ */
+export function parseCompareResult(raw: any): CompareResult {
+    return {
+        samplePercent: raw.sample_percent,
+        sampled: raw.sampled ?? 0,
+        matched: raw.matched ?? 0,
+        mismatchCount: raw.mismatch_count ?? 0,
+        mismatches: (raw.mismatches ?? []).map((m: any) => ({
+            key: Buffer.from(m.key, 'base64'),
+            problem: m.problem,
+        })),
+    };
+}
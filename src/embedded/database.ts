@@ -5,10 +5,57 @@
  * No server required - similar to Python SDK's Database class.
  */
 
-import { DatabaseError } from '../errors';
+import { AbortError, DatabaseError, ReadOnlyError, TransactionError } from '../errors';
 import { NativeBindings } from './ffi/bindings';
-import { EmbeddedTransaction } from './transaction';
+import { AckLevel, EmbeddedTransaction, HandleInvalidatedEvent } from './transaction';
+import { WriteBatch } from './batch';
+import { KeyPrefix, KeyPrefixRegistry, KeyConstructors } from './key-prefix';
+import { ScanProjection, ProjectedEntry } from './projection';
+import { exportJsonl, parseExportHeader, ExportOptions, ExportResult } from './export';
+import { importJsonl, ImportOptions, ImportResult } from './import';
+import { BytesLike, toBuffer, useNativeKeyCodec } from './key-encoding';
+import { EncodedView, ValueEncoding } from './encoded-view';
+import { DatabaseMetrics, parseMetrics, StallInfo, fromNativeStallInfo } from './metrics';
+import { UndoJournal, UndoJournalConfig, UndoOptions, UndoResult } from './undo-journal';
+import { Snapshot } from './snapshot';
+import { PathEntry, PathOrder, TreeSummary } from './path-tree';
+import { ScopedDatabase, ScopeOptions } from './scope';
+import {
+    ExternalMemoryReporter,
+    NativeMemoryUsage,
+    externalMemoryAdjuster,
+    fromNativeMemoryUsage,
+    resolveExternalMemoryInterval,
+} from './memory';
+import { channels, traceQuery, traceScan } from './diagnostics';
+import { DatasetExportOptions, ExportManifest, exportDataset } from './export-manifest';
+import { ReaderHandle } from './reader';
+import { SlowTransactionEvent, TransactionLabelStats, TransactionOutcome, TransactionStatsRegistry, UNLABELED } from './transaction-stats';
+import { CompareOptions, CompareResult, parseCompareResult, resolveCompareOptions } from './compare';
+import { FixtureOptions, FixtureResult, createFixtureDir, readFixture, writeFixture } from './fixture';
+import {
+    AlertCallback,
+    AlertConfig,
+    AlertEvent,
+    AlertSample,
+    AlertThresholds,
+    AlertTracker,
+    directorySize,
+    resolveAlertInterval,
+} from './alerts';
+import { RetentionPolicy, RetentionStats, parseRetentionStats, toNativeRetention } from './retention';
+import { FormatMigrationOptions, FormatMigrationResult, migrateFormat } from './format';
+import { BulkLoader, BulkLoadResult } from './bulk-loader';
+import { IoProfile, MaybeProfiled, Profiled, diffIoProfile, rejectScanProfile } from './io-profile';
+import { Clock, checkTtl, offsetClock, systemClock } from './clock';
+import { SizeLimits, resolveSizeLimits } from './limits';
+import { BACKGROUND_EVENT_NAMES, BackgroundEvent, NativeEventPoller, parseBackgroundEvent } from './events';
+import { Keyspace, KeyspaceOptions, CompressionCodec, COMPRESSION_CODES, toNativeKeyspaceConfig } from './keyspace';
+import { PATH_SEPARATOR, useNativePathCodec, validatePath } from './path';
 import * as koffi from 'koffi';
+import * as fs from 'fs';
+import * as readline from 'readline';
+import { EventEmitter } from 'events';
 
 export interface EmbeddedDatabaseConfig {
     walEnabled?: boolean;
@@ -16,8 +63,228 @@ export interface EmbeddedDatabaseConfig {
     memtableSizeBytes?: number;
     groupCommit?: boolean;
     indexPolicy?: 'write_optimized' | 'balanced' | 'scan_optimized' | 'append_only';
+    /** Default codec used to compress values in the native layer (default: 'none') */
+    compression?: CompressionCodec;
+    /** Values smaller than this are stored uncompressed (default: 0) */
+    compressionMinBytes?: number;
+    /** 32-byte key used to encrypt WAL and SST files at rest */
+    encryptionKey?: Buffer;
+    /** Cipher used with `encryptionKey` (default: 'aes-256-gcm') */
+    cipher?: 'aes-256-gcm' | 'chacha20';
+    /** Journal prior state of keys touched by deletePrefix/deleteRange/deleteTree/importJsonl so they can be undone */
+    undoJournal?: boolean | UndoJournalConfig;
+    /** How often native background events are drained while listeners are attached (default: 250ms) */
+    eventPollIntervalMs?: number;
+    /** Time source for TTL expiry and SDK-written timestamps (default: system clock) */
+    clock?: Clock;
+    /** Correction applied to the clock, e.g. to compensate for host clock skew */
+    clockOffsetMs?: number;
+    /**
+     * Highest on-disk format version this handle may write. Pin it to the
+     * version the oldest SDK in the fleet understands so a rollback can still
+     * open the data; newer format features stay disabled until it is raised.
+     */
+    writeFormatVersion?: number;
+    /**
+     * File with default open options (default: `$SOCHDB_CONFIG`). Options
+     * passed in code always win over the file and the environment.
+     */
+    configFile?: string;
+    /** Do not read defaults from `SOCHDB_*` environment variables (default: false) */
+    ignoreEnvironment?: boolean;
+    /** Largest key accepted, checked before reaching the native library (default: 64 KiB) */
+    maxKeyBytes?: number;
+    /** Largest value accepted, checked before reaching the native library (default: 64 MiB) */
+    maxValueBytes?: number;
+    /** Soft limits that emit `'alert'` events when crossed (see `setAlertThresholds()`) */
+    alerts?: AlertConfig;
+    /**
+     * Transactions running longer than this are counted as slow, logged with
+     * their label and emitted as `'transaction:slow'` (default: off). Open
+     * transactions are checked on the same interval, so one that never
+     * finishes is reported (with outcome `'open'`) within twice this time.
+     */
+    slowTransactionMs?: number;
+    /**
+     * How often native memory use is reported to V8 as external memory
+     * (default: 1000ms, 0 disables). Only takes effect when the optional
+     * external-memory addon is installed; see `./memory`.
+     */
+    externalMemoryIntervalMs?: number;
+}
+
+/**
+ * Options for scan operations
+ */
+export interface ScanOptions extends Omit<ReadOptions, 'profile'> {
+    /** Cancels the scan; the iterator is closed and an AbortError is thrown */
+    signal?: AbortSignal;
+    /** Called with the scan's storage I/O once iteration completes */
+    onProfile?: (profile: IoProfile) => void;
+}
+
+/**
+ * Read options applied by the native engine
+ */
+export interface NativeReadOptions {
+    /** Insert blocks read by this call into the block cache (default: true) */
+    fillCache?: boolean;
+    /** Verify block checksums; a mismatch throws CorruptionError */
+    verifyChecksums?: boolean;
+}
+
+/**
+ * Per-call options for reads
+ *
+ * @example
+ * ```typescript
+ * // Analytics scan that doesn't evict the hot working set
+ * for await (const entry of db.scanPrefix('events/', { fillCache: false })) { ... }
+ *
+ * // Critical read with corruption detection
+ * const balance = await db.get('acct:42', { verifyChecksums: true });
+ * ```
+ */
+export interface ReadOptions extends NativeReadOptions {
+    /** Read from a pinned snapshot instead of the latest state */
+    snapshot?: Snapshot;
+    /** Return `{ value, profile }` with the storage I/O performed by this read */
+    profile?: boolean;
+    /** Cancel the read and reject with a TimeoutError if it takes longer than this */
+    timeoutMs?: number;
+}
+
+/**
+ * Options for `beginTransaction()`
+ */
+export interface TransactionOptions {
+    /**
+     * Read at this snapshot instead of the latest state. Only reads made
+     * through the new transaction are validated at commit; reads done earlier
+     * through the snapshot itself are not carried over, so repeat any read the
+     * writes depend on through the transaction.
+     */
+    snapshot?: Snapshot;
+    /** Reject every write in the native layer (cannot be combined with `snapshot`) */
+    readOnly?: boolean;
+    /** Acknowledgment level used by `commit()` unless it is given one */
+    ack?: AckLevel;
+    /** Name used to attribute this transaction in `transactionStats()`, slow-transaction logs and diagnostics */
+    label?: string;
+    /** Deadline for every operation of the transaction, commit included (see `EmbeddedTransaction.setDeadline()`) */
+    timeoutMs?: number;
+}
+
+/**
+ * Options for `snapshot()`
+ */
+export interface SnapshotOptions {
+    /**
+     * Pin the state as of this LSN (e.g. a `snapshot_lsn` recorded by an
+     * earlier report) instead of the latest state. The engine must still
+     * retain the versions visible at that LSN.
+     */
+    lsn?: bigint;
+}
+
+/**
+ * Options for `listPath()` and `treeSummary()`
+ */
+export interface PathListOptions {
+    /** List as of this snapshot, so later reads through it see the same tree */
+    snapshot?: Snapshot;
+    /** Levels below the path to include in a summary (0 = unlimited) */
+    maxDepth?: number;
+    /** Order of listed entries (default: 'bytes') */
+    order?: PathOrder;
+}
+
+/**
+ * Options for `scanPath()`
+ */
+export interface PathScanOptions {
+    /** Scan as of this snapshot */
+    snapshot?: Snapshot;
+    /** Order of scanned documents (default: 'bytes') */
+    order?: PathOrder;
+    /** Cancels the scan; the iterator is closed and an AbortError is thrown */
+    signal?: AbortSignal;
+}
+
+/**
+ * Options for range, prefix and tree deletes
+ */
+export interface DeleteOptions {
+    /** Report what would be deleted without modifying the database */
+    dryRun?: boolean;
+    /** Number of matching keys to include in the result (default: 10) */
+    sampleSize?: number;
+    /**
+     * Keys deleted per transaction (default: 1000). If a batch fails, the
+     * batches before it stay committed.
+     */
+    batchSize?: number;
+    signal?: AbortSignal;
+}
+
+/**
+ * Result of a range, prefix or tree delete
+ */
+export interface DeleteResult {
+    /** Keys deleted (or that would be deleted in dry-run mode) */
+    count: number;
+    /** First matching keys, in key order */
+    sampleKeys: Buffer[];
+    dryRun: boolean;
+}
+
+/**
+ * Options for `checkpointTo()`
+ */
+export interface CheckpointToOptions {
+    /** Only materialize changes made after `baseLsn`, hard-linking unchanged files */
+    incremental?: boolean;
+    /** LSN of the checkpoint this one builds on (required when incremental) */
+    baseLsn?: bigint;
+    /** Cancels the checkpoint, which then runs on a worker thread; rejects with AbortError */
+    signal?: AbortSignal;
+}
+
+/**
+ * Result of `checkpointTo()`
+ */
+export interface CheckpointInfo {
+    /** Directory containing the checkpoint */
+    path: string;
+    /** LSN the checkpoint is consistent at */
+    lsn: bigint;
+    incremental: boolean;
+    baseLsn?: bigint;
+}
+
+/**
+ * Options for auto-committed writes
+ */
+export interface WriteOptions {
+    /** Resolve once the write reaches this stage (default: the database's `syncMode`) */
+    ack?: AckLevel;
+    /** Cancel the write and its commit and reject with a TimeoutError if they take longer than this */
+    timeoutMs?: number;
+}
+
+/**
+ * Per-call options for puts
+ */
+export interface PutOptions {
+    /** Override the database compression codec for this value */
+    compression?: CompressionCodec;
+    /** Expire the key this many milliseconds after the write (per the database clock) */
+    ttlMs?: number;
 }
 
+/** Returned by a checkpoint stopped through `sochdb_checkpoint_cancel` */
+const CHECKPOINT_CANCELLED = -8;
+
 /**
  * Embedded Database using direct FFI
  * 
@@ -31,20 +298,52 @@ export interface EmbeddedDatabaseConfig {
  * await db.close();
  * ```
  */
-export class EmbeddedDatabase {
+export class EmbeddedDatabase extends EventEmitter {
     private handle: any;
     private bindings: NativeBindings;
     private closed = false;
     private path: string;
     private concurrent = false;
     private _concurrentModeFallback = false;
+    private prefixRegistry = new KeyPrefixRegistry();
+    private undoJournal: UndoJournal | null = null;
+    private eventPoller: NativeEventPoller;
+    private _clock: Clock = systemClock;
+    private liveTransactions = new Set<EmbeddedTransaction>();
+    private _sizeLimits: SizeLimits = resolveSizeLimits();
+    /** Temporary directory of a database opened with `openFixture()`; removed on close */
+    private fixtureDir: string | null = null;
+    private fixtureReadOnly = false;
+    private alertTracker: AlertTracker | null = null;
+    private alertTimer: NodeJS.Timeout | null = null;
+    private alertCheckRunning = false;
+    private readers = new Set<ReaderHandle>();
+    private transactionStatsRegistry = new TransactionStatsRegistry();
+    private slowTransactionMs: number | undefined;
+    private slowTransactionTimer: NodeJS.Timeout | null = null;
+    private externalMemory: ExternalMemoryReporter | null = null;
 
-    private constructor(path: string, handle: any, concurrent = false, fallback = false) {
+    private constructor(path: string, handle: any, concurrent = false, fallback = false, eventPollIntervalMs = 250) {
+        super();
         this.path = path;
         this.handle = handle;
         this.concurrent = concurrent;
         this._concurrentModeFallback = fallback;
         this.bindings = NativeBindings.getInstance();
+        useNativeKeyCodec(this.bindings);
+        useNativePathCodec(this.bindings);
+
+        this.eventPoller = new NativeEventPoller(
+            () => this.pollBackgroundEvents(),
+            (raw) => this.emitBackgroundEvent(parseBackgroundEvent(raw, this.now())),
+            eventPollIntervalMs,
+            (error) => this.reportBackgroundError(error)
+        );
+        this.on('newListener', (event: string | symbol) => {
+            if (typeof event === 'string' && BACKGROUND_EVENT_NAMES.includes(event)) {
+                this.startEventPolling();
+            }
+        });
     }
 
     /**
@@ -58,21 +357,25 @@ export class EmbeddedDatabase {
      */
     static open(path: string, config?: EmbeddedDatabaseConfig): EmbeddedDatabase {
         const bindings = NativeBindings.getInstance();
+        const handle = EmbeddedDatabase.openStandardHandle(bindings, path, config);
+        return EmbeddedDatabase.wrapHandle(path, handle, config);
+    }
+
+    /**
+     * Open a standard-mode native handle with every handle-level option applied
+     */
+    private static openStandardHandle(bindings: NativeBindings, path: string, config?: EmbeddedDatabaseConfig): any {
+        // Validated before the native open so bad limits don't leak a handle
+        resolveSizeLimits(config);
+        resolveExternalMemoryInterval(config?.externalMemoryIntervalMs);
+        resolveAlertInterval(config?.alerts?.intervalMs);
         let handle;
 
-        if (config) {
-            const cConfig = {
-                wal_enabled: config.walEnabled ?? false,
-                wal_enabled_set: config.walEnabled !== undefined,
-                sync_mode: config.syncMode === 'full' ? 2 : (config.syncMode === 'normal' ? 1 : 0),
-                sync_mode_set: config.syncMode !== undefined,
-                memtable_size_bytes: BigInt(config.memtableSizeBytes ?? 0),
-                group_commit: config.groupCommit ?? false,
-                group_commit_set: config.groupCommit !== undefined,
-                default_index_policy: 1, // Default to Balanced
-                default_index_policy_set: false
-            };
-            handle = bindings.sochdb_open_with_config(path, cConfig);
+        const nativeConfig = EmbeddedDatabase.resolveNativeConfig(bindings, config);
+        if (config?.encryptionKey) {
+            handle = EmbeddedDatabase.openEncrypted(bindings, path, config, nativeConfig);
+        } else if (nativeConfig) {
+            handle = bindings.sochdb_open_with_config(path, nativeConfig);
         } else {
             handle = bindings.sochdb_open(path);
         }
@@ -81,7 +384,190 @@ export class EmbeddedDatabase {
             throw new DatabaseError(`Failed to open database at ${path}`);
         }
 
-        return new EmbeddedDatabase(path, handle, false);
+        EmbeddedDatabase.configureHandle(bindings, handle, config);
+        return handle;
+    }
+
+    /**
+     * Apply the options set on an open handle rather than passed to the
+     * native open; the handle is closed if one fails
+     */
+    private static configureHandle(bindings: NativeBindings, handle: any, config?: EmbeddedDatabaseConfig): void {
+        if (config?.compression !== undefined) {
+            EmbeddedDatabase.configureCompression(bindings, handle, config);
+        }
+        if (config?.writeFormatVersion !== undefined) {
+            EmbeddedDatabase.configureWriteFormat(bindings, handle, config.writeFormatVersion);
+        }
+    }
+
+    /**
+     * Build the database object around a freshly opened native handle and
+     * apply the handle-independent options
+     */
+    private static wrapHandle(
+        path: string,
+        handle: any,
+        config?: EmbeddedDatabaseConfig,
+        concurrent = false,
+        fallback = false
+    ): EmbeddedDatabase {
+        const db = new EmbeddedDatabase(path, handle, concurrent, fallback, config?.eventPollIntervalMs);
+        db._sizeLimits = resolveSizeLimits(config);
+        db.slowTransactionMs = config?.slowTransactionMs;
+        if (db.slowTransactionMs !== undefined && db.slowTransactionMs > 0) {
+            db.watchSlowTransactions(db.slowTransactionMs);
+        }
+        if (config?.clock || config?.clockOffsetMs) {
+            db.setClock(config.clock ?? systemClock, config.clockOffsetMs);
+        }
+        if (config?.undoJournal) {
+            db.undoJournal = new UndoJournal(db, config.undoJournal === true ? {} : config.undoJournal);
+        }
+        if (config?.alerts) {
+            db.setAlertThresholds(config.alerts.thresholds, config.alerts);
+        }
+        db.startExternalMemoryReporting(resolveExternalMemoryInterval(config?.externalMemoryIntervalMs));
+        return EmbeddedDatabase.publishOpen(db);
+    }
+
+    private static openEncrypted(
+        bindings: NativeBindings,
+        path: string,
+        config: EmbeddedDatabaseConfig,
+        nativeConfig: Record<string, any> | null
+    ): any {
+        if (!bindings.sochdb_open_encrypted) {
+            throw new DatabaseError(
+                'Encryption at rest is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const key = config.encryptionKey!;
+        if (key.length !== 32) {
+            throw new DatabaseError(`Encryption key must be 32 bytes, got ${key.length}`);
+        }
+        const cipher = config.cipher ?? 'aes-256-gcm';
+        if (cipher !== 'aes-256-gcm' && cipher !== 'chacha20') {
+            throw new DatabaseError(`Unsupported cipher: ${cipher}`);
+        }
+
+        return bindings.sochdb_open_encrypted(
+            path,
+            nativeConfig ?? EmbeddedDatabase.toNativeConfig(config),
+            key,
+            key.length,
+            cipher === 'chacha20' ? 2 : 1
+        );
+    }
+
+    /**
+     * Open options after applying defaults from the config file and
+     * `SOCHDB_*` environment variables, as the native layer resolves them.
+     * Useful for logging the effective settings at startup.
+     *
+     * Recognized variables: `SOCHDB_CONFIG` (config file path),
+     * `SOCHDB_WAL_ENABLED`, `SOCHDB_SYNC_MODE`, `SOCHDB_MEMTABLE_SIZE_BYTES`,
+     * `SOCHDB_GROUP_COMMIT`.
+     */
+    static resolveConfig(config: EmbeddedDatabaseConfig = {}): EmbeddedDatabaseConfig {
+        const bindings = NativeBindings.getInstance();
+        const native = EmbeddedDatabase.resolveNativeConfig(bindings, config) ?? EmbeddedDatabase.toNativeConfig(config);
+        return {
+            ...config,
+            walEnabled: native.wal_enabled_set ? native.wal_enabled : config.walEnabled,
+            syncMode: native.sync_mode_set
+                ? (['off', 'normal', 'full'] as const)[native.sync_mode]
+                : config.syncMode,
+            // 0 is the native "unset" value; options passed in code always win
+            memtableSizeBytes: config.memtableSizeBytes ?? (Number(native.memtable_size_bytes) || undefined),
+            groupCommit: native.group_commit_set ? native.group_commit : config.groupCommit,
+        };
+    }
+
+    /**
+     * Native config with file/environment defaults applied, or null to open
+     * with built-in defaults
+     */
+    private static resolveNativeConfig(
+        bindings: NativeBindings,
+        config?: EmbeddedDatabaseConfig
+    ): Record<string, any> | null {
+        const explicit = config ? EmbeddedDatabase.toNativeConfig(config) : null;
+        if (!bindings.sochdb_resolve_config) {
+            if (config?.configFile) {
+                throw new DatabaseError(
+                    'Config files are not supported by the loaded SochDB native library. ' +
+                    'Please upgrade the native library or pass options in code.'
+                );
+            }
+            return explicit;
+        }
+
+        const resolved: Record<string, any> = {};
+        const res = bindings.sochdb_resolve_config(
+            explicit ?? EmbeddedDatabase.toNativeConfig({}),
+            config?.configFile ?? null,
+            !config?.ignoreEnvironment,
+            resolved
+        );
+        if (res !== 0) {
+            throw new DatabaseError(
+                `Failed to load SochDB defaults from ${config?.configFile ?? '$SOCHDB_CONFIG'} (Code ${res})`
+            );
+        }
+        return resolved;
+    }
+
+    private static toNativeConfig(config: EmbeddedDatabaseConfig): Record<string, any> {
+        return {
+            wal_enabled: config.walEnabled ?? false,
+            wal_enabled_set: config.walEnabled !== undefined,
+            sync_mode: config.syncMode === 'full' ? 2 : (config.syncMode === 'normal' ? 1 : 0),
+            sync_mode_set: config.syncMode !== undefined,
+            memtable_size_bytes: BigInt(config.memtableSizeBytes ?? 0),
+            group_commit: config.groupCommit ?? false,
+            group_commit_set: config.groupCommit !== undefined,
+            default_index_policy: 1, // Default to Balanced
+            default_index_policy_set: false
+        };
+    }
+
+    private static configureWriteFormat(bindings: NativeBindings, handle: any, version: number): void {
+        if (!bindings.sochdb_set_write_format_version) {
+            bindings.sochdb_close(handle);
+            throw new DatabaseError(
+                'Format version pinning is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library or open without the writeFormatVersion option.'
+            );
+        }
+        const res = bindings.sochdb_set_write_format_version(handle, version);
+        if (res !== 0) {
+            bindings.sochdb_close(handle);
+            throw new DatabaseError(
+                `Cannot write format version ${version}: the data is already at a newer version ` +
+                `or this library does not support it (Code ${res})`
+            );
+        }
+    }
+
+    private static configureCompression(bindings: NativeBindings, handle: any, config: EmbeddedDatabaseConfig): void {
+        if (!bindings.sochdb_set_compression) {
+            bindings.sochdb_close(handle);
+            throw new DatabaseError(
+                'Value compression is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library or open without the compression option.'
+            );
+        }
+        const res = bindings.sochdb_set_compression(
+            handle,
+            COMPRESSION_CODES[config.compression ?? 'none'],
+            config.compressionMinBytes ?? 0
+        );
+        if (res !== 0) {
+            bindings.sochdb_close(handle);
+            throw new DatabaseError(`Failed to enable ${config.compression} compression`);
+        }
     }
 
     /**
@@ -127,10 +613,14 @@ export class EmbeddedDatabase {
      * ```
      * 
      * @param path - Path to database directory
-     * @param options - Optional configuration for concurrent mode
+     * @param options - Open options (resolved against the config file and
+     *   `SOCHDB_*` variables as in `open()`) plus `fallbackToStandard`
      * @returns EmbeddedDatabase instance in concurrent mode
      */
-    static openConcurrent(path: string, options?: { fallbackToStandard?: boolean }): EmbeddedDatabase {
+    static openConcurrent(
+        path: string,
+        options?: EmbeddedDatabaseConfig & { fallbackToStandard?: boolean }
+    ): EmbeddedDatabase {
         const bindings = NativeBindings.getInstance();
         const fallbackToStandard = options?.fallbackToStandard ?? false;
         
@@ -141,11 +631,8 @@ export class EmbeddedDatabase {
                     'Falling back to standard mode. For production multi-process deployments, ' +
                     'please upgrade the SochDB native library.'
                 );
-                const handle = bindings.sochdb_open(path);
-                if (!handle) {
-                    throw new DatabaseError(`Failed to open database at ${path}`);
-                }
-                return new EmbeddedDatabase(path, handle, false, true);
+                const handle = EmbeddedDatabase.openStandardHandle(bindings, path, options);
+                return EmbeddedDatabase.wrapHandle(path, handle, options, false, true);
             }
             throw new DatabaseError(
                 'Concurrent mode not supported. Please upgrade the SochDB native library to v0.4.8+ ' +
@@ -153,13 +640,62 @@ export class EmbeddedDatabase {
             );
         }
 
-        const handle = bindings.sochdb_open_concurrent(path);
+        if (options?.encryptionKey) {
+            throw new DatabaseError('Encryption at rest is not supported in concurrent mode');
+        }
+        resolveSizeLimits(options);
+        resolveExternalMemoryInterval(options?.externalMemoryIntervalMs);
+        resolveAlertInterval(options?.alerts?.intervalMs);
+        const nativeConfig = EmbeddedDatabase.resolveNativeConfig(bindings, options);
+        let handle;
+        if (nativeConfig && bindings.sochdb_open_concurrent_with_config) {
+            handle = bindings.sochdb_open_concurrent_with_config(path, nativeConfig);
+        } else if (nativeConfig && EmbeddedDatabase.overridesDefaults(nativeConfig)) {
+            throw new DatabaseError(
+                'Open options in concurrent mode are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        } else {
+            handle = bindings.sochdb_open_concurrent(path);
+        }
         if (!handle) {
             throw new DatabaseError(`Failed to open database in concurrent mode at ${path}`);
         }
+        EmbeddedDatabase.configureHandle(bindings, handle, options);
 
         const isConcurrent = bindings.sochdb_is_concurrent?.(handle) === 1;
-        return new EmbeddedDatabase(path, handle, isConcurrent, false);
+        return EmbeddedDatabase.wrapHandle(path, handle, options, isConcurrent, false);
+    }
+
+    /**
+     * Whether a resolved native config differs from the built-in defaults
+     */
+    private static overridesDefaults(nativeConfig: Record<string, any>): boolean {
+        return !!(
+            nativeConfig.wal_enabled_set ||
+            nativeConfig.sync_mode_set ||
+            nativeConfig.group_commit_set ||
+            nativeConfig.default_index_policy_set ||
+            Number(nativeConfig.memtable_size_bytes) !== 0
+        );
+    }
+
+    /**
+     * Announce a newly opened database; every open path goes through here so
+     * open and close events pair up
+     */
+    private static publishOpen(db: EmbeddedDatabase): EmbeddedDatabase {
+        if (channels.databaseOpen.hasSubscribers) {
+            channels.databaseOpen.publish({ database: db.path });
+        }
+        return db;
+    }
+
+    /**
+     * Directory the database was opened at
+     */
+    get location(): string {
+        return this.path;
     }
 
     /**
@@ -177,127 +713,1041 @@ export class EmbeddedDatabase {
     }
 
     /**
-     * Check if concurrent mode is available in the native library
+     * Clock used for TTL expiry and SDK-written timestamps
      */
-    static isConcurrentModeAvailable(): boolean {
-        return NativeBindings.getInstance().isConcurrentModeAvailable();
+    get clock(): Clock {
+        return this._clock;
     }
 
     /**
-     * Put a key-value pair (auto-transaction)
+     * Replace the clock, optionally shifted by `offsetMs`
+     *
+     * @example
+     * ```typescript
+     * const clock = new ManualClock();
+     * db.setClock(clock);
+     * clock.advance(60_000); // entries with a 60s TTL are now expired
+     * ```
      */
-    async put(key: Buffer, value: Buffer): Promise<void> {
-        this.ensureOpen();
-
-        const txn = this.transaction();
-        try {
-            await txn.put(key, value);
-            await txn.commit();
-        } catch (error) {
-            await txn.abort();
-            throw error;
-        }
+    setClock(clock: Clock, offsetMs = 0): void {
+        this._clock = offsetMs ? offsetClock(offsetMs, clock) : clock;
     }
 
     /**
-     * Get a value by key (auto-transaction)
+     * Backend serving this handle (`openDatabase` may return a PortableDatabase instead)
      */
-    async get(key: Buffer): Promise<Buffer | null> {
-        this.ensureOpen();
+    get backend(): 'native' {
+        return 'native';
+    }
 
-        const txn = this.transaction();
-        try {
-            const value = await txn.get(key);
-            await txn.commit();
-            return value;
-        } catch (error) {
-            await txn.abort();
-            throw error;
-        }
+    /**
+     * Key and value size limits enforced on writes
+     */
+    get sizeLimits(): Readonly<SizeLimits> {
+        return this._sizeLimits;
     }
 
     /**
-     * Delete a key (auto-transaction)
+     * Current time according to the database clock, in ms since the epoch
      */
-    async delete(key: Buffer): Promise<void> {
-        this.ensureOpen();
+    now(): number {
+        return this._clock.now();
+    }
 
-        const txn = this.transaction();
-        try {
-            await txn.delete(key);
-            await txn.commit();
-        } catch (error) {
-            await txn.abort();
-            throw error;
+    /**
+     * On-disk format version of the database at `path`, without opening it
+     *
+     * @returns The format version, or null if there is no database at `path`
+     *
+     * @example
+     * ```typescript
+     * const version = Database.formatVersion('./data');
+     * if (version !== null && version > Database.supportedFormatVersion()) {
+     *     throw new Error('Data was written by a newer SochDB; upgrade this service first');
+     * }
+     * ```
+     */
+    static formatVersion(path: string): number | null {
+        const bindings = NativeBindings.getInstance();
+        if (!bindings.sochdb_format_version) {
+            throw new DatabaseError(
+                'Format versioning is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const version = bindings.sochdb_format_version(path);
+        if (version === -1) {
+            return null;
+        }
+        if (version < 0) {
+            throw new DatabaseError(`Failed to read format version at ${path} (Code ${version})`);
         }
+        return version;
     }
 
     /**
-     * Put value at path (auto-transaction)
+     * Newest on-disk format version the loaded native library can read and write
      */
-    async putPath(path: string, value: Buffer): Promise<void> {
-        this.ensureOpen();
-
-        const txn = this.transaction();
-        try {
-            await txn.putPath(path, value);
-            await txn.commit();
-        } catch (error) {
-            await txn.abort();
-            throw error;
+    static supportedFormatVersion(): number {
+        const bindings = NativeBindings.getInstance();
+        if (!bindings.sochdb_supported_format_version) {
+            throw new DatabaseError(
+                'Format versioning is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
         }
+        return bindings.sochdb_supported_format_version();
     }
 
     /**
-     * Get value at path (auto-transaction)
+     * Compare two databases on a sample of keys, e.g. production against a
+     * restored backup
+     *
+     * The native engine opens both paths read-only, so neither database's
+     * writer lock is taken and a mistyped path is reported instead of created.
+     *
+     * @example
+     * ```typescript
+     * const report = await Database.compare('./prod', './restore-check', { samplePercent: 5 });
+     * if (report.mismatchCount > 0) {
+     *     console.error(`${report.mismatchCount} of ${report.sampled} sampled keys differ`, report.mismatches);
+     * }
+     * ```
      */
-    async getPath(path: string): Promise<Buffer | null> {
-        this.ensureOpen();
+    static async compare(pathA: string, pathB: string, options?: CompareOptions): Promise<CompareResult> {
+        const resolved = resolveCompareOptions(options);
+        const bindings = NativeBindings.getInstance();
+        if (!bindings.sochdb_compare_sampled) {
+            throw new DatabaseError(
+                'Database comparison is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
 
-        const txn = this.transaction();
-        try {
-            const value = await txn.getPath(path);
-            await txn.commit();
-            return value;
-        } catch (error) {
-            await txn.abort();
-            throw error;
+        AbortError.throwIfAborted(options?.signal);
+        const prefix = toBuffer(resolved.prefix);
+        const outPtr = [null];
+        const outLen = [0];
+        const res = bindings.sochdb_compare_sampled(
+            pathA, pathB, prefix, prefix.length,
+            resolved.samplePercent, BigInt(resolved.seed), resolved.maxMismatches,
+            outPtr, outLen
+        );
+        if (res !== 0) {
+            throw new DatabaseError(`Failed to compare ${pathA} with ${pathB} (Code ${res})`);
         }
+        const raw = JSON.parse(Buffer.from(koffi.decode(outPtr[0], 'uint8', outLen[0])).toString('utf8'));
+        bindings.sochdb_free_bytes(outPtr[0], outLen[0]);
+        return parseCompareResult(raw);
     }
 
     /**
-     * Scan keys with prefix
+     * Migrate a closed database to another format version in place
+     *
+     * Downgrades succeed only when the data uses no features the target
+     * version lacks. If the migration fails or is aborted, the database
+     * stays at its original version.
+     *
+     * @example
+     * ```typescript
+     * await Database.upgradeFormat('./data', Database.supportedFormatVersion(), {
+     *     onProgress: (p) => console.log(`${p.bytesDone}/${p.bytesTotal} bytes`),
+     * });
+     * ```
      */
-    async *scanPrefix(prefix: Buffer): AsyncGenerator<[Buffer, Buffer]> {
-        this.ensureOpen();
+    static async upgradeFormat(
+        path: string,
+        targetVersion: number,
+        options?: FormatMigrationOptions
+    ): Promise<FormatMigrationResult> {
+        return migrateFormat(NativeBindings.getInstance(), path, targetVersion, options);
+    }
 
-        const txn = this.transaction();
-        try {
-            for await (const entry of txn.scanPrefix(prefix)) {
-                yield entry;
-            }
-            await txn.commit();
-        } catch (error) {
-            await txn.abort();
-            throw error;
-        }
+    /**
+     * Check if concurrent mode is available in the native library
+     */
+    static isConcurrentModeAvailable(): boolean {
+        return NativeBindings.getInstance().isConcurrentModeAvailable();
     }
 
     /**
-     * Begin a transaction
+     * Put a key-value pair (auto-transaction)
+     *
+     * @example
+     * ```typescript
+     * // Resolve once visible; durability follows with the next batched fsync
+     * await db.put('metrics/cpu', sample, { ack: 'applied' });
+     *
+     * // Resolve only once fsynced, whatever the database's syncMode
+     * await db.put('orders/42', order, { ack: 'fsynced' });
+     * ```
      */
-    transaction(): EmbeddedTransaction {
+    async put(key: BytesLike, value: BytesLike, options?: PutOptions & WriteOptions): Promise<void> {
         this.ensureOpen();
 
-        const txnHandle = this.bindings.sochdb_begin_txn(this.handle);
-        return new EmbeddedTransaction(this, this.handle, txnHandle);
+        return traceQuery(this.path, 'put', key, async () => {
+            const txn = this.transaction({ ack: options?.ack, timeoutMs: options?.timeoutMs });
+            try {
+                await txn.put(key, value, options);
+                await txn.commit();
+            } catch (error) {
+                await txn.abort();
+                throw error;
+            }
+        });
     }
 
     /**
-     * Execute operations within a transaction (with auto-commit/abort)
-     */
-    async withTransaction<T>(fn: (txn: EmbeddedTransaction) => Promise<T>): Promise<T> {
+     * Get a value by key (auto-transaction)
+     *
+     * @example
+     * ```typescript
+     * const { value, profile } = await db.get('doc:1', { profile: true });
+     * console.log(profile.cacheMisses, profile.bytesFromDisk);
+     * ```
+     */
+    async get<O extends ReadOptions | undefined = undefined>(
+        key: BytesLike,
+        options?: O
+    ): Promise<MaybeProfiled<Buffer | null, O>> {
+        this.ensureOpen();
+        return traceQuery(this.path, 'get', key, () => this.read(options, (txn) => txn.get(key, options))) as
+            Promise<MaybeProfiled<Buffer | null, O>>;
+    }
+
+    /**
+     * Delete a key (auto-transaction)
+     */
+    async delete(key: BytesLike, options?: WriteOptions): Promise<void> {
+        this.ensureOpen();
+
+        return traceQuery(this.path, 'delete', key, async () => {
+            const txn = this.transaction({ ack: options?.ack, timeoutMs: options?.timeoutMs });
+            try {
+                await txn.delete(key);
+                await txn.commit();
+            } catch (error) {
+                await txn.abort();
+                throw error;
+            }
+        });
+    }
+
+    /**
+     * Put value at path (auto-transaction)
+     *
+     * The path must pass `validatePath`: paths with a leading "/", an empty
+     * segment or a literal "%" are rejected with InvalidPathError (they were
+     * accepted before 0.5). Build paths with `PathBuilder` to escape segments.
+     */
+    async putPath(path: string, value: BytesLike, options?: WriteOptions): Promise<void> {
+        this.ensureOpen();
+
+        return traceQuery(this.path, 'putPath', path, async () => {
+            const txn = this.transaction({ ack: options?.ack, timeoutMs: options?.timeoutMs });
+            try {
+                await txn.putPath(path, value);
+                await txn.commit();
+            } catch (error) {
+                await txn.abort();
+                throw error;
+            }
+        });
+    }
+
+    /**
+     * Get value at path (auto-transaction)
+     */
+    async getPath<O extends ReadOptions | undefined = undefined>(
+        path: string,
+        options?: O
+    ): Promise<MaybeProfiled<Buffer | null, O>> {
+        this.ensureOpen();
+        return traceQuery(this.path, 'getPath', path, () => this.read(options, (txn) => txn.getPath(path, options))) as
+            Promise<MaybeProfiled<Buffer | null, O>>;
+    }
+
+    /**
+     * List the direct children of a path (auto-transaction, or the given snapshot)
+     *
+     * @example
+     * ```typescript
+     * const snap = db.snapshot();
+     * try {
+     *     for (const entry of await db.listPath('users', { snapshot: snap })) {
+     *         if (entry.hasValue) {
+     *             const doc = await snap.getPath(entry.path);
+     *         }
+     *     }
+     * } finally {
+     *     snap.release();
+     * }
+     * ```
+     */
+    async listPath(path: string, options?: PathListOptions): Promise<PathEntry[]> {
+        this.ensureOpen();
+        return this.read(options, (txn) => txn.listPath(path, options?.order)) as Promise<PathEntry[]>;
+    }
+
+    /**
+     * Scan every document at or below a path (auto-transaction, or the given snapshot)
+     *
+     * @example
+     * ```typescript
+     * // "a/b/c" and "a/z" are yielded before "a!/x", unlike a raw byte scan
+     * for await (const [path, value] of db.scanPath('a', { order: 'components' })) {
+     *     console.log(path, value.length);
+     * }
+     * ```
+     */
+    async *scanPath(path: string, options?: PathScanOptions): AsyncGenerator<[string, Buffer]> {
+        this.ensureOpen();
+        rejectScanProfile(options);
+        if (options?.snapshot) {
+            yield* options.snapshot.scanPath(path, options);
+            return;
+        }
+
+        const txn = this.transaction();
+        try {
+            for await (const entry of txn.scanPath(path, options)) {
+                yield entry;
+            }
+            await txn.commit();
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Summarize the subtree below a path (auto-transaction, or the given snapshot)
+     */
+    async treeSummary(path: string, options?: PathListOptions): Promise<TreeSummary> {
+        this.ensureOpen();
+        return this.read(options, (txn) => txn.treeSummary(path, options?.maxDepth)) as Promise<TreeSummary>;
+    }
+
+    /**
+     * Run a single read in an auto-transaction (or the given snapshot),
+     * attaching the I/O profile when requested
+     */
+    private async read<T>(
+        options: ReadOptions | undefined,
+        fn: (txn: EmbeddedTransaction) => Promise<T>
+    ): Promise<T | Profiled<T>> {
+        if (options?.snapshot) {
+            const txn = options.snapshot.getTransaction();
+            const before = options.profile ? txn.ioProfile() : null;
+            const previousDeadline = txn.remainingDeadlineMs();
+            if (options.timeoutMs !== undefined) {
+                txn.setDeadline(options.timeoutMs);
+            }
+            try {
+                const value = await fn(txn);
+                return before ? { value, profile: diffIoProfile(txn.ioProfile(), before) } : value;
+            } finally {
+                // The snapshot outlives this read; put back whatever deadline it had
+                if (options.timeoutMs !== undefined) {
+                    txn.setDeadline(previousDeadline);
+                }
+            }
+        }
+
+        const txn = this.transaction({ timeoutMs: options?.timeoutMs });
+        try {
+            const value = await fn(txn);
+            const profile = options?.profile ? txn.ioProfile() : null;
+            await txn.commit();
+            return profile ? { value, profile } : value;
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Scan keys with prefix
+     *
+     * @param prefix - Key prefix to scan
+     * @param options - Optional scan options (e.g. an AbortSignal to cancel a long scan)
+     */
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        this.ensureOpen();
+        rejectScanProfile(options);
+        yield* traceScan(this.path, 'scanPrefix', prefix, () => this.scanPrefixIn(prefix, options));
+    }
+
+    private async *scanPrefixIn(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        if (options?.snapshot) {
+            const snapTxn = options.snapshot.getTransaction();
+            const before = options.onProfile ? snapTxn.ioProfile() : null;
+            yield* options.snapshot.scanPrefix(prefix, options);
+            if (before) {
+                options.onProfile!(diffIoProfile(snapTxn.ioProfile(), before));
+            }
+            return;
+        }
+
         const txn = this.transaction();
+        try {
+            for await (const entry of txn.scanPrefix(prefix, options)) {
+                yield entry;
+            }
+            options?.onProfile?.(txn.ioProfile());
+            await txn.commit();
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Delete every key with the given prefix
+     *
+     * Use `{ dryRun: true }` to see how many keys match (and a sample of them)
+     * before deleting anything.
+     *
+     * @example
+     * ```typescript
+     * const preview = await db.deletePrefix('sessions/2024-', { dryRun: true });
+     * console.log(preview.count, preview.sampleKeys.map(String));
+     * await db.deletePrefix('sessions/2024-');
+     * ```
+     */
+    async deletePrefix(prefix: BytesLike, options?: DeleteOptions): Promise<DeleteResult> {
+        this.ensureOpen();
+        const prefixBuf = toBuffer(prefix);
+        return this.deleteMatching('deletePrefix', { prefix: prefixBuf.toString('base64') }, prefixBuf, () => true, options);
+    }
+
+    /**
+     * Delete every key in `[start, end)`
+     *
+     * @param start - First key to delete (inclusive)
+     * @param end - Upper bound (exclusive)
+     * @param options - Dry-run, sampling and cancellation options
+     */
+    async deleteRange(start: BytesLike, end: BytesLike, options?: DeleteOptions): Promise<DeleteResult> {
+        this.ensureOpen();
+        const startBuf = toBuffer(start);
+        const endBuf = toBuffer(end);
+        if (Buffer.compare(startBuf, endBuf) >= 0) {
+            throw new DatabaseError('deleteRange() requires start < end');
+        }
+
+        // Scan the longest common prefix of the bounds and filter to the range
+        let common = 0;
+        while (common < startBuf.length && common < endBuf.length && startBuf[common] === endBuf[common]) {
+            common++;
+        }
+        return this.deleteMatching(
+            'deleteRange',
+            { start: startBuf.toString('base64'), end: endBuf.toString('base64') },
+            startBuf.subarray(0, common),
+            (key) => Buffer.compare(key, startBuf) >= 0 && Buffer.compare(key, endBuf) < 0,
+            options
+        );
+    }
+
+    /**
+     * Delete the document at a path and every document below it
+     *
+     * @example
+     * ```typescript
+     * const preview = await db.deleteTree('users/alice', { dryRun: true });
+     * console.log(preview.count, preview.sampleKeys.map(String));
+     * await db.deleteTree('users/alice');
+     * ```
+     */
+    async deleteTree(path: string, options?: DeleteOptions): Promise<DeleteResult> {
+        this.ensureOpen();
+        validatePath(path);
+        const root = Buffer.from(path);
+        const children = Buffer.from(path + PATH_SEPARATOR);
+        return this.deleteMatching(
+            'deleteTree',
+            { path },
+            root,
+            (key) => key.equals(root) || key.subarray(0, children.length).equals(children),
+            options
+        );
+    }
+
+    /**
+     * Scan `prefix` once and delete the matching keys in batches, so memory
+     * stays bounded by the batch size rather than the number of matches
+     */
+    private async deleteMatching(
+        op: string,
+        details: Record<string, unknown>,
+        prefix: Buffer,
+        matches: (key: Buffer) => boolean,
+        options?: DeleteOptions
+    ): Promise<DeleteResult> {
+        const dryRun = options?.dryRun ?? false;
+        const sampleSize = options?.sampleSize ?? 10;
+        const batchSize = options?.batchSize ?? 1000;
+        if (!Number.isInteger(batchSize) || batchSize <= 0) {
+            throw new DatabaseError(`batchSize must be a positive integer, got ${batchSize}`);
+        }
+        const result: DeleteResult = { count: 0, sampleKeys: [], dryRun };
+        const journaled = !dryRun && this.undoJournal ? await this.undoJournal.begin(op, details) : null;
+
+        const deleteBatch = (keys: Buffer[]) => this.withTransaction(async (txn) => {
+            if (journaled) {
+                await this.undoJournal!.recordOp(txn, journaled);
+            }
+            for (const key of keys) {
+                if (journaled) {
+                    await this.undoJournal!.recordWrite(txn, journaled, key, () => txn.delete(key));
+                } else {
+                    await txn.delete(key);
+                }
+            }
+        });
+
+        // The scan runs in its own transaction, so batches committed while
+        // it is open don't shift its position
+        const scan = this.transaction();
+        try {
+            let batch: Buffer[] = [];
+            for await (const [key] of scan.scanPrefix(prefix, { signal: options?.signal })) {
+                if (!matches(key)) continue;
+                result.count++;
+                if (result.sampleKeys.length < sampleSize) {
+                    result.sampleKeys.push(key);
+                }
+                if (dryRun) continue;
+
+                batch.push(key);
+                if (batch.length >= batchSize) {
+                    await deleteBatch(batch);
+                    batch = [];
+                }
+            }
+            if (batch.length > 0) {
+                await deleteBatch(batch);
+            }
+        } finally {
+            await scan.abort();
+        }
+
+        return result;
+    }
+
+    /**
+     * Undo the most recent deletePrefix/deleteRange/deleteTree/importJsonl
+     *
+     * Requires the database to be opened with `undoJournal` enabled. Only
+     * operations inside the journal's retention window can be undone, and
+     * only while the keys they wrote are unchanged unless `force` is set.
+     *
+     * @returns What was undone, or null if there is nothing to undo
+     */
+    async undoLastAdminOp(options?: UndoOptions): Promise<UndoResult | null> {
+        this.ensureOpen();
+        if (!this.undoJournal) {
+            throw new DatabaseError('Undo journal is not enabled. Open the database with { undoJournal: true }.');
+        }
+        return this.undoJournal.undoLast(options);
+    }
+
+    /**
+     * Undo journal, if enabled
+     * @internal
+     */
+    getUndoJournal(): UndoJournal | null {
+        return this.undoJournal;
+    }
+
+    /**
+     * Scan keys with prefix, returning a derived output per entry
+     *
+     * @param prefix - Key prefix to scan
+     * @param projection - `'keys'`, `'length'`, or `{ jsonField: 'path.to.field' }`
+     * @param options - Optional scan options
+     */
+    async *scanProjected(prefix: BytesLike, projection: ScanProjection, options?: ScanOptions): AsyncGenerator<ProjectedEntry> {
+        this.ensureOpen();
+
+        const txn = this.transaction();
+        try {
+            for await (const entry of txn.scanProjected(prefix, projection, options)) {
+                yield entry;
+            }
+            await txn.commit();
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Get a view of the database that encodes and decodes values
+     *
+     * @example
+     * ```typescript
+     * const users = db.encoded<{ name: string }>('json');
+     * await users.put('user:1', { name: 'Alice' });
+     * const alice = await users.get('user:1'); // { name: 'Alice' }
+     *
+     * const text = db.encoded('utf8');
+     * await text.put('greeting', 'hello');
+     * ```
+     */
+    encoded<T = unknown>(encoding: 'json'): EncodedView<T>;
+    encoded(encoding: 'utf8'): EncodedView<string>;
+    encoded(encoding: 'buffer'): EncodedView<Buffer>;
+    encoded(encoding: ValueEncoding): EncodedView<any> {
+        this.ensureOpen();
+        return new EncodedView(this, encoding);
+    }
+
+    /**
+     * Get a handle to a named keyspace
+     *
+     * When options are given they are applied by the native engine to every
+     * key in the keyspace, so blob-heavy and index-heavy data can be tuned
+     * independently. Omitted options keep the engine defaults.
+     *
+     * @param name - Keyspace name (must not contain '/')
+     * @param options - Optional compression, cache priority and bloom filter settings
+     */
+    keyspace(name: string, options?: KeyspaceOptions): Keyspace {
+        this.ensureOpen();
+
+        const keyspace = new Keyspace(this, name, options);
+        if (options) {
+            if (!this.bindings.sochdb_keyspace_configure) {
+                throw new DatabaseError(
+                    'Per-keyspace settings are not supported by the loaded SochDB native library. ' +
+                    'Please upgrade the native library or open the keyspace without options.'
+                );
+            }
+            const res = this.bindings.sochdb_keyspace_configure(this.handle, name, toNativeKeyspaceConfig(options));
+            if (res !== 0) {
+                throw new DatabaseError(`Failed to configure keyspace '${name}'`);
+            }
+        }
+        return keyspace;
+    }
+
+    /**
+     * Cheap token that changes whenever a transaction commits
+     *
+     * Derived from the last committed LSN, so it only ever increases and is
+     * safe to call on hot paths (no internal locks, unlike `stats()`).
+     *
+     * @example
+     * ```typescript
+     * app.get('/items', async (req, res) => {
+     *     const etag = `"${db.stateToken()}"`;
+     *     if (req.headers['if-none-match'] === etag) return res.status(304).end();
+     *     res.setHeader('ETag', etag);
+     *     res.json(await listItems());
+     * });
+     * ```
+     */
+    stateToken(): string {
+        this.ensureOpen();
+        if (!this.bindings.sochdb_last_committed_lsn) {
+            throw new DatabaseError(
+                'State tokens are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const lsn = BigInt(this.bindings.sochdb_last_committed_lsn(this.handle));
+        return lsn.toString(16).padStart(16, '0');
+    }
+
+    /**
+     * Begin a transaction
+     */
+    transaction(options?: TransactionOptions): EmbeddedTransaction {
+        this.ensureOpen();
+
+        if (options?.snapshot && options.readOnly) {
+            throw new DatabaseError('readOnly cannot be combined with snapshot; read through the snapshot instead');
+        }
+        let txn: EmbeddedTransaction;
+        if (options?.snapshot) {
+            txn = this.beginAtSnapshot(options.snapshot);
+        } else if (options?.readOnly) {
+            txn = this.beginReadOnly();
+        } else {
+            const txnHandle = this.bindings.sochdb_begin_txn(this.handle);
+            txn = this.trackTransaction(new EmbeddedTransaction(this, this.handle, txnHandle));
+        }
+        txn.defaultAck = options?.ack;
+        txn.label = options?.label;
+        if (options?.timeoutMs !== undefined) {
+            try {
+                txn.setDeadline(options.timeoutMs);
+            } catch (error) {
+                void txn.abort();
+                throw error;
+            }
+        }
+        return txn;
+    }
+
+    /**
+     * Outcome and duration counters per transaction label
+     *
+     * Labels with the most conflicts come first; transactions started
+     * without a label are grouped under `(unlabeled)`.
+     *
+     * @example
+     * ```typescript
+     * await db.withTransaction(checkout, { label: 'checkout' });
+     *
+     * for (const s of db.transactionStats()) {
+     *     console.log(s.label, s.conflicts, s.slow, s.maxDurationMs);
+     * }
+     * ```
+     */
+    transactionStats(options?: { reset?: boolean }): TransactionLabelStats[] {
+        const stats = this.transactionStatsRegistry.snapshot();
+        if (options?.reset) {
+            this.transactionStatsRegistry.reset();
+        }
+        return stats;
+    }
+
+    /**
+     * @internal
+     */
+    recordTransaction(txn: EmbeddedTransaction, outcome: TransactionOutcome, durationMs: number): void {
+        if (txn.handleKind !== 'transaction') return;
+
+        const slow = this.slowTransactionMs !== undefined && durationMs >= this.slowTransactionMs;
+        this.transactionStatsRegistry.record(txn.label, outcome, durationMs, slow);
+        if (slow) {
+            this.reportSlowTransaction(txn, outcome, durationMs);
+        }
+    }
+
+    private watchSlowTransactions(thresholdMs: number): void {
+        this.slowTransactionTimer = setInterval(() => this.checkOpenTransactions(), thresholdMs);
+        // Never keep the process alive just to watch transactions
+        this.slowTransactionTimer.unref();
+    }
+
+    /**
+     * Report transactions that are still open past the threshold, once each
+     */
+    private checkOpenTransactions(): void {
+        const threshold = this.slowTransactionMs;
+        if (threshold === undefined || this.closed) return;
+        for (const txn of this.liveTransactions) {
+            if (txn.handleKind !== 'transaction' || txn.reportedSlow) continue;
+            const durationMs = txn.elapsedMs();
+            if (durationMs >= threshold) {
+                txn.reportedSlow = true;
+                this.reportSlowTransaction(txn, 'open', durationMs);
+            }
+        }
+    }
+
+    private reportSlowTransaction(txn: EmbeddedTransaction, outcome: SlowTransactionEvent['outcome'], durationMs: number): void {
+        const event: SlowTransactionEvent = {
+            label: txn.label ?? UNLABELED,
+            outcome,
+            durationMs,
+            snapshotTs: txn.snapshotTs,
+        };
+        console.warn(
+            outcome === 'open'
+                ? `[SochDB] Slow transaction '${event.label}' has been open for ${durationMs.toFixed(1)}ms`
+                : `[SochDB] Slow transaction '${event.label}' (${outcome}) took ${durationMs.toFixed(1)}ms`
+        );
+        this.emit('transaction:slow', event);
+    }
+
+    /**
+     * Begin a transaction, optionally continuing from an existing snapshot
+     *
+     * @example
+     * ```typescript
+     * const snap = db.snapshot();
+     * const balance = decode(await snap.get('acct/1'));
+     * // ...more reads through the snapshot...
+     *
+     * const txn = db.beginTransaction({ snapshot: snap });
+     * await txn.put('acct/1', encode(balance - 10));
+     * await txn.commit(); // fails if acct/1 changed since the snapshot
+     * snap.release();
+     * ```
+     */
+    beginTransaction(options?: TransactionOptions): EmbeddedTransaction {
+        return this.transaction(options);
+    }
+
+    private beginReadOnly(): EmbeddedTransaction {
+        if (!this.bindings.sochdb_begin_txn_readonly) {
+            throw new DatabaseError(
+                'Read-only transactions are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const txnHandle = this.bindings.sochdb_begin_txn_readonly(this.handle);
+        return this.trackTransaction(new EmbeddedTransaction(this, this.handle, txnHandle));
+    }
+
+    private beginAtSnapshot(snapshot: Snapshot): EmbeddedTransaction {
+        // Once released, the versions at its LSN may already be reclaimed
+        if (snapshot.isReleased) {
+            throw new DatabaseError('Cannot begin a transaction from a released snapshot');
+        }
+        return this.beginAt(snapshot.lsn);
+    }
+
+    private beginAt(ts: bigint): EmbeddedTransaction {
+        if (!this.bindings.sochdb_begin_txn_at) {
+            throw new DatabaseError(
+                'Starting transactions from a snapshot is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const txnHandle = this.bindings.sochdb_begin_txn_at(this.handle, ts);
+        if (!txnHandle || BigInt(txnHandle.txn_id) === 0n) {
+            throw new TransactionError(`Failed to begin transaction at snapshot ${ts}`);
+        }
+        return this.trackTransaction(new EmbeddedTransaction(this, this.handle, txnHandle));
+    }
+
+    /**
+     * Register a named key prefix
+     *
+     * The prefix is validated by the native engine against every previously
+     * registered prefix; overlapping prefixes are rejected.
+     *
+     * @example
+     * ```typescript
+     * db.definePrefix('user', 'u/');
+     * db.definePrefix('order', 'o/');
+     *
+     * await db.put(db.keys.user(42), Buffer.from('Alice'));   // key "u/42"
+     * await db.put(db.keys.order(42, 7), Buffer.from('...')); // key "o/42/7"
+     * ```
+     */
+    definePrefix(name: string, prefix: string): KeyPrefix {
+        this.ensureOpen();
+
+        if (!this.bindings.sochdb_register_prefix) {
+            throw new DatabaseError(
+                'Key prefixes are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        this.prefixRegistry.validate(name, prefix);
+        const res = this.bindings.sochdb_register_prefix(this.handle, name, prefix);
+        if (res !== 0) {
+            throw new DatabaseError(`Native engine rejected prefix '${name}' ("${prefix}")`);
+        }
+        return this.prefixRegistry.add(name, prefix);
+    }
+
+    /**
+     * Typed key constructors for registered prefixes
+     */
+    get keys(): KeyConstructors {
+        return this.prefixRegistry.keys;
+    }
+
+    /**
+     * Look up a registered prefix by name, or by a key that belongs to it
+     */
+    prefix(nameOrKey: string | Buffer): KeyPrefix | undefined {
+        return typeof nameOrKey === 'string'
+            ? this.prefixRegistry.get(nameOrKey)
+            : this.prefixRegistry.route(nameOrKey);
+    }
+
+    /**
+     * Pin a point-in-time snapshot for consistent multi-call reads
+     *
+     * Release the snapshot when done; it holds back garbage collection of
+     * old versions while alive.
+     *
+     * @example
+     * ```typescript
+     * // Re-open the exact state an earlier report ran against
+     * const snap = db.snapshot({ lsn: BigInt(previous.snapshot_lsn) });
+     * try {
+     *     const hits = await collection.search({ queryVector, k: 10, snapshot: snap });
+     *     const users = await db.listPath('users', { snapshot: snap });
+     * } finally {
+     *     snap.release();
+     * }
+     * ```
+     */
+    snapshot(options: SnapshotOptions = {}): Snapshot {
+        this.ensureOpen();
+        if (options.lsn !== undefined) {
+            return new Snapshot(this.beginAt(options.lsn));
+        }
+        return new Snapshot(this.transaction());
+    }
+
+    /**
+     * Extend the TTL of every expiring key under `prefix` to `ttlMs` from now
+     *
+     * Runs natively in one pass, so keys that must stay alive together
+     * (e.g. a session spread over many keys) are refreshed together. Keys
+     * without a TTL are left alone.
+     *
+     * @returns Number of keys whose expiry was extended
+     *
+     * @example
+     * ```typescript
+     * await db.put(`session/${id}/profile`, profile, { ttlMs: 30 * 60_000 });
+     * await db.put(`session/${id}/cart`, cart, { ttlMs: 30 * 60_000 });
+     *
+     * // on every request
+     * await db.touchPrefix(`session/${id}/`, 30 * 60_000);
+     * ```
+     */
+    async touchPrefix(prefix: BytesLike, ttlMs: number): Promise<number> {
+        this.ensureOpen();
+        if (!this.bindings.sochdb_touch_prefix) {
+            throw new DatabaseError(
+                'Key expiry is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        checkTtl(ttlMs);
+        const prefixBuf = toBuffer(prefix);
+        const touched = this.bindings.sochdb_touch_prefix(
+            this.handle, prefixBuf, prefixBuf.length, BigInt(Math.floor(this.now() + ttlMs))
+        );
+        if (touched < 0) {
+            throw new DatabaseError(`Failed to touch prefix '${prefixBuf.toString()}' (Code ${touched})`);
+        }
+        return Number(touched);
+    }
+
+    /**
+     * Mark a prefix append-only
+     *
+     * New keys can still be written under the prefix, but the engine rejects
+     * any update or delete of an existing key with an AppendOnlyViolationError,
+     * whichever SDK or handle attempts it. The setting is persistent and
+     * cannot be removed.
+     *
+     * @example
+     * ```typescript
+     * db.setAppendOnly('audit/');
+     * await db.put(`audit/${Date.now()}`, entry);  // ok
+     * await db.delete('audit/1700000000000');       // throws AppendOnlyViolationError
+     * ```
+     */
+    setAppendOnly(prefix: BytesLike): void {
+        this.ensureOpen();
+        if (!this.bindings.sochdb_set_append_only) {
+            throw new DatabaseError(
+                'Append-only prefixes are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const prefixBuf = toBuffer(prefix);
+        const res = this.bindings.sochdb_set_append_only(this.handle, prefixBuf, prefixBuf.length);
+        if (res !== 0) {
+            throw new DatabaseError(`Failed to mark prefix '${prefixBuf.toString()}' append-only (Code ${res})`);
+        }
+    }
+
+    /**
+     * Set the retention policy for a prefix, enforced by compaction
+     *
+     * Replaces any existing policy for the prefix; pass `{}` to remove it.
+     * Dropped entries are counted in `retentionStats()`.
+     *
+     * @example
+     * ```typescript
+     * db.setRetention('events/', { maxAge: '30d', maxBytes: 10 * 1024 ** 3 });
+     * ```
+     */
+    setRetention(prefix: BytesLike, policy: RetentionPolicy): void {
+        this.ensureOpen();
+        if (!this.bindings.sochdb_set_retention) {
+            throw new DatabaseError(
+                'Retention policies are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const prefixBuf = toBuffer(prefix);
+        const { maxAgeMs, maxBytes } = toNativeRetention(policy);
+        const res = this.bindings.sochdb_set_retention(
+            this.handle, prefixBuf, prefixBuf.length, BigInt(maxAgeMs), BigInt(maxBytes)
+        );
+        if (res !== 0) {
+            throw new DatabaseError(`Failed to set retention for prefix '${prefixBuf.toString()}' (Code ${res})`);
+        }
+    }
+
+    /**
+     * Retention policies and what compaction has dropped under each
+     */
+    retentionStats(): RetentionStats[] {
+        this.ensureOpen();
+        return parseRetentionStats(this.readNativeString('Retention stats', this.bindings.sochdb_retention_stats_json));
+    }
+
+    /**
+     * Get a view of the database confined to `prefix`
+     *
+     * Keys and paths passed to the scope are stored under the prefix, and
+     * scans return keys relative to it, so a library can be handed a
+     * sandboxed view instead of the whole database. A "/" is appended to a
+     * prefix that does not end in one. With `readOnly`, every transaction the
+     * scope opens is read-only in the native layer.
+     *
+     * @example
+     * ```typescript
+     * plugin.init(db.scope('plugins/search/', { readOnly: true }));
+     * ```
+     */
+    scope(prefix: string, options?: ScopeOptions): ScopedDatabase {
+        this.ensureOpen();
+        return new ScopedDatabase(this, prefix, options);
+    }
+
+    /**
+     * Get an additional read-only handle on this database
+     *
+     * Reader handles share this database's engine state (no new open and no
+     * extra file locks) and can be closed independently. Closing the
+     * database closes every reader handle.
+     */
+    readerHandle(): ReaderHandle {
+        this.ensureOpen();
+        const reader = new ReaderHandle(this);
+        this.readers.add(reader);
+        return reader;
+    }
+
+    /**
+     * @internal
+     */
+    releaseReader(reader: ReaderHandle): void {
+        this.readers.delete(reader);
+    }
+
+    /**
+     * Create a batch of writes that is applied atomically by `write()`
+     */
+    batch(): WriteBatch {
+        this.ensureOpen();
+        return new WriteBatch(this);
+    }
+
+    /**
+     * Execute operations within a transaction (with auto-commit/abort)
+     */
+    async withTransaction<T>(fn: (txn: EmbeddedTransaction) => Promise<T>, options?: TransactionOptions): Promise<T> {
+        const txn = this.transaction(options);
         try {
             const result = await fn(txn);
             await txn.commit();
@@ -310,11 +1760,397 @@ export class EmbeddedDatabase {
 
     /**
      * Force a checkpoint
+     *
+     * With a signal, the checkpoint runs on a worker thread and aborting the
+     * signal stops it natively; the returned promise rejects with AbortError.
+     *
+     * @param options - Optional AbortSignal that cancels the running checkpoint
+     */
+    async checkpoint(options?: { signal?: AbortSignal }): Promise<bigint> {
+        this.ensureOpen();
+        const signal = options?.signal;
+        AbortError.throwIfAborted(signal);
+        if (signal && !this.bindings.sochdb_checkpoint_cancel) {
+            throw new DatabaseError(
+                'Cancelling checkpoints is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        return traceQuery(this.path, 'checkpoint', undefined, async () => {
+            const lsn = signal
+                ? await this.cancellableCheckpoint(signal, (done) => this.bindings.sochdb_checkpoint.async(this.handle, done))
+                : this.bindings.sochdb_checkpoint(this.handle);
+            return BigInt(lsn);
+        });
+    }
+
+    /**
+     * Run a native checkpoint call on a worker thread and stop it natively
+     * when `signal` aborts
+     */
+    private cancellableCheckpoint<T extends number | bigint>(
+        signal: AbortSignal,
+        start: (done: (err: any, res: T) => void) => void
+    ): Promise<T> {
+        return new Promise((resolve, reject) => {
+            const cancel = () => this.bindings.sochdb_checkpoint_cancel(this.handle);
+            signal.addEventListener('abort', cancel, { once: true });
+            start((err, res) => {
+                signal.removeEventListener('abort', cancel);
+                if (err) {
+                    reject(new DatabaseError(`Checkpoint failed: ${err}`));
+                } else if (Number(res) === CHECKPOINT_CANCELLED) {
+                    reject(new AbortError('Checkpoint was cancelled', signal.reason));
+                } else {
+                    resolve(res);
+                }
+            });
+        });
+    }
+
+    /**
+     * Materialize a consistent on-disk checkpoint in a directory
+     *
+     * The checkpoint can be opened as a database or shipped to another
+     * machine. Unchanged SST files are hard-linked when the target is on the
+     * same filesystem. With `incremental`, only changes after `baseLsn` are
+     * written, so a chain of checkpoints can be shipped cheaply.
+     *
+     * @example
+     * ```typescript
+     * const full = await db.checkpointTo('/backups/ckpt-1');
+     * const delta = await db.checkpointTo('/backups/ckpt-2', { incremental: true, baseLsn: full.lsn });
+     * ```
+     */
+    async checkpointTo(dir: string, options?: CheckpointToOptions): Promise<CheckpointInfo> {
+        this.ensureOpen();
+        const signal = options?.signal;
+        AbortError.throwIfAborted(signal);
+
+        if (!this.bindings.sochdb_checkpoint_to) {
+            throw new DatabaseError(
+                'checkpointTo() is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        if (signal && !this.bindings.sochdb_checkpoint_cancel) {
+            throw new DatabaseError(
+                'Cancelling checkpoints is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+
+        const incremental = options?.incremental ?? false;
+        if (incremental && options?.baseLsn === undefined) {
+            throw new DatabaseError('Incremental checkpoints require baseLsn');
+        }
+
+        const baseLsn = options?.baseLsn ?? 0n;
+        const lsn = BigInt(signal
+            ? await this.cancellableCheckpoint(signal, (done) =>
+                this.bindings.sochdb_checkpoint_to.async(this.handle, dir, incremental, baseLsn, done))
+            : this.bindings.sochdb_checkpoint_to(this.handle, dir, incremental, baseLsn));
+        if (lsn < 0n) {
+            throw new DatabaseError(`Failed to write checkpoint to ${dir} (Code ${lsn})`);
+        }
+
+        return {
+            path: dir,
+            lsn,
+            incremental,
+            baseLsn: incremental ? options?.baseLsn : undefined,
+        };
+    }
+
+    /**
+     * Export all entries under a prefix to a JSONL file
+     *
+     * The export reads from a single pinned snapshot, so concurrent writes
+     * never produce a mix of old and new state. The snapshot LSN is returned
+     * and (unless `header: false`) written as the first line of the file.
+     *
+     * @param prefix - Key prefix to export (empty buffer exports everything)
+     * @param filePath - Destination file
+     * @param options - Header and cancellation options
+     */
+    async exportJsonl(prefix: BytesLike, filePath: string, options?: ExportOptions): Promise<ExportResult> {
+        this.ensureOpen();
+
+        const txn = this.transaction();
+        try {
+            const result = await exportJsonl(txn, toBuffer(prefix), filePath, () => this.now(), options);
+            await txn.commit();
+            return result;
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Export several prefixes from one snapshot into `dir`, with a manifest
+     *
+     * Every file is read from the same pinned snapshot. The manifest records
+     * the snapshot LSN and each file's prefix, entry count, size and SHA-256,
+     * so a release can be checked later with `verifyExportManifest(dir)`.
+     *
+     * @example
+     * ```typescript
+     * const manifest = await db.exportDataset('./release-2026-10', {
+     *     prefixes: ['users/', 'orders/'],
+     *     metadata: { release: '2026-10' },
+     * });
+     * console.log(`exported at LSN ${manifest.snapshot_lsn}`);
+     * ```
+     */
+    async exportDataset(dir: string, options: DatasetExportOptions): Promise<ExportManifest> {
+        this.ensureOpen();
+
+        const txn = this.transaction();
+        try {
+            const manifest = await exportDataset(txn, dir, () => this.now(), options);
+            await txn.commit();
+            return manifest;
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Freeze the current contents into a deterministic fixture file
+     *
+     * Equal contents always give byte-identical files on every platform, so
+     * fixtures can be committed and diffed. Expiry deadlines and SDK
+     * bookkeeping under `_admin/` are not recorded.
+     *
+     * @example
+     * ```typescript
+     * await seed(db);
+     * await db.freezeToFixture('./test/fixtures/seeded.sochdb-fixture');
+     * ```
+     */
+    async freezeToFixture(file: string, options?: FixtureOptions): Promise<FixtureResult> {
+        this.ensureOpen();
+        return this.read(undefined, (txn) => writeFixture(txn, file, options)) as Promise<FixtureResult>;
+    }
+
+    /**
+     * Load a fixture written by `freezeToFixture()` into a temporary,
+     * read-only database
+     *
+     * The fixture is loaded into a temporary directory which is then reopened
+     * natively read-only: writes are rejected with ReadOnlyError and no
+     * checkpoints or compactions run, so the files stay exactly as loaded.
+     * The temporary directory is removed when the database is closed.
+     *
+     * @example
+     * ```typescript
+     * const db = await Database.openFixture('./test/fixtures/seeded.sochdb-fixture');
+     * expect((await db.get('users/alice'))?.toString()).toBe('{"name":"Alice"}');
+     * db.close();
+     * ```
+     */
+    static async openFixture(file: string, config?: EmbeddedDatabaseConfig): Promise<EmbeddedDatabase> {
+        const bindings = NativeBindings.getInstance();
+        if (!bindings.sochdb_open_read_only) {
+            throw new DatabaseError(
+                'Read-only fixture databases are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+
+        const dir = await createFixtureDir();
+        let handle;
+        try {
+            const loader = EmbeddedDatabase.open(dir, config);
+            try {
+                if (loader.bindings.sochdb_bulk_loader_new) {
+                    await loader.ingestSorted(readFixture(file));
+                } else {
+                    await loader.withTransaction(async (txn) => {
+                        for await (const [key, value] of readFixture(file)) {
+                            await txn.put(key, value);
+                        }
+                    });
+                }
+            } finally {
+                loader.close();
+            }
+
+            handle = bindings.sochdb_open_read_only(dir);
+            if (!handle) {
+                throw new DatabaseError(`Failed to reopen fixture database at ${dir} read-only`);
+            }
+        } catch (error) {
+            fs.rmSync(dir, { recursive: true, force: true });
+            throw error;
+        }
+
+        const db = EmbeddedDatabase.wrapHandle(dir, handle, { ...config, undoJournal: undefined });
+        db.fixtureDir = dir;
+        db.fixtureReadOnly = true;
+        return db;
+    }
+
+    /**
+     * Import a JSONL file produced by `exportJsonl()`
+     *
+     * Existing keys are handled by the conflict policy: `'skip'`, `'overwrite'`
+     * and `'fail'` are evaluated by the native engine per key; a merge callback
+     * receives the existing and incoming values and returns the value to store.
+     *
+     * @example
+     * ```typescript
+     * await db.importJsonl('./users.jsonl', { conflict: 'skip' });
+     * await db.importJsonl('./counters.jsonl', {
+     *     conflict: (key, existing, incoming) =>
+     *         Buffer.from(String(Number(existing) + Number(incoming))),
+     * });
+     * ```
+     */
+    async importJsonl(filePath: string, options?: ImportOptions): Promise<ImportResult> {
+        this.ensureOpen();
+        return importJsonl(this, filePath, options);
+    }
+
+    /**
+     * Create a loader that writes presorted entries directly into SST files
+     *
+     * Bypasses the memtable and WAL; use for initial loads of large sorted
+     * datasets. Keys must be added in strictly increasing order.
+     */
+    bulkLoader(): BulkLoader {
+        this.ensureOpen();
+        if (this.fixtureReadOnly) {
+            throw new ReadOnlyError('Fixture databases are read-only');
+        }
+        if (!this.bindings.sochdb_bulk_loader_new) {
+            throw new DatabaseError(
+                'Bulk loading is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const handle = this.bindings.sochdb_bulk_loader_new(this.handle);
+        if (!handle) {
+            throw new DatabaseError('Failed to create bulk loader');
+        }
+        return new BulkLoader(this.bindings, handle, this._sizeLimits);
+    }
+
+    /**
+     * Ingest presorted entries, or a presorted JSONL file in `exportJsonl()` format
+     *
+     * @example
+     * ```typescript
+     * await db.ingestSorted('./users.sorted.jsonl');
+     * await db.ingestSorted(generateSortedEntries());
+     * ```
+     */
+    async ingestSorted(
+        source: string | Iterable<[BytesLike, BytesLike]> | AsyncIterable<[BytesLike, BytesLike]>
+    ): Promise<BulkLoadResult> {
+        const loader = this.bulkLoader();
+        try {
+            if (typeof source === 'string') {
+                const lines = readline.createInterface({ input: fs.createReadStream(source), crlfDelay: Infinity });
+                let first = true;
+                for await (const line of lines) {
+                    if (line.length === 0) continue;
+                    if (first) {
+                        first = false;
+                        if (parseExportHeader(line)) continue;
+                    }
+                    const entry = JSON.parse(line);
+                    loader.add(Buffer.from(entry.key, 'base64'), Buffer.from(entry.value, 'base64'));
+                }
+            } else {
+                for await (const [key, value] of source) {
+                    loader.add(key, value);
+                }
+            }
+            return loader.finish();
+        } catch (error) {
+            loader.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Watch resource usage against soft limits
+     *
+     * Usage is sampled every `intervalMs`; crossing a threshold emits one
+     * `'alert'` event with state `raised`, and dropping back below it emits
+     * one with state `cleared`. Pass `{}` to stop watching.
+     *
+     * @example
+     * ```typescript
+     * db.setAlertThresholds({ walSizeBytes: 512 * 1024 * 1024, activeTransactions: 200 });
+     * db.onAlert((alert) => {
+     *     if (alert.state === 'raised') {
+     *         logger.warn(`${alert.metric} at ${alert.value} (limit ${alert.threshold})`, alert.sample);
+     *     }
+     * });
+     * ```
      */
-    async checkpoint(): Promise<bigint> {
+    setAlertThresholds(thresholds: AlertThresholds, options?: { intervalMs?: number }): void {
         this.ensureOpen();
-        const lsn = this.bindings.sochdb_checkpoint(this.handle);
-        return BigInt(lsn);
+        const intervalMs = resolveAlertInterval(options?.intervalMs);
+        this.stopAlerts();
+        if (Object.values(thresholds).every((t) => t === undefined)) {
+            this.alertTracker = null;
+            return;
+        }
+
+        if (this.alertTracker) {
+            this.alertTracker.thresholds = { ...thresholds };
+        } else {
+            this.alertTracker = new AlertTracker({ ...thresholds });
+        }
+        this.alertTimer = setInterval(() => void this.checkAlerts(), intervalMs);
+        // Never keep the process alive just to watch usage
+        this.alertTimer.unref();
+    }
+
+    /**
+     * Register an alert callback; returns a function that removes it
+     */
+    onAlert(callback: AlertCallback): () => void {
+        this.on('alert', callback);
+        return () => this.off('alert', callback);
+    }
+
+    private async checkAlerts(): Promise<void> {
+        const tracker = this.alertTracker;
+        if (!tracker || this.alertCheckRunning || this.closed) return;
+
+        this.alertCheckRunning = true;
+        try {
+            const stats = await this.stats();
+            const sample: AlertSample = {
+                walSizeBytes: Number(stats.walSizeBytes),
+                memtableSizeBytes: Number(stats.memtableSizeBytes),
+                activeTransactions: stats.activeTransactions,
+                // Walking the directory is comparatively expensive; only do it when watched
+                diskUsageBytes: tracker.thresholds.diskUsageBytes !== undefined ? await directorySize(this.path) : 0,
+            };
+            const timestamp = this.now();
+            for (const transition of tracker.update(sample)) {
+                const event: AlertEvent = { ...transition, database: this.path, sample, timestamp };
+                this.emit('alert', event);
+            }
+        } catch (error) {
+            console.warn('[SochDB] Alert check failed:', error);
+        } finally {
+            this.alertCheckRunning = false;
+        }
+    }
+
+    private stopAlerts(): void {
+        if (this.alertTimer) {
+            clearInterval(this.alertTimer);
+            this.alertTimer = null;
+        }
     }
 
     /**
@@ -343,16 +2179,218 @@ export class EmbeddedDatabase {
         return result;
     }
 
+    /**
+     * Get per-operation latency percentiles and throughput recorded by the native engine
+     */
+    async metrics(): Promise<DatabaseMetrics> {
+        this.ensureOpen();
+        const json = this.readNativeString('metrics()', this.bindings.sochdb_metrics_json);
+        return parseMetrics(json);
+    }
+
+    /**
+     * Get engine metrics in the Prometheus text exposition format
+     *
+     * @example
+     * ```typescript
+     * app.get('/metrics', async (_req, res) => {
+     *     res.type('text/plain').send(await db.metricsPrometheus());
+     * });
+     * ```
+     */
+    async metricsPrometheus(): Promise<string> {
+        this.ensureOpen();
+        return this.readNativeString('metricsPrometheus()', this.bindings.sochdb_metrics_prometheus);
+    }
+
+    /**
+     * Report whether writes are currently stalled, why, and for how long
+     *
+     * @example
+     * ```typescript
+     * const stall = db.stallInfo();
+     * if (stall.stalled) {
+     *     logger.warn(`writes stalled ${stall.stalledForMs}ms: ${stall.reasons.join(', ')}`);
+     * }
+     * ```
+     */
+    stallInfo(): StallInfo {
+        this.ensureOpen();
+        if (!this.bindings.sochdb_stall_info) {
+            throw new DatabaseError(
+                'Stall diagnostics are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        return fromNativeStallInfo(this.bindings.sochdb_stall_info(this.handle));
+    }
+
+    /**
+     * Memory held natively by this database (memtables, block cache, pinned values)
+     *
+     * Also reported to V8 as external memory when the external-memory addon
+     * is installed; see `./memory`.
+     */
+    nativeMemoryUsage(): NativeMemoryUsage {
+        this.ensureOpen();
+        if (!this.bindings.sochdb_memory_usage) {
+            throw new DatabaseError(
+                'Native memory accounting is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        return fromNativeMemoryUsage(this.bindings.sochdb_memory_usage(this.handle));
+    }
+
+    /**
+     * Feed changes in native memory use to V8 so they count towards GC pressure
+     */
+    private startExternalMemoryReporting(intervalMs: number): void {
+        const adjust = intervalMs > 0 && this.bindings.sochdb_memory_usage
+            ? externalMemoryAdjuster()
+            : null;
+        if (adjust) {
+            this.externalMemory = new ExternalMemoryReporter(
+                () => fromNativeMemoryUsage(this.bindings.sochdb_memory_usage(this.handle)).totalBytes,
+                adjust,
+                intervalMs
+            );
+        }
+    }
+
+    /**
+     * Call a native `(db, out_ptr, out_len) -> int` function and decode its UTF-8 output
+     */
+    private readNativeString(feature: string, fn: any): string {
+        if (!fn) {
+            throw new DatabaseError(
+                `${feature} is not supported by the loaded SochDB native library. ` +
+                'Please upgrade the native library.'
+            );
+        }
+
+        const outPtr = [null];
+        const outLen = [0];
+        const res = fn(this.handle, outPtr, outLen);
+        if (res !== 0) {
+            throw new DatabaseError(`${feature} failed (Code ${res})`);
+        }
+
+        const text = Buffer.from(koffi.decode(outPtr[0], 'uint8', outLen[0])).toString('utf8');
+        this.bindings.sochdb_free_bytes(outPtr[0], outLen[0]);
+        return text;
+    }
+
     /**
      * Close the database
      */
     close(): void {
         if (!this.closed) {
-            this.bindings.sochdb_close(this.handle);
-            this.closed = true;
+            try {
+                this.eventPoller.stop();
+                this.stopAlerts();
+                this.externalMemory?.stop();
+                this.externalMemory = null;
+                if (this.slowTransactionTimer) {
+                    clearInterval(this.slowTransactionTimer);
+                    this.slowTransactionTimer = null;
+                }
+                for (const reader of [...this.readers]) {
+                    reader.close();
+                }
+                // Fail open transactions, snapshots and iterators cleanly instead of
+                // letting them touch freed native state
+                for (const txn of [...this.liveTransactions]) {
+                    txn.invalidate('database_closed');
+                }
+            } finally {
+                this.bindings.sochdb_close(this.handle);
+                this.closed = true;
+            }
+            if (this.fixtureDir) {
+                fs.rmSync(this.fixtureDir, { recursive: true, force: true });
+            }
+            if (channels.databaseClose.hasSubscribers) {
+                channels.databaseClose.publish({ database: this.path });
+            }
+        }
+    }
+
+    private trackTransaction(txn: EmbeddedTransaction): EmbeddedTransaction {
+        this.liveTransactions.add(txn);
+        return txn;
+    }
+
+    /**
+     * @internal
+     */
+    releaseTransaction(txn: EmbeddedTransaction): void {
+        this.liveTransactions.delete(txn);
+    }
+
+    /**
+     * Emit `'handle:invalidated'` so apps can re-create the handle
+     *
+     * @example
+     * ```typescript
+     * db.on('handle:invalidated', (e: HandleInvalidatedEvent) => {
+     *     if (e.kind === 'snapshot' && e.reason === 'compaction') {
+     *         reportSnapshot = db.snapshot();
+     *     }
+     * });
+     * ```
+     * @internal
+     */
+    notifyHandleInvalidated(event: HandleInvalidatedEvent): void {
+        this.emit('handle:invalidated', event);
+    }
+
+    /**
+     * Begin draining native background events once someone listens for them
+     *
+     * @example
+     * ```typescript
+     * db.on('compaction:finish', (e: BackgroundEvent) => {
+     *     metrics.histogram('sochdb.compaction.ms', e.durationMs);
+     * });
+     * ```
+     */
+    private startEventPolling(): void {
+        if (this.closed || this.eventPoller.running) return;
+        if (!this.bindings.sochdb_poll_events) {
+            console.warn(
+                '[SochDB] Background events are not supported by the loaded native library. ' +
+                'Flush/compaction listeners will not fire.'
+            );
+            return;
+        }
+        this.eventPoller.start();
+    }
+
+    private pollBackgroundEvents(): any[] {
+        if (this.closed) return [];
+        return JSON.parse(this.readNativeString('Background events', this.bindings.sochdb_poll_events));
+    }
+
+    /**
+     * Surface a failure nobody awaits (an event poll, a rollback callback run
+     * by `close()`) as `'error'`, or log it when nobody listens (an unhandled
+     * `'error'` event would throw from the timer)
+     * @internal
+     */
+    reportBackgroundError(error: unknown, operation = 'Polling background events'): void {
+        if (this.listenerCount('error') > 0) {
+            this.emit('error', error);
+        } else {
+            console.warn(`[SochDB] ${operation} failed: ${(error as Error)?.message ?? error}`);
         }
     }
 
+    private emitBackgroundEvent(event: BackgroundEvent): void {
+        this.emit('background', event);
+        this.emit(`${event.type}:${event.phase}`, event);
+    }
+
     private ensureOpen(): void {
         if (this.closed) {
             throw new DatabaseError('Database is closed');
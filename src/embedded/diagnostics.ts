@@ -0,0 +1,121 @@
+/**
+ * diagnostics_channel Bridge - Embedded Mode
+ *
+ * Publishes operation and lifecycle events on Node's `diagnostics_channel`
+ * so APM tools can instrument the SDK without patching it. Nothing is built
+ * or published unless a channel has subscribers.
+ *
+ * Channels:
+ * - `sochdb:query:start` / `sochdb:query:finish` - data operations; both
+ *   receive the same `QueryMessage` object, so subscribers can attach state
+ *   at start and read it at finish
+ * - `sochdb:transaction:commit` / `sochdb:transaction:abort`
+ * - `sochdb:database:open` / `sochdb:database:close`
+ */
+
+import * as diagnostics_channel from 'diagnostics_channel';
+
+export interface QueryMessage {
+    /** Operation name: get, put, delete, getPath, putPath, scanPrefix, checkpoint, ... */
+    operation: string;
+    /** Database path */
+    database: string;
+    /** Key, path or prefix the operation targets, if any */
+    target?: string;
+    /** Set at finish */
+    durationMs?: number;
+    /** Set at finish when the operation failed */
+    error?: unknown;
+}
+
+export interface TransactionMessage {
+    database: string;
+    /** Label given with `beginTransaction({ label })` */
+    label?: string;
+    snapshotTs: bigint;
+    durationMs: number;
+    error?: unknown;
+}
+
+export interface DatabaseLifecycleMessage {
+    database: string;
+}
+
+export const channels = {
+    queryStart: diagnostics_channel.channel('sochdb:query:start'),
+    queryFinish: diagnostics_channel.channel('sochdb:query:finish'),
+    transactionCommit: diagnostics_channel.channel('sochdb:transaction:commit'),
+    transactionAbort: diagnostics_channel.channel('sochdb:transaction:abort'),
+    databaseOpen: diagnostics_channel.channel('sochdb:database:open'),
+    databaseClose: diagnostics_channel.channel('sochdb:database:close'),
+};
+
+function isTraced(): boolean {
+    return channels.queryStart.hasSubscribers || channels.queryFinish.hasSubscribers;
+}
+
+function startQuery(database: string, operation: string, target: string | Buffer | undefined): QueryMessage {
+    const message: QueryMessage = {
+        operation,
+        database,
+        target: Buffer.isBuffer(target) ? target.toString() : target,
+    };
+    channels.queryStart.publish(message);
+    return message;
+}
+
+/**
+ * Run `fn`, publishing start/finish messages when anyone is listening
+ * @internal
+ */
+export async function traceQuery<T>(
+    database: string,
+    operation: string,
+    target: string | Buffer | undefined,
+    fn: () => Promise<T>
+): Promise<T> {
+    if (!isTraced()) {
+        return fn();
+    }
+
+    const started = performance.now();
+    const message = startQuery(database, operation, target);
+    try {
+        return await fn();
+    } catch (error) {
+        message.error = error;
+        throw error;
+    } finally {
+        message.durationMs = performance.now() - started;
+        channels.queryFinish.publish(message);
+    }
+}
+
+/**
+ * Iterate `scan`, publishing start before the first entry and finish once
+ * the scan completes, fails or is abandoned
+ * @internal
+ */
+export async function* traceScan<T>(
+    database: string,
+    operation: string,
+    target: string | Buffer | undefined,
+    scan: () => AsyncGenerator<T>
+): AsyncGenerator<T> {
+    if (!isTraced()) {
+        yield* scan();
+        return;
+    }
+
+    const started = performance.now();
+    const message = startQuery(database, operation, target);
+    try {
+        yield* scan();
+    } catch (error) {
+        message.error = error;
+        throw error;
+    } finally {
+        message.durationMs = performance.now() - started;
+        channels.queryFinish.publish(message);
+    }
+}
@@ -0,0 +1,76 @@
+/**
+ * Encoded Views - Embedded Mode
+ *
+ * A thin wrapper around EmbeddedDatabase that converts values to and from
+ * a chosen encoding, so callers don't repeat `Buffer.from(JSON.stringify(...))`.
+ */
+
+import { DatabaseError } from '../errors';
+import type { EmbeddedDatabase, PutOptions, ScanOptions } from './database';
+import type { BytesLike } from './key-encoding';
+
+export type ValueEncoding = 'buffer' | 'utf8' | 'json';
+
+/**
+ * Encode a value for storage
+ * @internal
+ */
+export function encodeValue(value: unknown, encoding: ValueEncoding): Buffer {
+    switch (encoding) {
+        case 'buffer':
+            if (!Buffer.isBuffer(value)) {
+                throw new DatabaseError('Expected a Buffer value');
+            }
+            return value;
+        case 'utf8':
+            return Buffer.from(String(value), 'utf8');
+        case 'json': {
+            const json = JSON.stringify(value);
+            if (json === undefined) {
+                throw new DatabaseError('Value is not JSON-serializable');
+            }
+            return Buffer.from(json, 'utf8');
+        }
+    }
+}
+
+/**
+ * Decode a stored value
+ * @internal
+ */
+export function decodeValue(raw: Buffer, encoding: ValueEncoding): unknown {
+    switch (encoding) {
+        case 'buffer':
+            return raw;
+        case 'utf8':
+            return raw.toString('utf8');
+        case 'json':
+            return JSON.parse(raw.toString('utf8'));
+    }
+}
+
+/**
+ * Database view with typed values
+ */
+export class EncodedView<T> {
+    constructor(private db: EmbeddedDatabase, public readonly encoding: ValueEncoding) {}
+
+    async put(key: BytesLike, value: T, options?: PutOptions): Promise<void> {
+        return this.db.put(key, encodeValue(value, this.encoding), options);
+    }
+
+    async get(key: BytesLike): Promise<T | null> {
+        const raw = await this.db.get(key);
+        return raw === null ? null : decodeValue(raw, this.encoding) as T;
+    }
+
+    async delete(key: BytesLike): Promise<void> {
+        return this.db.delete(key);
+    }
+
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, T]> {
+        for await (const [key, raw] of this.db.scanPrefix(prefix, options)) {
+            yield [key, decodeValue(raw, this.encoding) as T];
+        }
+    }
+}
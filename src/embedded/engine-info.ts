@@ -0,0 +1,55 @@
+/**
+ * Engine Introspection - Embedded Mode
+ *
+ * Reports which native library is loaded and what it was built with, for
+ * bug reports and runtime feature detection.
+ */
+
+import * as koffi from 'koffi';
+import { DatabaseError } from '../errors';
+import { NativeBindings } from './ffi/bindings';
+
+export interface EngineInfo {
+    /** Version of the native sochdb_storage library (null if it predates engineInfo) */
+    version: string | null;
+    /** Compile-time features, e.g. `compression-lz4`, `compression-zstd`, `encryption` */
+    features: string[];
+    /** Platform triple the library was built for (e.g. `x86_64-unknown-linux-gnu`) */
+    target: string | null;
+    /** File the library was loaded from */
+    libraryPath: string;
+}
+
+/**
+ * Describe the loaded native engine
+ *
+ * @example
+ * ```typescript
+ * const info = engineInfo();
+ * if (!info.features.includes('encryption')) {
+ *     throw new Error(`sochdb ${info.version} was built without encryption support`);
+ * }
+ * ```
+ */
+export function engineInfo(): EngineInfo {
+    const bindings = NativeBindings.getInstance();
+    if (!bindings.sochdb_engine_info) {
+        return { version: null, features: [], target: null, libraryPath: bindings.libraryPath };
+    }
+
+    const outPtr = [null];
+    const outLen = [0];
+    const res = bindings.sochdb_engine_info(outPtr, outLen);
+    if (res !== 0) {
+        throw new DatabaseError(`Failed to read engine info (Code ${res})`);
+    }
+    const raw = JSON.parse(Buffer.from(koffi.decode(outPtr[0], 'uint8', outLen[0])).toString('utf8'));
+    bindings.sochdb_free_bytes(outPtr[0], outLen[0]);
+
+    return {
+        version: raw.version ?? null,
+        features: raw.features ?? [],
+        target: raw.target ?? null,
+        libraryPath: bindings.libraryPath,
+    };
+}
@@ -0,0 +1,96 @@
+/**
+ * Background Events - Embedded Mode
+ *
+ * The native engine queues structured events for background work
+ * (memtable flushes, compactions). They are drained on a timer and
+ * re-emitted on the EmbeddedDatabase, so operators can correlate latency
+ * spikes with background activity.
+ */
+
+export interface BackgroundEvent {
+    type: 'flush' | 'compaction';
+    phase: 'start' | 'finish';
+    /** LSM level the job writes to (compactions only) */
+    level?: number;
+    /** Key range covered by the job */
+    rangeStart?: Buffer;
+    rangeEnd?: Buffer;
+    /** Bytes read and written by the job (known at finish) */
+    inputBytes?: number;
+    outputBytes?: number;
+    /** Job duration in milliseconds (finish only) */
+    durationMs?: number;
+    /** Wall-clock time the event was recorded, in ms since the epoch */
+    timestamp: number;
+}
+
+/** Event names that require native event polling */
+export const BACKGROUND_EVENT_NAMES = [
+    'background',
+    'flush:start',
+    'flush:finish',
+    'compaction:start',
+    'compaction:finish',
+];
+
+/**
+ * Convert an event from the native JSON queue
+ * @internal
+ */
+export function parseBackgroundEvent(raw: any, now: number): BackgroundEvent {
+    return {
+        type: raw.type,
+        phase: raw.phase,
+        level: raw.level ?? undefined,
+        rangeStart: raw.range_start != null ? Buffer.from(raw.range_start, 'base64') : undefined,
+        rangeEnd: raw.range_end != null ? Buffer.from(raw.range_end, 'base64') : undefined,
+        inputBytes: raw.input_bytes ?? undefined,
+        outputBytes: raw.output_bytes ?? undefined,
+        durationMs: raw.duration_ms ?? undefined,
+        timestamp: raw.timestamp_ms ?? now,
+    };
+}
+
+/**
+ * Periodically drains a native event queue
+ * @internal
+ */
+export class NativeEventPoller {
+    private timer: NodeJS.Timeout | null = null;
+
+    constructor(
+        private poll: () => any[],
+        private onEvent: (event: any) => void,
+        private intervalMs: number,
+        private onError: (error: unknown) => void
+    ) {}
+
+    get running(): boolean {
+        return this.timer !== null;
+    }
+
+    start(): void {
+        if (this.timer) return;
+        this.timer = setInterval(() => this.drain(), this.intervalMs);
+        // Never keep the process alive just to watch for events
+        this.timer.unref();
+    }
+
+    stop(): void {
+        if (!this.timer) return;
+        clearInterval(this.timer);
+        this.timer = null;
+        this.drain();
+    }
+
+    drain(): void {
+        // Runs from a timer, so anything thrown here would be uncaught
+        try {
+            for (const event of this.poll()) {
+                this.onEvent(event);
+            }
+        } catch (error) {
+            this.onError(error);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+/**
+ * Dataset Exports - Embedded Mode
+ *
+ * Exports several prefixes from one pinned snapshot into a directory and
+ * writes a manifest describing the result, so a dataset release produced
+ * from a live database can be verified later.
+ *
+ * Layout:
+ * - `<dir>/<index>.jsonl` - one `exportJsonl` file per prefix
+ * - `<dir>/manifest.json` - `ExportManifest`
+ */
+
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import * as path from 'path';
+import { AbortError, DatabaseError } from '../errors';
+import type { EmbeddedTransaction } from './transaction';
+import { exportJsonl } from './export';
+import { BytesLike, toBuffer } from './key-encoding';
+
+export const MANIFEST_FILE = 'manifest.json';
+export const MANIFEST_FORMAT_VERSION = 1;
+
+export interface DatasetExportOptions {
+    /** Prefixes to export; each becomes one file */
+    prefixes: BytesLike[];
+    /** Free-form metadata copied into the manifest (release name, source, ...) */
+    metadata?: Record<string, unknown>;
+    signal?: AbortSignal;
+}
+
+export interface ManifestFile {
+    /** File name relative to the export directory */
+    file: string;
+    /** Exported prefix, base64-encoded */
+    prefix: string;
+    /** Entries in the file */
+    count: number;
+    bytes: number;
+    /** Hex SHA-256 of the file contents */
+    sha256: string;
+}
+
+export interface ExportManifest {
+    sochdb_export_manifest: number;
+    /** LSN of the snapshot every file was read from */
+    snapshot_lsn: string;
+    created_at: string;
+    metadata?: Record<string, unknown>;
+    files: ManifestFile[];
+}
+
+export interface ManifestVerification {
+    ok: boolean;
+    /** Files that are missing or whose size or hash does not match */
+    mismatches: Array<{ file: string; problem: 'missing' | 'size' | 'sha256' }>;
+}
+
+/**
+ * Export every prefix from the transaction's snapshot and write the manifest
+ * @internal
+ */
+export async function exportDataset(
+    txn: EmbeddedTransaction,
+    dir: string,
+    now: () => number,
+    options: DatasetExportOptions
+): Promise<ExportManifest> {
+    if (options.prefixes.length === 0) {
+        throw new DatabaseError('exportDataset requires at least one prefix');
+    }
+    await fs.promises.mkdir(dir, { recursive: true });
+
+    const files: ManifestFile[] = [];
+    for (const [index, prefixLike] of options.prefixes.entries()) {
+        AbortError.throwIfAborted(options.signal);
+        const prefix = toBuffer(prefixLike);
+        const file = `${index.toString().padStart(4, '0')}.jsonl`;
+        const filePath = path.join(dir, file);
+
+        const { count } = await exportJsonl(txn, prefix, filePath, now, { signal: options.signal });
+        const { bytes, sha256 } = await hashFile(filePath);
+        files.push({ file, prefix: prefix.toString('base64'), count, bytes, sha256 });
+    }
+
+    const manifest: ExportManifest = {
+        sochdb_export_manifest: MANIFEST_FORMAT_VERSION,
+        snapshot_lsn: txn.snapshotTs.toString(),
+        created_at: new Date(now()).toISOString(),
+        metadata: options.metadata,
+        files,
+    };
+    await fs.promises.writeFile(path.join(dir, MANIFEST_FILE), JSON.stringify(manifest, null, 2) + '\n');
+    return manifest;
+}
+
+/**
+ * Check every file listed in an export directory's manifest against its recorded size and hash
+ *
+ * @example
+ * ```typescript
+ * const { ok, mismatches } = await verifyExportManifest('./release-2026-10');
+ * if (!ok) throw new Error(`Corrupt release: ${JSON.stringify(mismatches)}`);
+ * ```
+ */
+export async function verifyExportManifest(dir: string): Promise<ManifestVerification> {
+    const manifest: ExportManifest = JSON.parse(
+        await fs.promises.readFile(path.join(dir, MANIFEST_FILE), 'utf8')
+    );
+    if (manifest.sochdb_export_manifest !== MANIFEST_FORMAT_VERSION) {
+        throw new DatabaseError(`Unsupported export manifest version: ${manifest.sochdb_export_manifest}`);
+    }
+
+    const root = path.resolve(dir);
+    const mismatches: ManifestVerification['mismatches'] = [];
+    for (const entry of manifest.files) {
+        // A manifest is untrusted input; never hash files outside the export
+        const filePath = path.resolve(root, entry.file);
+        if (path.isAbsolute(entry.file) || !filePath.startsWith(root + path.sep)) {
+            throw new DatabaseError(`Export manifest lists a file outside the export directory: ${entry.file}`);
+        }
+        if (!fs.existsSync(filePath)) {
+            mismatches.push({ file: entry.file, problem: 'missing' });
+            continue;
+        }
+        const { bytes, sha256 } = await hashFile(filePath);
+        if (bytes !== entry.bytes) {
+            mismatches.push({ file: entry.file, problem: 'size' });
+        } else if (sha256 !== entry.sha256) {
+            mismatches.push({ file: entry.file, problem: 'sha256' });
+        }
+    }
+    return { ok: mismatches.length === 0, mismatches };
+}
+
+async function hashFile(filePath: string): Promise<{ bytes: number; sha256: string }> {
+    const hash = crypto.createHash('sha256');
+    let bytes = 0;
+    for await (const chunk of fs.createReadStream(filePath)) {
+        hash.update(chunk);
+        bytes += chunk.length;
+    }
+    return { bytes, sha256: hash.digest('hex') };
+}
@@ -0,0 +1,117 @@
+/**
+ * JSONL Export - Embedded Mode
+ *
+ * Exports run inside a single read transaction, so every exported entry
+ * comes from the same pinned snapshot even while writers are active.
+ *
+ * The file is written under a temporary name and renamed into place once
+ * complete, so an aborted or failed export never leaves a partial file.
+ *
+ * File layout: an optional header line followed by one entry per line,
+ * with keys and values base64-encoded:
+ *
+ * ```
+ * {"sochdb_export":1,"snapshot_lsn":"1042","prefix":"dXNlcnMv","exported_at":"2026-01-01T00:00:00.000Z"}
+ * {"key":"dXNlcnMvYWxpY2U=","value":"eyJuYW1lIjoiQWxpY2UifQ=="}
+ * ```
+ */
+
+import * as fs from 'fs';
+import { AbortError } from '../errors';
+import type { EmbeddedTransaction } from './transaction';
+
+export const EXPORT_FORMAT_VERSION = 1;
+
+export interface ExportOptions {
+    /** Write a header line with the snapshot LSN (default: true) */
+    header?: boolean;
+    signal?: AbortSignal;
+}
+
+export interface ExportResult {
+    /** Number of entries written */
+    count: number;
+    /** LSN of the snapshot the export was read from */
+    snapshotLsn: bigint;
+}
+
+export interface ExportHeader {
+    sochdb_export: number;
+    snapshot_lsn: string;
+    prefix: string;
+    exported_at: string;
+}
+
+const FLUSH_BYTES = 1 << 20;
+
+/**
+ * Export every entry under a prefix from the transaction's snapshot
+ * @internal
+ */
+export async function exportJsonl(
+    txn: EmbeddedTransaction,
+    prefix: Buffer,
+    filePath: string,
+    now: () => number,
+    options?: ExportOptions
+): Promise<ExportResult> {
+    const snapshotLsn = txn.snapshotTs;
+    const tempPath = `${filePath}.${process.pid}.partial`;
+    const file = await fs.promises.open(tempPath, 'w');
+    let count = 0;
+    let complete = false;
+
+    try {
+        let chunk: string[] = [];
+        let chunkBytes = 0;
+
+        if (options?.header ?? true) {
+            const header: ExportHeader = {
+                sochdb_export: EXPORT_FORMAT_VERSION,
+                snapshot_lsn: snapshotLsn.toString(),
+                prefix: prefix.toString('base64'),
+                exported_at: new Date(now()).toISOString(),
+            };
+            chunk.push(JSON.stringify(header) + '\n');
+        }
+
+        for await (const [key, value] of txn.scanPrefix(prefix, { signal: options?.signal })) {
+            const line = JSON.stringify({ key: key.toString('base64'), value: value.toString('base64') }) + '\n';
+            chunk.push(line);
+            chunkBytes += line.length;
+            count++;
+
+            if (chunkBytes >= FLUSH_BYTES) {
+                await file.write(chunk.join(''));
+                chunk = [];
+                chunkBytes = 0;
+            }
+        }
+
+        AbortError.throwIfAborted(options?.signal);
+        if (chunk.length > 0) {
+            await file.write(chunk.join(''));
+        }
+        complete = true;
+    } finally {
+        await file.close();
+        if (!complete) {
+            await fs.promises.rm(tempPath, { force: true });
+        }
+    }
+
+    await fs.promises.rename(tempPath, filePath);
+    return { count, snapshotLsn };
+}
+
+/**
+ * Parse the header line of a JSONL export, if present
+ */
+export function parseExportHeader(line: string): ExportHeader | null {
+    try {
+        const parsed = JSON.parse(line);
+        return typeof parsed?.sochdb_export === 'number' ? parsed : null;
+    } catch {
+        return null;
+    }
+}
@@ -1,5 +1,6 @@
 import * as koffi from 'koffi';
 import { findLibrary } from './library-finder';
+import { DatabaseError } from '../../errors';
 
 // Helper to safely define koffi types (handles test environment where types may be pre-defined)
 function safeDefinePointer(name: string) {
@@ -23,6 +24,8 @@ function safeDefineStruct(name: string, fields: any) {
 
 const DatabaseHandle = safeDefinePointer('DatabaseHandle');
 const IteratorHandle = safeDefinePointer('IteratorHandle');
+const BulkLoaderHandle = safeDefinePointer('BulkLoaderHandle');
+const MigrationHandle = safeDefinePointer('MigrationHandle');
 
 // Structs
 const Stats = safeDefineStruct('Stats', {
@@ -55,6 +58,54 @@ const CommitResult = safeDefineStruct('CommitResult', {
     error_code: 'int32'
 });
 
+const StallInfo = safeDefineStruct('StallInfo', {
+    stalled: 'bool',
+    reasons: 'uint8',
+    stalled_for_ms: 'uint64',
+    total_stalls: 'uint64',
+    total_stall_ms: 'uint64'
+});
+
+const MemoryUsage = safeDefineStruct('MemoryUsage', {
+    memtable_bytes: 'uint64',
+    block_cache_bytes: 'uint64',
+    pinned_value_bytes: 'uint64',
+    other_bytes: 'uint64'
+});
+
+const IoStats = safeDefineStruct('IoStats', {
+    blocks_read: 'uint64',
+    cache_hits: 'uint64',
+    cache_misses: 'uint64',
+    bytes_from_cache: 'uint64',
+    bytes_from_disk: 'uint64'
+});
+
+const MigrationProgress = safeDefineStruct('MigrationProgress', {
+    from_version: 'uint32',
+    to_version: 'uint32',
+    files_done: 'uint64',
+    files_total: 'uint64',
+    bytes_done: 'uint64',
+    bytes_total: 'uint64'
+});
+
+const ReadOptions = safeDefineStruct('ReadOptions', {
+    fill_cache: 'bool',
+    fill_cache_set: 'bool',
+    verify_checksums: 'bool',
+    verify_checksums_set: 'bool'
+});
+
+const KeyspaceConfig = safeDefineStruct('KeyspaceConfig', {
+    compression: 'uint8',
+    compression_set: 'bool',
+    cache_priority: 'uint8',
+    cache_priority_set: 'bool',
+    bloom_bits_per_key: 'uint32',
+    bloom_bits_per_key_set: 'bool'
+});
+
 export class NativeBindings {
     private static instance: NativeBindings;
     private lib: any;
@@ -63,13 +114,21 @@ export class NativeBindings {
     // FFIs
     public sochdb_open: any;
     public sochdb_open_with_config: any;
+    public sochdb_open_read_only: any;
     public sochdb_open_concurrent: any;
+    public sochdb_open_concurrent_with_config: any;
     public sochdb_is_concurrent: any;
     public sochdb_close: any;
 
     // Transactional Operations (mapped to base functions)
     public sochdb_begin_txn: any;
+    public sochdb_begin_txn_at: any;
+    public sochdb_begin_txn_readonly: any;
+    public sochdb_last_committed_lsn: any;
     public sochdb_commit: any;
+    public sochdb_commit_nowait: any;
+    public sochdb_wait_durable: any;
+    public sochdb_txn_set_deadline: any;
     public sochdb_abort: any;
 
     // KV Operations (All take DatabaseHandle AND TxnHandle)
@@ -92,17 +151,124 @@ export class NativeBindings {
     // Stats
     public sochdb_stats: any;
     public sochdb_checkpoint: any;
+    public sochdb_checkpoint_cancel: any;
 
     // Memory
     public sochdb_free_bytes: any;
 
+    // Keyspaces (optional - null when the native library predates them)
+    public sochdb_keyspace_configure: any;
+
+    // Background event queue (optional)
+    public sochdb_poll_events: any;
+
+    // Per-transaction I/O counters (optional)
+    public sochdb_txn_io_stats: any;
+
+    // Bulk loading (optional)
+    public sochdb_bulk_loader_new: any;
+    public sochdb_bulk_loader_add: any;
+    public sochdb_bulk_loader_finish: any;
+    public sochdb_bulk_loader_abort: any;
+
+    // Reads with per-call options (optional)
+    public sochdb_get_opts: any;
+    public sochdb_get_path_opts: any;
+
+    // Path-tree listings (optional)
+    public sochdb_list_path: any;
+    public sochdb_tree_summary: any;
+    public sochdb_list_path_ordered: any;
+    public sochdb_scan_path: any;
+    public sochdb_scan_prefix_opts: any;
+
+    // Engine metrics (optional)
+    public sochdb_metrics_json: any;
+    public sochdb_metrics_prometheus: any;
+    public sochdb_stall_info: any;
+
+    // Consistent hash used for partition routing (optional)
+    public sochdb_shard_for: any;
+
+    // Ordered tuple key codec (optional)
+    public sochdb_encode_tuple_key: any;
+    public sochdb_decode_tuple_key: any;
+
+    // Path segment escaping and validation (optional)
+    public sochdb_escape_path_segment: any;
+    public sochdb_validate_path: any;
+
+    // Ordered scalar key encoders (optional)
+    public sochdb_encode_u64_key: any;
+    public sochdb_encode_i64_key: any;
+    public sochdb_encode_f64_key: any;
+
+    // Put with conflict policy (optional)
+    public sochdb_put_with_policy: any;
+
+    // Checkpoint to directory (optional)
+    public sochdb_checkpoint_to: any;
+
+    // Projected scans (optional)
+    public sochdb_scan_prefix_projected: any;
+
+    // Key prefix registry (optional)
+    public sochdb_register_prefix: any;
+
+    // Append-only prefixes (optional)
+    public sochdb_set_append_only: any;
+
+    // Per-prefix retention (optional)
+    public sochdb_set_retention: any;
+    public sochdb_retention_stats_json: any;
+
+    // Encryption at rest (optional)
+    public sochdb_open_encrypted: any;
+
+    // Open-option defaults from config file / SOCHDB_* environment (optional)
+    public sochdb_resolve_config: any;
+
+    // Value compression (optional)
+    public sochdb_set_compression: any;
+    public sochdb_put_compressed: any;
+
+    // On-disk format versioning (optional)
+    public sochdb_format_version: any;
+    public sochdb_supported_format_version: any;
+    public sochdb_set_write_format_version: any;
+    public sochdb_migration_begin: any;
+    public sochdb_migration_step: any;
+    public sochdb_migration_abort: any;
+
+    // Engine build information (optional)
+    public sochdb_engine_info: any;
+
+    // Sampled comparison of two databases (optional)
+    public sochdb_compare_sampled: any;
+
+    // Native memory accounting (optional)
+    public sochdb_memory_usage: any;
+    public sochdb_process_memory_usage: any;
+
+    // Key expiry (optional)
+    public sochdb_expire: any;
+    public sochdb_touch_prefix: any;
+
+    /** Path the native library was loaded from */
+    public readonly libraryPath: string;
+
     private constructor() {
         const libPath = findLibrary();
+        this.libraryPath = libPath;
         try {
             this.lib = koffi.load(libPath);
         } catch (error: any) {
             console.error(`Failed to load SochDB library from ${libPath}:`, error);
-            throw error;
+            throw new DatabaseError(
+                `Failed to load SochDB native library from ${libPath} ` +
+                `(${process.platform}/${process.arch}${process.versions.electron ? `, Electron ${process.versions.electron}` : ''}): ` +
+                `${error.message}. Call runtimeInfo() to check the library against this runtime.`
+            );
         }
 
         // Initialize bindings
@@ -110,12 +276,16 @@ export class NativeBindings {
         // DB Management
         this.sochdb_open = this.lib.func('sochdb_open', DatabaseHandle, ['string']);
         this.sochdb_open_with_config = this.lib.func('sochdb_open_with_config', DatabaseHandle, ['string', DatabaseConfig]);
+        // Open an existing database that rejects every write with -4 and runs no checkpoints or compactions (optional)
+        this.sochdb_open_read_only = this.optionalFunc('sochdb_open_read_only', DatabaseHandle, ['string']);
         
         // Concurrent mode (v0.4.8+)
         // Use 'void*' instead of DatabaseHandle to avoid koffi type loading issues
         try {
             this.sochdb_open_concurrent = this.lib.func('sochdb_open_concurrent', 'void*', ['string']);
             this.sochdb_is_concurrent = this.lib.func('sochdb_is_concurrent', 'int', ['void*']);
+            // Concurrent open with resolved options (optional)
+            this.sochdb_open_concurrent_with_config = this.optionalFunc('sochdb_open_concurrent_with_config', 'void*', ['string', DatabaseConfig]);
             this._concurrentModeAvailable = true;
             console.log('[SochDB FFI] Concurrent mode functions loaded successfully');
         } catch (error: any) {
@@ -123,6 +293,7 @@ export class NativeBindings {
             console.log('[SochDB FFI] Concurrent mode functions not available:', error?.message || error);
             this.sochdb_open_concurrent = null;
             this.sochdb_is_concurrent = null;
+            this.sochdb_open_concurrent_with_config = null;
             this._concurrentModeAvailable = false;
         }
         
@@ -130,7 +301,20 @@ export class NativeBindings {
 
         // Transactions
         this.sochdb_begin_txn = this.lib.func('sochdb_begin_txn', TxnHandle, [DatabaseHandle]);
+        // Begin a transaction reading at an existing snapshot timestamp (optional); txn_id 0 on failure
+        this.sochdb_begin_txn_at = this.optionalFunc('sochdb_begin_txn_at', TxnHandle, [DatabaseHandle, 'uint64']);
+        // Begin a transaction that rejects every write with -4 (optional)
+        this.sochdb_begin_txn_readonly = this.optionalFunc('sochdb_begin_txn_readonly', TxnHandle, [DatabaseHandle]);
+        // LSN of the most recent commit, read from an atomic without taking locks (optional)
+        this.sochdb_last_committed_lsn = this.optionalFunc('sochdb_last_committed_lsn', 'uint64', [DatabaseHandle]);
         this.sochdb_commit = this.lib.func('sochdb_commit', CommitResult, [DatabaseHandle, TxnHandle]);
+        // Commit returning once applied, with the WAL write queued for the next group flush (optional).
+        // wait_durable blocks until commit_ts reaches level 1 (WAL written) or 2 (fsynced); call it with .async
+        this.sochdb_commit_nowait = this.optionalFunc('sochdb_commit_nowait', CommitResult, [DatabaseHandle, TxnHandle]);
+        this.sochdb_wait_durable = this.optionalFunc('sochdb_wait_durable', 'int', [DatabaseHandle, 'uint64', 'uint8']);
+        // Cancel later operations on the transaction (including commit) once this many ms have passed;
+        // cancelled operations return -7, and 0 clears the deadline (optional)
+        this.sochdb_txn_set_deadline = this.optionalFunc('sochdb_txn_set_deadline', 'int', [DatabaseHandle, TxnHandle, 'uint64']);
         this.sochdb_abort = this.lib.func('sochdb_abort', 'int', [DatabaseHandle, TxnHandle]);
 
         // KV Operations
@@ -150,9 +334,136 @@ export class NativeBindings {
         // Stats & Checkpoint
         this.sochdb_stats = this.lib.func('sochdb_stats', Stats, [DatabaseHandle]);
         this.sochdb_checkpoint = this.lib.func('sochdb_checkpoint', 'int', [DatabaseHandle]);
+        // Stop a checkpoint (sochdb_checkpoint or sochdb_checkpoint_to) running on another thread
+        // at its next page; that call then returns -8 (optional)
+        this.sochdb_checkpoint_cancel = this.optionalFunc('sochdb_checkpoint_cancel', 'int', [DatabaseHandle]);
 
         // Memory Management
         this.sochdb_free_bytes = this.lib.func('sochdb_free_bytes', 'void', ['uint8*', 'size_t']);
+
+        // Keyspaces
+        this.sochdb_keyspace_configure = this.optionalFunc('sochdb_keyspace_configure', 'int', [DatabaseHandle, 'string', KeyspaceConfig]);
+
+        // Background events: (db, out_ptr, out_len) -> 0; output is a JSON array drained from the queue
+        this.sochdb_poll_events = this.optionalFunc('sochdb_poll_events', 'int', [DatabaseHandle, koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // I/O counters accumulated by a transaction since it began
+        this.sochdb_txn_io_stats = this.optionalFunc('sochdb_txn_io_stats', IoStats, [DatabaseHandle, TxnHandle]);
+
+        // Bulk loading: presorted entries are written straight to SST files
+        this.sochdb_bulk_loader_new = this.optionalFunc('sochdb_bulk_loader_new', BulkLoaderHandle, [DatabaseHandle]);
+        this.sochdb_bulk_loader_add = this.optionalFunc('sochdb_bulk_loader_add', 'int', [BulkLoaderHandle, 'uint8*', 'size_t', 'uint8*', 'size_t']);
+        this.sochdb_bulk_loader_finish = this.optionalFunc('sochdb_bulk_loader_finish', 'int', [BulkLoaderHandle]);
+        this.sochdb_bulk_loader_abort = this.optionalFunc('sochdb_bulk_loader_abort', 'void', [BulkLoaderHandle]);
+
+        // Reads with options: same as the plain variants plus a ReadOptions struct.
+        // Return -2 when checksum verification fails.
+        this.sochdb_get_opts = this.optionalFunc('sochdb_get_opts', 'int', [DatabaseHandle, TxnHandle, 'uint8*', 'size_t', ReadOptions, koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        this.sochdb_get_path_opts = this.optionalFunc('sochdb_get_path_opts', 'int', [DatabaseHandle, TxnHandle, 'string', ReadOptions, koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Path-tree listings: (db, txn, path, [max_depth,] out_ptr, out_len) -> 0; output is JSON
+        this.sochdb_list_path = this.optionalFunc('sochdb_list_path', 'int', [DatabaseHandle, TxnHandle, 'string', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        this.sochdb_tree_summary = this.optionalFunc('sochdb_tree_summary', 'int', [DatabaseHandle, TxnHandle, 'string', 'uint32', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        // Path order: 0 = raw bytes, 1 = by path component. scan_path yields every document below the path
+        this.sochdb_list_path_ordered = this.optionalFunc('sochdb_list_path_ordered', 'int', [DatabaseHandle, TxnHandle, 'string', 'uint8', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        this.sochdb_scan_path = this.optionalFunc('sochdb_scan_path', IteratorHandle, [DatabaseHandle, TxnHandle, 'string', 'uint8']);
+        this.sochdb_scan_prefix_opts = this.optionalFunc('sochdb_scan_prefix_opts', IteratorHandle, [DatabaseHandle, TxnHandle, 'uint8*', 'size_t', ReadOptions]);
+
+        // Metrics: (db, out_ptr, out_len) -> 0 on success; output freed with sochdb_free_bytes
+        this.sochdb_metrics_json = this.optionalFunc('sochdb_metrics_json', 'int', [DatabaseHandle, koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        this.sochdb_metrics_prometheus = this.optionalFunc('sochdb_metrics_prometheus', 'int', [DatabaseHandle, koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Write stall state; reasons is a bitmask (1 = memtable full, 2 = WAL sync backlog, 4 = compaction debt)
+        this.sochdb_stall_info = this.optionalFunc('sochdb_stall_info', StallInfo, [DatabaseHandle]);
+
+        // (key, key_len, shards) -> shard index in [0, shards)
+        this.sochdb_shard_for = this.optionalFunc('sochdb_shard_for', 'uint32', ['uint8*', 'size_t', 'uint32']);
+
+        // Tuple keys: encode takes a JSON array of {bytes|string|f64|i64} elements (bytes base64, i64 decimal)
+        // and returns the key; decode returns the same JSON. Both return nonzero on invalid input
+        this.sochdb_encode_tuple_key = this.optionalFunc('sochdb_encode_tuple_key', 'int', ['uint8*', 'size_t', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        this.sochdb_decode_tuple_key = this.optionalFunc('sochdb_decode_tuple_key', 'int', ['uint8*', 'size_t', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Paths: escape (bytes, len, binary, out_ptr, out_len) -> 0 with the escaped segment; binary
+        // segments escape every non-printable byte, text segments only "%", "/" and control characters.
+        // validate (path, out_ptr, out_len) -> 0 when valid, else nonzero with the reason as UTF-8
+        this.sochdb_escape_path_segment = this.optionalFunc('sochdb_escape_path_segment', 'int', ['uint8*', 'size_t', 'bool', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+        this.sochdb_validate_path = this.optionalFunc('sochdb_validate_path', 'int', ['string', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Scalar keys: (value, out[8]) -> 0, writing the 8-byte order-preserving key; nonzero on NaN
+        this.sochdb_encode_u64_key = this.optionalFunc('sochdb_encode_u64_key', 'int', ['uint64', 'uint8*']);
+        this.sochdb_encode_i64_key = this.optionalFunc('sochdb_encode_i64_key', 'int', ['int64', 'uint8*']);
+        this.sochdb_encode_f64_key = this.optionalFunc('sochdb_encode_f64_key', 'int', ['double', 'uint8*']);
+
+        // (out_ptr, out_len) -> 0; JSON { version, features: [...], target }
+        this.sochdb_engine_info = this.optionalFunc('sochdb_engine_info', 'int', [koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Opens both paths read-only without taking the writer lock; mismatches are reported as JSON
+        this.sochdb_compare_sampled = this.optionalFunc('sochdb_compare_sampled', 'int', ['string', 'string', 'uint8*', 'size_t', 'double', 'uint64', 'uint32', koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Bytes held natively by one database, and by every database open in the process
+        this.sochdb_memory_usage = this.optionalFunc('sochdb_memory_usage', MemoryUsage, [DatabaseHandle]);
+        this.sochdb_process_memory_usage = this.optionalFunc('sochdb_process_memory_usage', MemoryUsage, []);
+
+        // Put with policy: (db, txn, key, klen, val, vlen, policy) -> outcome, negative on error
+        this.sochdb_put_with_policy = this.optionalFunc('sochdb_put_with_policy', 'int', [DatabaseHandle, TxnHandle, 'uint8*', 'size_t', 'uint8*', 'size_t', 'uint8']);
+
+        // Checkpoint to directory: (db, dir, incremental, base_lsn) -> checkpoint LSN, negative on error
+        this.sochdb_checkpoint_to = this.optionalFunc('sochdb_checkpoint_to', 'int64', [DatabaseHandle, 'string', 'bool', 'uint64']);
+
+        // Projected scan: (db, txn, prefix, len, mode, arg) where mode 1 = keys, 2 = length, 3 = JSON field
+        this.sochdb_scan_prefix_projected = this.optionalFunc('sochdb_scan_prefix_projected', IteratorHandle, [DatabaseHandle, TxnHandle, 'uint8*', 'size_t', 'uint8', 'string']);
+
+        // Key prefixes: (db, name, prefix) -> 0 on success, nonzero when invalid or overlapping
+        this.sochdb_register_prefix = this.optionalFunc('sochdb_register_prefix', 'int', [DatabaseHandle, 'string', 'string']);
+
+        // Persistently mark a prefix append-only; writes that update or delete an existing key there return -6
+        this.sochdb_set_append_only = this.optionalFunc('sochdb_set_append_only', 'int', [DatabaseHandle, 'uint8*', 'size_t']);
+
+        // Retention enforced during compaction: (db, prefix, len, max_age_ms, max_bytes), 0 = unlimited; both 0 removes the policy
+        this.sochdb_set_retention = this.optionalFunc('sochdb_set_retention', 'int', [DatabaseHandle, 'uint8*', 'size_t', 'uint64', 'uint64']);
+        // (db, out_ptr, out_len) -> 0; JSON array of per-prefix retention policies and what compaction removed
+        this.sochdb_retention_stats_json = this.optionalFunc('sochdb_retention_stats_json', 'int', [DatabaseHandle, koffi.out(koffi.pointer('uint8*')), koffi.out(koffi.pointer('size_t'))]);
+
+        // Encryption: (path, config, key, key_len, cipher) where cipher 1 = AES-256-GCM, 2 = ChaCha20
+        this.sochdb_open_encrypted = this.optionalFunc('sochdb_open_encrypted', DatabaseHandle, ['string', DatabaseConfig, 'uint8*', 'size_t', 'uint8']);
+
+        // Fill fields not explicitly set in `config` from the config file (null = $SOCHDB_CONFIG)
+        // and, when apply_env is true, SOCHDB_* variables. Returns -1 if the config file is invalid.
+        this.sochdb_resolve_config = this.optionalFunc('sochdb_resolve_config', 'int', [DatabaseConfig, 'string', 'bool', koffi.out(koffi.pointer(DatabaseConfig))]);
+
+        // Value compression: (db, codec, min_size) and (db, txn, key, klen, val, vlen, codec)
+        this.sochdb_set_compression = this.optionalFunc('sochdb_set_compression', 'int', [DatabaseHandle, 'uint8', 'size_t']);
+        this.sochdb_put_compressed = this.optionalFunc('sochdb_put_compressed', 'int', [DatabaseHandle, TxnHandle, 'uint8*', 'size_t', 'uint8*', 'size_t', 'uint8']);
+
+        // Format versions: version of the data at a path (-1 = no database, <-1 = error),
+        // newest version this library can read/write, and a cap on what an open handle writes
+        this.sochdb_format_version = this.optionalFunc('sochdb_format_version', 'int32', ['string']);
+        this.sochdb_supported_format_version = this.optionalFunc('sochdb_supported_format_version', 'uint32', []);
+        this.sochdb_set_write_format_version = this.optionalFunc('sochdb_set_write_format_version', 'int', [DatabaseHandle, 'uint32']);
+
+        // In-place format migration of a closed database. step() rewrites one unit of work
+        // and returns 0 (more to do), 1 (done) or <0 (-3 = data needs the newer format)
+        this.sochdb_migration_begin = this.optionalFunc('sochdb_migration_begin', MigrationHandle, ['string', 'uint32']);
+        this.sochdb_migration_step = this.optionalFunc('sochdb_migration_step', 'int', [MigrationHandle, koffi.out(koffi.pointer(MigrationProgress))]);
+        this.sochdb_migration_abort = this.optionalFunc('sochdb_migration_abort', 'void', [MigrationHandle]);
+
+        // Key expiry: deadlines are absolute ms since the epoch, computed from the SDK clock.
+        // touch_prefix returns the number of expiring keys whose deadline was extended (<0 on error)
+        this.sochdb_expire = this.optionalFunc('sochdb_expire', 'int', [DatabaseHandle, TxnHandle, 'uint8*', 'size_t', 'uint64']);
+        this.sochdb_touch_prefix = this.optionalFunc('sochdb_touch_prefix', 'int64', [DatabaseHandle, 'uint8*', 'size_t', 'uint64']);
+    }
+
+    /**
+     * Bind a symbol that only newer native libraries export.
+     * Returns null instead of throwing when the symbol is missing.
+     */
+    private optionalFunc(name: string, result: any, args: any[]): any {
+        try {
+            return this.lib.func(name, result, args);
+        } catch {
+            return null;
+        }
     }
 
     public static getInstance(): NativeBindings {
@@ -12,14 +12,15 @@ import * as os from 'os';
 /**
  * Get the Rust target triple for the current platform
  */
-function getTargetTriple(): string {
+export function getTargetTriple(): string {
     const platform = os.platform();
     const arch = os.arch();
 
     if (platform === 'darwin') {
         return arch === 'arm64' ? 'aarch64-apple-darwin' : 'x86_64-apple-darwin';
     } else if (platform === 'linux') {
-        return arch === 'arm64' ? 'aarch64-unknown-linux-gnu' : 'x86_64-unknown-linux-gnu';
+        const libc = isMusl() ? 'musl' : 'gnu';
+        return arch === 'arm64' ? `aarch64-unknown-linux-${libc}` : `x86_64-unknown-linux-${libc}`;
     } else if (platform === 'win32') {
         return arch === 'x64' ? 'x86_64-pc-windows-msvc' : 'i686-pc-windows-msvc';
     }
@@ -27,6 +28,32 @@ function getTargetTriple(): string {
     throw new Error(`Unsupported platform: ${platform}/${arch}`);
 }
 
+let musl: boolean | undefined;
+
+/**
+ * Whether Node runs against musl libc (Alpine) rather than glibc.
+ * Assumes glibc when the process report is unavailable.
+ */
+export function isMusl(): boolean {
+    if (musl === undefined) {
+        // getReport() is slow, so it runs at most once
+        const report = process.platform === 'linux'
+            ? process.report?.getReport() as { header?: { glibcVersionRuntime?: string } } | undefined
+            : undefined;
+        musl = report?.header !== undefined && !report.header.glibcVersionRuntime;
+    }
+    return musl;
+}
+
+/**
+ * Map a path inside an Electron `app.asar` archive to its `app.asar.unpacked`
+ * twin; native libraries cannot be loaded from inside the archive, so
+ * packagers unpack them next to it.
+ */
+function unpackedAsarPath(searchPath: string): string {
+    return searchPath.replace(/app\.asar([\\/])/, 'app.asar.unpacked$1');
+}
+
 /**
  * Get the library filename for the current platform
  */
@@ -97,7 +124,8 @@ export function findLibrary(): string {
     ];
 
     // Search for library
-    for (const searchPath of searchPaths) {
+    for (const candidate of searchPaths) {
+        const searchPath = unpackedAsarPath(candidate);
         if (fs.existsSync(searchPath)) {
             return path.resolve(searchPath);
         }
@@ -122,3 +150,59 @@ export function findLibrary(): string {
         `Alternatively, set SOCHDB_LIB_PATH environment variable to library path.`
     );
 }
+
+/**
+ * Find the optional addon that reports native memory to V8
+ *
+ * Search order:
+ * 1. SOCHDB_EXTERNAL_MEMORY_ADDON environment variable
+ * 2. Bundled addon in package (_bin/{target}/)
+ * 3. Local build (`npm run build:native`)
+ *
+ * Returns null when it is not installed; the SDK works without it.
+ */
+export function findExternalMemoryAddon(): string | null {
+    const filename = 'sochdb_external_memory.node';
+
+    const envPath = process.env.SOCHDB_EXTERNAL_MEMORY_ADDON;
+    if (envPath && fs.existsSync(envPath)) {
+        return envPath;
+    }
+
+    let target: string;
+    try {
+        target = getTargetTriple();
+    } catch {
+        return null;
+    }
+    const localBuild = path.join('native', 'external-memory', 'build', 'Release', filename);
+    const searchPaths: string[] = [
+        // Same package-root depths as findLibrary()
+        path.join(__dirname, '..', '..', '..', '..', '_bin', target, filename),
+        path.join(__dirname, '..', '..', '..', '_bin', target, filename),
+        path.join(__dirname, '..', '..', '_bin', target, filename),
+        path.join(__dirname, '..', '..', '..', '..', localBuild),
+        path.join(__dirname, '..', '..', '..', localBuild),
+        path.join(__dirname, '..', '..', localBuild),
+    ];
+
+    for (const candidate of searchPaths) {
+        const searchPath = unpackedAsarPath(candidate);
+        if (fs.existsSync(searchPath)) {
+            return path.resolve(searchPath);
+        }
+    }
+    return null;
+}
+
+/**
+ * Whether a native library exists for this platform, without loading it
+ */
+export function isNativeLibraryAvailable(): boolean {
+    try {
+        findLibrary();
+        return true;
+    } catch {
+        return false;
+    }
+}
@@ -0,0 +1,98 @@
+/**
+ * Test Fixtures - Embedded Mode
+ *
+ * A fixture is a frozen copy of database contents for integration tests.
+ * The same contents always produce byte-identical files: entries are
+ * written in key order and nothing run-dependent is recorded (no snapshot
+ * LSN, export time or TTL deadlines). Opening a fixture loads it into a
+ * throwaway, read-only database.
+ *
+ * File layout:
+ *
+ * ```
+ * {"sochdb_fixture":1}
+ * {"key":"dXNlcnMvYWxpY2U=","value":"eyJuYW1lIjoiQWxpY2UifQ=="}
+ * ```
+ */
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import * as readline from 'readline';
+import { AbortError, DatabaseError } from '../errors';
+import type { EmbeddedTransaction } from './transaction';
+import { BytesLike, toBuffer } from './key-encoding';
+
+export const FIXTURE_FORMAT_VERSION = 1;
+
+/** SDK bookkeeping (undo journal, ...) holds timestamps and is left out by default */
+const DEFAULT_EXCLUDE = ['_admin/'];
+
+export interface FixtureOptions {
+    /** Only freeze keys under this prefix (default: everything) */
+    prefix?: BytesLike;
+    /** Leave out keys under these prefixes (default: `['_admin/']`) */
+    exclude?: BytesLike[];
+    signal?: AbortSignal;
+}
+
+export interface FixtureResult {
+    /** Entries written */
+    count: number;
+}
+
+/**
+ * Write the transaction's view of the database as a fixture
+ * @internal
+ */
+export async function writeFixture(
+    txn: EmbeddedTransaction,
+    filePath: string,
+    options: FixtureOptions = {}
+): Promise<FixtureResult> {
+    const exclude = (options.exclude ?? DEFAULT_EXCLUDE).map(toBuffer);
+    const lines = [JSON.stringify({ sochdb_fixture: FIXTURE_FORMAT_VERSION }) + '\n'];
+    let previous: Buffer | null = null;
+
+    for await (const [key, value] of txn.scanPrefix(toBuffer(options.prefix ?? ''), { signal: options.signal })) {
+        if (exclude.some((prefix) => key.subarray(0, prefix.length).equals(prefix))) continue;
+        if (previous && Buffer.compare(previous, key) >= 0) {
+            throw new DatabaseError('Scan returned keys out of order; cannot write a deterministic fixture');
+        }
+        previous = key;
+        lines.push(JSON.stringify({ key: key.toString('base64'), value: value.toString('base64') }) + '\n');
+    }
+    AbortError.throwIfAborted(options.signal);
+
+    await fs.promises.writeFile(filePath, lines.join(''));
+    return { count: lines.length - 1 };
+}
+
+/**
+ * Read the entries of a fixture file, in key order
+ * @internal
+ */
+export async function* readFixture(filePath: string): AsyncGenerator<[Buffer, Buffer]> {
+    const lines = readline.createInterface({ input: fs.createReadStream(filePath), crlfDelay: Infinity });
+    let first = true;
+    for await (const line of lines) {
+        if (line.length === 0) continue;
+        const parsed = JSON.parse(line);
+        if (first) {
+            first = false;
+            if (parsed.sochdb_fixture !== FIXTURE_FORMAT_VERSION) {
+                throw new DatabaseError(`${filePath} is not a SochDB fixture (or uses an unsupported version)`);
+            }
+            continue;
+        }
+        yield [Buffer.from(parsed.key, 'base64'), Buffer.from(parsed.value, 'base64')];
+    }
+}
+
+/**
+ * Fresh temporary directory to load a fixture into
+ * @internal
+ */
+export function createFixtureDir(): Promise<string> {
+    return fs.promises.mkdtemp(path.join(os.tmpdir(), 'sochdb-fixture-'));
+}
@@ -0,0 +1,112 @@
+/**
+ * Format Migration - Embedded Mode
+ *
+ * Rewrites a closed database to another on-disk format version in place.
+ * The native engine does the work in small steps so progress can be
+ * reported and the migration cancelled between steps; an interrupted
+ * migration leaves the database at its original version.
+ */
+
+import { AbortError, DatabaseError } from '../errors';
+import { NativeBindings } from './ffi/bindings';
+
+export interface FormatMigrationProgress {
+    fromVersion: number;
+    toVersion: number;
+    filesDone: number;
+    filesTotal: number;
+    bytesDone: number;
+    bytesTotal: number;
+}
+
+export interface FormatMigrationOptions {
+    /** Called after every migration step */
+    onProgress?: (progress: FormatMigrationProgress) => void;
+    /** Abort between steps; the database keeps its original version */
+    signal?: AbortSignal;
+}
+
+export interface FormatMigrationResult {
+    fromVersion: number;
+    toVersion: number;
+    /** False if the database was already at the target version */
+    migrated: boolean;
+}
+
+/** Returned by a step when a downgrade would lose data written with newer features */
+const MIGRATION_NEEDS_NEWER_FORMAT = -3;
+
+/**
+ * @internal
+ */
+export async function migrateFormat(
+    bindings: NativeBindings,
+    path: string,
+    targetVersion: number,
+    options: FormatMigrationOptions = {}
+): Promise<FormatMigrationResult> {
+    if (!bindings.sochdb_migration_begin) {
+        throw new DatabaseError(
+            'Format migration is not supported by the loaded SochDB native library. ' +
+            'Please upgrade the native library.'
+        );
+    }
+    AbortError.throwIfAborted(options.signal);
+
+    const handle = bindings.sochdb_migration_begin(path, targetVersion);
+    if (!handle) {
+        throw new DatabaseError(
+            `Failed to start format migration of ${path} to version ${targetVersion}. ` +
+            'Make sure the database is closed everywhere and the version is supported.'
+        );
+    }
+
+    for (;;) {
+        if (options.signal?.aborted) {
+            bindings.sochdb_migration_abort(handle);
+            AbortError.throwIfAborted(options.signal);
+        }
+
+        const native: any = {};
+        const res = bindings.sochdb_migration_step(handle, native);
+        if (res < 0) {
+            bindings.sochdb_migration_abort(handle);
+            if (res === MIGRATION_NEEDS_NEWER_FORMAT) {
+                throw new DatabaseError(
+                    `Cannot downgrade ${path} to format version ${targetVersion}: ` +
+                    'the data uses features that version cannot represent'
+                );
+            }
+            throw new DatabaseError(`Format migration of ${path} failed (Code ${res})`);
+        }
+
+        const progress = fromNativeMigrationProgress(native);
+        try {
+            options.onProgress?.(progress);
+        } catch (error) {
+            if (res !== 1) bindings.sochdb_migration_abort(handle);
+            throw error;
+        }
+        if (res === 1) {
+            return {
+                fromVersion: progress.fromVersion,
+                toVersion: progress.toVersion,
+                migrated: progress.fromVersion !== progress.toVersion,
+            };
+        }
+
+        // Let timers and I/O run between steps
+        await new Promise((resolve) => setImmediate(resolve));
+    }
+}
+
+function fromNativeMigrationProgress(native: any): FormatMigrationProgress {
+    return {
+        fromVersion: native.from_version,
+        toVersion: native.to_version,
+        filesDone: Number(native.files_done),
+        filesTotal: Number(native.files_total),
+        bytesDone: Number(native.bytes_done),
+        bytesTotal: Number(native.bytes_total),
+    };
+}
@@ -0,0 +1,203 @@
+/**
+ * JSONL Import - Embedded Mode
+ *
+ * Reads files produced by `exportJsonl()` and writes them back with a
+ * conflict policy applied to keys that already exist in the target.
+ */
+
+import * as fs from 'fs';
+import * as readline from 'readline';
+import { AbortError, DatabaseError, ImportConflictError } from '../errors';
+import { parseExportHeader } from './export';
+import type { EmbeddedDatabase } from './database';
+import type { EmbeddedTransaction } from './transaction';
+import type { JournaledOp, UndoJournal } from './undo-journal';
+
+/**
+ * Resolve a conflict between an existing and an incoming value.
+ * Return the value to store, or null to keep the existing value.
+ */
+export type MergeCallback = (key: Buffer, existing: Buffer, incoming: Buffer) => Buffer | null | Promise<Buffer | null>;
+
+export type ConflictPolicy = 'skip' | 'overwrite' | 'fail' | MergeCallback;
+
+export interface ImportOptions {
+    /**
+     * What to do when a key already exists (default: 'overwrite').
+     * With 'fail', batches written before the conflicting one stay committed.
+     */
+    conflict?: ConflictPolicy;
+    /** Entries written per transaction (default: 1000) */
+    batchSize?: number;
+    /** Report what would be written without modifying the database */
+    dryRun?: boolean;
+    /** Number of conflicting keys to include in a dry-run result (default: 10) */
+    sampleSize?: number;
+    signal?: AbortSignal;
+}
+
+export interface ImportResult {
+    /** Entries written to keys that did not exist */
+    inserted: number;
+    /** Existing keys replaced by the incoming value */
+    overwritten: number;
+    /** Existing keys kept as-is */
+    skipped: number;
+    /** Existing keys replaced by a merge callback result */
+    merged: number;
+    /**
+     * Existing keys the 'fail' policy would reject. Only a dry run reports
+     * conflicts; a real import throws ImportConflictError at the first one.
+     */
+    conflicts: number;
+    /** First conflicting keys, in file order (dry run only) */
+    conflictKeys: Buffer[];
+    /** True when nothing was written */
+    dryRun: boolean;
+}
+
+/** Native conflict policy codes */
+const POLICY_CODES = { skip: 1, overwrite: 2, fail: 3 } as const;
+
+/** Native put-with-policy outcomes */
+const OUTCOME_INSERTED = 0;
+const OUTCOME_OVERWRITTEN = 1;
+const OUTCOME_SKIPPED = 2;
+const OUTCOME_CONFLICT = 3;
+
+/**
+ * Import a JSONL export file
+ * @internal
+ */
+export async function importJsonl(
+    db: EmbeddedDatabase,
+    filePath: string,
+    options?: ImportOptions
+): Promise<ImportResult> {
+    const policy = options?.conflict ?? 'overwrite';
+    if (typeof policy !== 'function' && !Object.prototype.hasOwnProperty.call(POLICY_CODES, policy)) {
+        throw new DatabaseError(
+            `Unknown conflict policy: ${String(policy)} (expected 'skip', 'overwrite', 'fail' or a merge callback)`
+        );
+    }
+    const batchSize = options?.batchSize ?? 1000;
+    if (!Number.isInteger(batchSize) || batchSize <= 0) {
+        throw new DatabaseError(`batchSize must be a positive integer, got ${batchSize}`);
+    }
+    const dryRun = options?.dryRun ?? false;
+    const sampleSize = options?.sampleSize ?? 10;
+    const result: ImportResult = { inserted: 0, overwritten: 0, skipped: 0, merged: 0, conflicts: 0, conflictKeys: [], dryRun };
+
+    const journal = dryRun ? undefined : db.getUndoJournal();
+    const undo = journal ? { journal, op: await journal.begin('import', { filePath }) } : undefined;
+
+    const lines = readline.createInterface({
+        input: fs.createReadStream(filePath),
+        crlfDelay: Infinity,
+    });
+
+    let batch: Array<[Buffer, Buffer]> = [];
+    let first = true;
+    try {
+        for await (const line of lines) {
+            AbortError.throwIfAborted(options?.signal);
+            if (line.length === 0) continue;
+            if (first) {
+                first = false;
+                if (parseExportHeader(line)) continue;
+            }
+
+            const entry = JSON.parse(line);
+            if (typeof entry.key !== 'string' || typeof entry.value !== 'string') {
+                throw new DatabaseError(`Malformed import line: ${line.slice(0, 80)}`);
+            }
+            batch.push([Buffer.from(entry.key, 'base64'), Buffer.from(entry.value, 'base64')]);
+
+            if (batch.length >= batchSize) {
+                await db.withTransaction((txn) => writeBatch(txn, batch, policy, result, dryRun, sampleSize, undo));
+                batch = [];
+            }
+        }
+
+        if (batch.length > 0) {
+            await db.withTransaction((txn) => writeBatch(txn, batch, policy, result, dryRun, sampleSize, undo));
+        }
+    } finally {
+        lines.close();
+    }
+
+    return result;
+}
+
+async function writeBatch(
+    txn: EmbeddedTransaction,
+    batch: Array<[Buffer, Buffer]>,
+    policy: ConflictPolicy,
+    result: ImportResult,
+    dryRun: boolean,
+    sampleSize: number,
+    undo?: { journal: UndoJournal; op: JournaledOp }
+): Promise<void> {
+    if (undo) {
+        await undo.journal.recordOp(txn, undo.op);
+    }
+    for (const [key, value] of batch) {
+        const write = () => writeEntry(txn, key, value, policy, result, dryRun, sampleSize);
+        if (undo) {
+            await undo.journal.recordWrite(txn, undo.op, key, write);
+        } else {
+            await write();
+        }
+    }
+}
+
+async function writeEntry(
+    txn: EmbeddedTransaction,
+    key: Buffer,
+    value: Buffer,
+    policy: ConflictPolicy,
+    result: ImportResult,
+    dryRun: boolean,
+    sampleSize: number
+): Promise<void> {
+    if (typeof policy === 'function') {
+        const existing = await txn.get(key);
+        if (existing === null) {
+            if (!dryRun) await txn.put(key, value);
+            result.inserted++;
+            return;
+        }
+        const merged = await policy(key, existing, value);
+        if (merged === null) {
+            result.skipped++;
+        } else {
+            if (!dryRun) await txn.put(key, merged);
+            result.merged++;
+        }
+        return;
+    }
+
+    const outcome = dryRun
+        ? await evaluatePolicy(txn, key, POLICY_CODES[policy])
+        : await txn.putWithPolicy(key, value, POLICY_CODES[policy]);
+    switch (outcome) {
+        case OUTCOME_INSERTED: result.inserted++; break;
+        case OUTCOME_OVERWRITTEN: result.overwritten++; break;
+        case OUTCOME_SKIPPED: result.skipped++; break;
+        case OUTCOME_CONFLICT:
+            if (!dryRun) throw new ImportConflictError(key);
+            result.conflicts++;
+            if (result.conflictKeys.length < sampleSize) result.conflictKeys.push(key);
+            break;
+    }
+}
+
+/**
+ * Outcome a put would have under a conflict policy, without writing
+ */
+async function evaluatePolicy(txn: EmbeddedTransaction, key: Buffer, policyCode: number): Promise<number> {
+    if ((await txn.get(key)) === null) return OUTCOME_INSERTED;
+    if (policyCode === POLICY_CODES.skip) return OUTCOME_SKIPPED;
+    if (policyCode === POLICY_CODES.fail) return OUTCOME_CONFLICT;
+    return OUTCOME_OVERWRITTEN;
+}
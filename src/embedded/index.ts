@@ -5,6 +5,72 @@
  * No server required.
  */
 
-export { EmbeddedDatabase, EmbeddedDatabaseConfig } from './database';
-export { EmbeddedTransaction } from './transaction';
+export {
+    EmbeddedDatabase,
+    EmbeddedDatabaseConfig,
+    PutOptions,
+    ScanOptions,
+    ReadOptions,
+    NativeReadOptions,
+    CheckpointToOptions,
+    CheckpointInfo,
+    DeleteOptions,
+    DeleteResult,
+    PathListOptions,
+    TransactionOptions,
+    SnapshotOptions,
+    WriteOptions,
+    PathScanOptions,
+} from './database';
+export { EmbeddedTransaction, TransactionCallback, AckLevel, CommitOptions } from './transaction';
+export { Snapshot } from './snapshot';
+export { Keyspace, KeyspaceOptions, TransactionKeyspace, CompressionCodec, CachePriority } from './keyspace';
+export { WriteBatch } from './batch';
+export { KeyPrefix, KeyPart, KeyConstructors } from './key-prefix';
+export { ScanProjection, ProjectedEntry } from './projection';
+export { ExportOptions, ExportResult, ExportHeader, parseExportHeader } from './export';
+export { ImportOptions, ImportResult, ConflictPolicy, MergeCallback } from './import';
+export {
+    encodeU64Key,
+    decodeU64Key,
+    encodeI64Key,
+    decodeI64Key,
+    encodeF64Key,
+    decodeF64Key,
+    encodeTupleKey,
+    decodeTupleKey,
+    TupleElement,
+    BytesLike,
+} from './key-encoding';
+export { EncodedView, ValueEncoding } from './encoded-view';
+export { DatabaseMetrics, OperationMetrics, StallInfo, StallReason } from './metrics';
+export { UndoJournalConfig, UndoOptions, UndoResult } from './undo-journal';
+export { BulkLoader, BulkLoadResult } from './bulk-loader';
+export { IoProfile, Profiled, MaybeProfiled } from './io-profile';
+export { BackgroundEvent } from './events';
+export { Clock, ManualClock, offsetClock, systemClock, jitterTtl } from './clock';
+export { PathEntry, TreeSummary, PathOrder } from './path-tree';
+export { FormatMigrationOptions, FormatMigrationProgress, FormatMigrationResult } from './format';
+export { ScopedDatabase, ScopedTransaction, ScopeOptions } from './scope';
+export { ReaderHandle } from './reader';
+export { CompareOptions, CompareResult, CompareMismatch, isSampled } from './compare';
+export { TransactionLabelStats, TransactionOutcome, SlowTransactionEvent } from './transaction-stats';
+export { shardFor } from './sharding';
+export { engineInfo, EngineInfo } from './engine-info';
+export { runtimeInfo, detectRuntime, RuntimeInfo, RuntimeName } from './runtime';
+export { FixtureOptions, FixtureResult } from './fixture';
+export { AlertCallback, AlertConfig, AlertEvent, AlertMetric, AlertSample, AlertThresholds } from './alerts';
+export { nativeMemoryUsage, NativeMemoryUsage } from './memory';
+export { HandleInvalidatedEvent, InvalidationReason } from './transaction';
+export { QueryMessage, TransactionMessage, DatabaseLifecycleMessage } from './diagnostics';
+export { parseDuration, RetentionPolicy, RetentionStats } from './retention';
+export { SizeLimits, DEFAULT_MAX_KEY_BYTES, DEFAULT_MAX_VALUE_BYTES } from './limits';
+export { PathBuilder, PathSegment, escapeSegment, unescapeSegment, unescapeSegmentBytes, validatePath } from './path';
+export {
+    verifyExportManifest,
+    DatasetExportOptions,
+    ExportManifest,
+    ManifestFile,
+    ManifestVerification,
+} from './export-manifest';
 export { HnswIndex, HnswConfig, HnswBindings, SearchResult } from './ffi/hnsw-bindings';
@@ -0,0 +1,78 @@
+/**
+ * I/O Profiling - Embedded Mode
+ *
+ * Per-operation storage counters reported by the native engine, used to
+ * attribute slow reads to storage layout (cache misses, read amplification).
+ */
+
+import { DatabaseError } from '../errors';
+
+export interface IoProfile {
+    /** SST blocks touched */
+    blocksRead: number;
+    /** Blocks served from the block cache */
+    cacheHits: number;
+    /** Blocks that had to be read from disk */
+    cacheMisses: number;
+    bytesFromCache: number;
+    bytesFromDisk: number;
+}
+
+/**
+ * A read result accompanied by its I/O profile (`{ profile: true }`)
+ */
+export interface Profiled<T> {
+    value: T;
+    profile: IoProfile;
+}
+
+/**
+ * Result of a read given `options`: `Profiled<T>` with `{ profile: true }`,
+ * `T` when profiling is off, and either when that is only known at runtime
+ */
+export type MaybeProfiled<T, O> = O extends undefined
+    ? T
+    : O extends { profile: true }
+        ? Profiled<T>
+        : O extends { profile?: false }
+            ? T
+            : T | Profiled<T>;
+
+/**
+ * Convert the native IoStats struct
+ * @internal
+ */
+export function fromNativeIoStats(stats: any): IoProfile {
+    return {
+        blocksRead: Number(stats.blocks_read),
+        cacheHits: Number(stats.cache_hits),
+        cacheMisses: Number(stats.cache_misses),
+        bytesFromCache: Number(stats.bytes_from_cache),
+        bytesFromDisk: Number(stats.bytes_from_disk),
+    };
+}
+
+/**
+ * Counters accumulated between two readings of the same transaction
+ * @internal
+ */
+export function diffIoProfile(after: IoProfile, before: IoProfile): IoProfile {
+    return {
+        blocksRead: after.blocksRead - before.blocksRead,
+        cacheHits: after.cacheHits - before.cacheHits,
+        cacheMisses: after.cacheMisses - before.cacheMisses,
+        bytesFromCache: after.bytesFromCache - before.bytesFromCache,
+        bytesFromDisk: after.bytesFromDisk - before.bytesFromDisk,
+    };
+}
+
+/**
+ * Scans report I/O through `onProfile`; a `profile` flag would otherwise be
+ * silently ignored
+ * @internal
+ */
+export function rejectScanProfile(options?: object): void {
+    if (options && 'profile' in options && (options as { profile?: unknown }).profile !== undefined) {
+        throw new DatabaseError('Scans do not support `profile`; pass an `onProfile` callback instead');
+    }
+}
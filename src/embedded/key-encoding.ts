@@ -0,0 +1,293 @@
+/**
+ * Ordered Key Encoding
+ *
+ * Encoders whose byte order matches the natural order of the encoded
+ * values, so range scans over numeric and composite keys sort correctly.
+ *
+ * - Unsigned integers: 8-byte big-endian
+ * - Signed integers: 8-byte big-endian with the sign bit flipped
+ * - Doubles: IEEE 754 big-endian, sign bit flipped for positives and all bits flipped for negatives
+ * - Tuples: type-tagged elements; strings and bytes are 0x00-terminated with 0x00 escaped as 0x00 0xFF.
+ *   Numbers, whether integers, bigints or doubles, share one tag and compare by value.
+ *
+ * Once a database has been opened, keys are encoded by its native library.
+ * The encoders here produce the same bytes (the tests check them against
+ * the native codec), so keys can also be built before then or where the
+ * native library is absent (the gRPC backend).
+ */
+
+import * as koffi from 'koffi';
+import { DatabaseError } from '../errors';
+import type { NativeBindings } from './ffi/bindings';
+
+export type TupleElement = string | number | bigint | Buffer;
+
+/** Keys and values may be passed as Buffers or UTF-8 strings */
+export type BytesLike = Buffer | string;
+
+/**
+ * Normalize a key or value argument to a Buffer
+ * @internal
+ */
+export function toBuffer(data: BytesLike): Buffer {
+    return typeof data === 'string' ? Buffer.from(data, 'utf8') : data;
+}
+
+const TAG_BYTES = 0x01;
+const TAG_STRING = 0x02;
+const TAG_NUMBER = 0x03;
+
+const U64_MAX = (1n << 64n) - 1n;
+const I64_MIN = -(1n << 63n);
+const I64_MAX = (1n << 63n) - 1n;
+const SIGN_BIT = 1n << 63n;
+
+let nativeCodec: NativeBindings | null = null;
+
+/**
+ * Encode keys with these bindings from now on (null reverts to the JS encoders)
+ * @internal
+ */
+export function useNativeKeyCodec(bindings: NativeBindings | null): void {
+    nativeCodec = bindings;
+}
+
+/**
+ * Encode an unsigned 64-bit integer as an order-preserving key
+ */
+export function encodeU64Key(value: number | bigint): Buffer {
+    const n = BigInt(value);
+    if (n < 0n || n > U64_MAX) {
+        throw new DatabaseError(`Value out of u64 range: ${value}`);
+    }
+    if (nativeCodec?.sochdb_encode_u64_key) {
+        return callNativeScalar(nativeCodec.sochdb_encode_u64_key, n);
+    }
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64BE(n);
+    return buf;
+}
+
+export function decodeU64Key(key: Buffer): bigint {
+    return key.readBigUInt64BE(0);
+}
+
+/**
+ * Encode a signed 64-bit integer as an order-preserving key
+ */
+export function encodeI64Key(value: number | bigint): Buffer {
+    const n = BigInt(value);
+    if (n < I64_MIN || n > I64_MAX) {
+        throw new DatabaseError(`Value out of i64 range: ${value}`);
+    }
+    if (nativeCodec?.sochdb_encode_i64_key) {
+        return callNativeScalar(nativeCodec.sochdb_encode_i64_key, n);
+    }
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64BE(BigInt.asUintN(64, n) ^ SIGN_BIT);
+    return buf;
+}
+
+export function decodeI64Key(key: Buffer): bigint {
+    return BigInt.asIntN(64, key.readBigUInt64BE(0) ^ SIGN_BIT);
+}
+
+/**
+ * Encode a double as an order-preserving key (NaN is rejected)
+ */
+export function encodeF64Key(value: number): Buffer {
+    if (Number.isNaN(value)) {
+        throw new DatabaseError('Cannot encode NaN as an ordered key');
+    }
+    // -0 and 0 encode alike
+    const normalized = value === 0 ? 0 : value;
+    if (nativeCodec?.sochdb_encode_f64_key) {
+        return callNativeScalar(nativeCodec.sochdb_encode_f64_key, normalized);
+    }
+    const buf = Buffer.alloc(8);
+    buf.writeDoubleBE(normalized);
+    const bits = buf.readBigUInt64BE(0);
+    buf.writeBigUInt64BE(bits & SIGN_BIT ? ~bits & U64_MAX : bits | SIGN_BIT);
+    return buf;
+}
+
+export function decodeF64Key(key: Buffer): number {
+    const bits = key.readBigUInt64BE(0);
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64BE(bits & SIGN_BIT ? bits ^ SIGN_BIT : ~bits & U64_MAX);
+    return buf.readDoubleBE(0);
+}
+
+/**
+ * Encode a tuple of values as a single order-preserving key
+ *
+ * Tuples compare element by element, and a tuple sorts before any tuple
+ * it is a prefix of, so `encodeTupleKey(['user', 42])` can be used as a
+ * scan prefix for `encodeTupleKey(['user', 42, 'orders'])`. Numbers sort
+ * by value whatever their type, so `[1.5]` sorts between `[1]` and `[2n]`.
+ *
+ * @example
+ * ```typescript
+ * await db.put(encodeTupleKey(['events', 1700000000, 'click']), payload);
+ * for await (const [key] of db.scanPrefix(encodeTupleKey(['events']))) {
+ *     const [, ts, kind] = decodeTupleKey(key);
+ * }
+ * ```
+ */
+export function encodeTupleKey(elements: TupleElement[]): Buffer {
+    if (nativeCodec?.sochdb_encode_tuple_key) {
+        const spec = Buffer.from(JSON.stringify(elements.map(toNativeElement)));
+        return callNativeCodec(nativeCodec, nativeCodec.sochdb_encode_tuple_key, spec, 'encode tuple key');
+    }
+
+    const parts: Buffer[] = [];
+    for (const element of elements) {
+        if (Buffer.isBuffer(element)) {
+            parts.push(Buffer.from([TAG_BYTES]), escapeBytes(element));
+        } else if (typeof element === 'string') {
+            parts.push(Buffer.from([TAG_STRING]), escapeBytes(Buffer.from(element, 'utf8')));
+        } else if (typeof element === 'number' || typeof element === 'bigint') {
+            parts.push(Buffer.from([TAG_NUMBER]), encodeNumber(element));
+        } else {
+            throw new DatabaseError(`Unsupported tuple element type: ${typeof element}`);
+        }
+    }
+    return Buffer.concat(parts);
+}
+
+/**
+ * Decode a key produced by `encodeTupleKey()`
+ *
+ * Integers outside the safe range are returned as bigints, every other number as a number.
+ */
+export function decodeTupleKey(key: Buffer): TupleElement[] {
+    if (nativeCodec?.sochdb_decode_tuple_key) {
+        const json = callNativeCodec(nativeCodec, nativeCodec.sochdb_decode_tuple_key, key, 'decode tuple key');
+        return (JSON.parse(json.toString('utf8')) as NativeTupleElement[]).map(fromNativeElement);
+    }
+
+    const elements: TupleElement[] = [];
+    let pos = 0;
+    while (pos < key.length) {
+        const tag = key[pos++];
+        switch (tag) {
+            case TAG_BYTES:
+            case TAG_STRING: {
+                const [bytes, next] = unescapeBytes(key, pos);
+                elements.push(tag === TAG_STRING ? bytes.toString('utf8') : bytes);
+                pos = next;
+                break;
+            }
+            case TAG_NUMBER:
+                if (pos + 16 > key.length) {
+                    throw new DatabaseError(`Truncated number in tuple key at offset ${pos - 1}`);
+                }
+                elements.push(decodeNumber(key.subarray(pos, pos + 16)));
+                pos += 16;
+                break;
+            default:
+                throw new DatabaseError(`Invalid tuple key tag 0x${tag.toString(16)} at offset ${pos - 1}`);
+        }
+    }
+    return elements;
+}
+
+/**
+ * Numbers are stored as the nearest double followed by the signed distance
+ * from it, so integers beyond 2^53 keep their exact value and still sort
+ * among doubles. Doubles themselves always have a zero remainder.
+ */
+function encodeNumber(value: number | bigint): Buffer {
+    if (typeof value === 'number') {
+        return Buffer.concat([encodeF64Key(value), encodeI64Key(0)]);
+    }
+    if (value < I64_MIN || value > I64_MAX) {
+        throw new DatabaseError(`Value out of i64 range: ${value}`);
+    }
+    const approx = Number(value);
+    return Buffer.concat([encodeF64Key(approx), encodeI64Key(value - BigInt(approx))]);
+}
+
+function decodeNumber(bytes: Buffer): number | bigint {
+    const approx = decodeF64Key(bytes.subarray(0, 8));
+    const remainder = decodeI64Key(bytes.subarray(8, 16));
+    if (remainder === 0n && !isUnsafeI64(approx)) {
+        return approx;
+    }
+    return BigInt(approx) + remainder;
+}
+
+function isUnsafeI64(value: number): boolean {
+    return Number.isInteger(value) && !Number.isSafeInteger(value) && value >= -(2 ** 63) && value < 2 ** 63;
+}
+
+type NativeTupleElement = { bytes: string } | { string: string } | { f64: number } | { i64: string };
+
+function toNativeElement(element: TupleElement): NativeTupleElement {
+    if (Buffer.isBuffer(element)) return { bytes: element.toString('base64') };
+    if (typeof element === 'string') return { string: element };
+    if (typeof element === 'bigint') return { i64: element.toString() };
+    if (typeof element === 'number') {
+        if (Number.isNaN(element)) {
+            throw new DatabaseError('Cannot encode NaN as an ordered key');
+        }
+        return { f64: element };
+    }
+    throw new DatabaseError(`Unsupported tuple element type: ${typeof element}`);
+}
+
+function fromNativeElement(element: NativeTupleElement): TupleElement {
+    if ('bytes' in element) return Buffer.from(element.bytes, 'base64');
+    if ('string' in element) return element.string;
+    if ('f64' in element) return element.f64;
+    const n = BigInt(element.i64);
+    return n >= BigInt(Number.MIN_SAFE_INTEGER) && n <= BigInt(Number.MAX_SAFE_INTEGER) ? Number(n) : n;
+}
+
+function callNativeScalar(fn: any, value: number | bigint): Buffer {
+    const out = Buffer.alloc(8);
+    const res = fn(value, out);
+    if (res !== 0) {
+        throw new DatabaseError(`Failed to encode ordered key (Code ${res})`);
+    }
+    return out;
+}
+
+function callNativeCodec(bindings: NativeBindings, fn: any, input: Buffer, operation: string): Buffer {
+    const outPtr = [null];
+    const outLen = [0];
+    const res = fn(input, input.length, outPtr, outLen);
+    if (res !== 0) {
+        throw new DatabaseError(`Failed to ${operation} (Code ${res})`);
+    }
+    const out = Buffer.from(koffi.decode(outPtr[0], 'uint8', outLen[0]));
+    bindings.sochdb_free_bytes(outPtr[0], outLen[0]);
+    return out;
+}
+
+function escapeBytes(bytes: Buffer): Buffer {
+    const out: number[] = [];
+    for (const b of bytes) {
+        out.push(b);
+        if (b === 0x00) out.push(0xff);
+    }
+    out.push(0x00);
+    return Buffer.from(out);
+}
+
+function unescapeBytes(key: Buffer, start: number): [Buffer, number] {
+    const out: number[] = [];
+    let pos = start;
+    while (pos < key.length) {
+        const b = key[pos++];
+        if (b !== 0x00) {
+            out.push(b);
+        } else if (key[pos] === 0xff) {
+            out.push(0x00);
+            pos++;
+        } else {
+            return [Buffer.from(out), pos];
+        }
+    }
+    throw new DatabaseError('Unterminated string in tuple key');
+}
@@ -0,0 +1,124 @@
+/**
+ * Key Prefix Routing - Embedded Mode
+ *
+ * Named key prefixes with typed constructors, so keys are built from a
+ * single registered definition instead of ad-hoc string concatenation.
+ */
+
+import { DatabaseError } from '../errors';
+
+export type KeyPart = string | number | bigint;
+
+/**
+ * A registered key prefix
+ */
+export class KeyPrefix {
+    private readonly prefixBuf: Buffer;
+
+    constructor(public readonly name: string, public readonly prefix: string) {
+        this.prefixBuf = Buffer.from(prefix);
+    }
+
+    /**
+     * Build a key under this prefix; multiple parts are joined with '/'
+     */
+    key(...parts: KeyPart[]): Buffer {
+        if (parts.length === 0) {
+            throw new DatabaseError(`Key for prefix '${this.name}' needs at least one part`);
+        }
+        const segments = parts.map((part) => {
+            const s = String(part);
+            if (s.length === 0) {
+                throw new DatabaseError(`Empty key part for prefix '${this.name}'`);
+            }
+            return s;
+        });
+        return Buffer.concat([this.prefixBuf, Buffer.from(segments.join('/'))]);
+    }
+
+    /**
+     * Check whether a key belongs to this prefix
+     */
+    matches(key: Buffer): boolean {
+        return key.length >= this.prefixBuf.length &&
+            key.subarray(0, this.prefixBuf.length).equals(this.prefixBuf);
+    }
+
+    /**
+     * Strip the prefix from a key, returning the id part
+     */
+    parse(key: Buffer): string {
+        if (!this.matches(key)) {
+            throw new DatabaseError(`Key does not belong to prefix '${this.name}'`);
+        }
+        return key.subarray(this.prefixBuf.length).toString();
+    }
+
+    /**
+     * The raw prefix, suitable for `scanPrefix()`
+     */
+    toBuffer(): Buffer {
+        return Buffer.from(this.prefixBuf);
+    }
+}
+
+/**
+ * Typed key constructors generated from registered prefixes (`keys.user(id)`)
+ */
+export type KeyConstructors = Record<string, (...parts: KeyPart[]) => Buffer>;
+
+const PREFIX_NAME = /^[A-Za-z_][A-Za-z0-9_]*$/;
+
+/**
+ * Registry of named prefixes for one database
+ * @internal
+ */
+export class KeyPrefixRegistry {
+    private prefixes = new Map<string, KeyPrefix>();
+    readonly keys: KeyConstructors = {};
+
+    /**
+     * Validate a new prefix against the registry without adding it
+     */
+    validate(name: string, prefix: string): void {
+        if (!PREFIX_NAME.test(name)) {
+            throw new DatabaseError(`Invalid prefix name: '${name}'`);
+        }
+        if (prefix.length === 0) {
+            throw new DatabaseError(`Prefix '${name}' must not be empty`);
+        }
+        if (this.prefixes.has(name)) {
+            throw new DatabaseError(`Prefix '${name}' is already defined`);
+        }
+        for (const existing of this.prefixes.values()) {
+            if (existing.prefix.startsWith(prefix) || prefix.startsWith(existing.prefix)) {
+                throw new DatabaseError(
+                    `Prefix '${name}' ("${prefix}") overlaps prefix '${existing.name}' ("${existing.prefix}")`
+                );
+            }
+        }
+    }
+
+    add(name: string, prefix: string): KeyPrefix {
+        const keyPrefix = new KeyPrefix(name, prefix);
+        this.prefixes.set(name, keyPrefix);
+        this.keys[name] = (...parts: KeyPart[]) => keyPrefix.key(...parts);
+        return keyPrefix;
+    }
+
+    get(name: string): KeyPrefix | undefined {
+        return this.prefixes.get(name);
+    }
+
+    /**
+     * Find the registered prefix a key belongs to
+     */
+    route(key: Buffer): KeyPrefix | undefined {
+        for (const prefix of this.prefixes.values()) {
+            if (prefix.matches(key)) {
+                return prefix;
+            }
+        }
+        return undefined;
+    }
+}
@@ -0,0 +1,150 @@
+/**
+ * Keyspaces - Embedded Mode
+ *
+ * A keyspace is a named, prefix-isolated region of the database with its
+ * own storage tuning (compression codec, block cache priority, bloom filter).
+ * Settings are applied by the native engine to every key under the keyspace.
+ */
+
+import { DatabaseError } from '../errors';
+import type { EmbeddedDatabase, PutOptions, ScanOptions } from './database';
+import type { EmbeddedTransaction } from './transaction';
+import { BytesLike, toBuffer } from './key-encoding';
+
+export type CompressionCodec = 'none' | 'lz4' | 'zstd';
+export type CachePriority = 'low' | 'normal' | 'high';
+
+export interface KeyspaceOptions {
+    /** Block compression codec for this keyspace */
+    compression?: CompressionCodec;
+    /** Block cache priority; low-priority blocks are evicted first */
+    cachePriority?: CachePriority;
+    /** Bloom filter bits per key (0 disables the filter) */
+    bloomFilterBitsPerKey?: number;
+}
+
+const KEYSPACE_ROOT = '_ks/';
+
+export const COMPRESSION_CODES: Record<CompressionCodec, number> = { none: 0, lz4: 1, zstd: 2 };
+const CACHE_PRIORITY_CODES: Record<CachePriority, number> = { low: 0, normal: 1, high: 2 };
+
+/**
+ * Encode keyspace options into the native KeyspaceConfig struct
+ * @internal
+ */
+export function toNativeKeyspaceConfig(options: KeyspaceOptions): Record<string, any> {
+    return {
+        compression: COMPRESSION_CODES[options.compression ?? 'none'],
+        compression_set: options.compression !== undefined,
+        cache_priority: CACHE_PRIORITY_CODES[options.cachePriority ?? 'normal'],
+        cache_priority_set: options.cachePriority !== undefined,
+        bloom_bits_per_key: options.bloomFilterBitsPerKey ?? 0,
+        bloom_bits_per_key_set: options.bloomFilterBitsPerKey !== undefined,
+    };
+}
+
+/**
+ * Handle to a named keyspace
+ *
+ * @example
+ * ```typescript
+ * const blobs = db.keyspace('blobs', { compression: 'zstd', cachePriority: 'low', bloomFilterBitsPerKey: 0 });
+ * const index = db.keyspace('index', { compression: 'none', cachePriority: 'high', bloomFilterBitsPerKey: 10 });
+ *
+ * await blobs.put(Buffer.from('img:1'), largeBuffer);
+ * await index.put(Buffer.from('by_name:alice'), Buffer.from('img:1'));
+ * ```
+ */
+export class Keyspace {
+    private db: EmbeddedDatabase;
+    private _name: string;
+    private _options: KeyspaceOptions;
+    private prefix: Buffer;
+
+    constructor(db: EmbeddedDatabase, name: string, options: KeyspaceOptions = {}) {
+        if (!name || name.includes('/')) {
+            throw new DatabaseError(`Invalid keyspace name: '${name}'`);
+        }
+        this.db = db;
+        this._name = name;
+        this._options = options;
+        this.prefix = Keyspace.prefixFor(name);
+    }
+
+    /**
+     * Key prefix under which a keyspace's entries are stored
+     * @internal
+     */
+    static prefixFor(name: string): Buffer {
+        return Buffer.from(`${KEYSPACE_ROOT}${name}/`);
+    }
+
+    get name(): string {
+        return this._name;
+    }
+
+    get options(): KeyspaceOptions {
+        return { ...this._options };
+    }
+
+    async put(key: BytesLike, value: BytesLike, options?: PutOptions): Promise<void> {
+        return this.db.put(this.encodeKey(key), value, options);
+    }
+
+    async get(key: BytesLike): Promise<Buffer | null> {
+        return this.db.get(this.encodeKey(key));
+    }
+
+    async delete(key: BytesLike): Promise<void> {
+        return this.db.delete(this.encodeKey(key));
+    }
+
+    /**
+     * Scan keys with prefix inside this keyspace (keys are yielded without the keyspace prefix)
+     */
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        for await (const [key, value] of this.db.scanPrefix(this.encodeKey(prefix), options)) {
+            yield [key.subarray(this.prefix.length), value];
+        }
+    }
+
+    /**
+     * Full storage key for a user key in this keyspace
+     * @internal
+     */
+    encodeKey(key: BytesLike): Buffer {
+        return Buffer.concat([this.prefix, toBuffer(key)]);
+    }
+}
+
+/**
+ * View of a keyspace bound to a transaction
+ *
+ * Writes through several views of the same transaction commit or abort together.
+ */
+export class TransactionKeyspace {
+    constructor(private txn: EmbeddedTransaction, private keyspace: Keyspace) {}
+
+    get name(): string {
+        return this.keyspace.name;
+    }
+
+    async put(key: BytesLike, value: BytesLike, options?: PutOptions): Promise<void> {
+        return this.txn.put(this.keyspace.encodeKey(key), value, options);
+    }
+
+    async get(key: BytesLike): Promise<Buffer | null> {
+        return this.txn.get(this.keyspace.encodeKey(key));
+    }
+
+    async delete(key: BytesLike): Promise<void> {
+        return this.txn.delete(this.keyspace.encodeKey(key));
+    }
+
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        const stripLen = Keyspace.prefixFor(this.keyspace.name).length;
+        for await (const [key, value] of this.txn.scanPrefix(this.keyspace.encodeKey(prefix), options)) {
+            yield [key.subarray(stripLen), value];
+        }
+    }
+}
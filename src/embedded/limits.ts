@@ -0,0 +1,57 @@
+/**
+ * Size Limits - Embedded Mode
+ *
+ * Keys and values are checked against configurable limits before they are
+ * handed to the native library, so pathological inputs fail with a typed
+ * error instead of reaching the engine.
+ */
+
+import { DatabaseError, KeyTooLargeError, ValueTooLargeError } from '../errors';
+
+/** Default `maxKeyBytes` (64 KiB) */
+export const DEFAULT_MAX_KEY_BYTES = 64 * 1024;
+/** Default `maxValueBytes` (64 MiB) */
+export const DEFAULT_MAX_VALUE_BYTES = 64 * 1024 * 1024;
+
+export interface SizeLimits {
+    maxKeyBytes: number;
+    maxValueBytes: number;
+}
+
+/**
+ * Resolve configured limits, applying defaults
+ * @internal
+ */
+export function resolveSizeLimits(config: Partial<SizeLimits> = {}): SizeLimits {
+    const limits = {
+        maxKeyBytes: config.maxKeyBytes ?? DEFAULT_MAX_KEY_BYTES,
+        maxValueBytes: config.maxValueBytes ?? DEFAULT_MAX_VALUE_BYTES,
+    };
+    for (const [name, limit] of Object.entries(limits)) {
+        if (!Number.isSafeInteger(limit) || limit <= 0) {
+            throw new DatabaseError(`${name} must be a positive integer, got ${limit}`);
+        }
+    }
+    return limits;
+}
+
+/**
+ * Throw KeyTooLargeError if `key` exceeds the limit
+ * @internal
+ */
+export function checkKeySize(key: Buffer, limits: SizeLimits): void {
+    if (key.length > limits.maxKeyBytes) {
+        throw new KeyTooLargeError(key.length, limits.maxKeyBytes);
+    }
+}
+
+/**
+ * Throw KeyTooLargeError / ValueTooLargeError if either exceeds its limit
+ * @internal
+ */
+export function checkEntrySize(key: Buffer, value: Buffer, limits: SizeLimits): void {
+    checkKeySize(key, limits);
+    if (value.length > limits.maxValueBytes) {
+        throw new ValueTooLargeError(key, value.length, limits.maxValueBytes);
+    }
+}
@@ -0,0 +1,173 @@
+/**
+ * Native Memory Accounting - Embedded Mode
+ *
+ * Memory held by the storage engine lives outside the V8 heap, so it does
+ * not show up in `process.memoryUsage().heapUsed` or heap snapshots. These
+ * helpers report it so it can be graphed next to the JS heap and checked
+ * against container limits.
+ *
+ * The engine is loaded through koffi, which has no `napi_env`, so V8 does
+ * not see these allocations on its own. When the small external-memory
+ * addon is installed (`_bin/<target>/sochdb_external_memory.node`, or
+ * `npm run build:native`), each open database polls its usage and feeds the
+ * change to `napi_adjust_external_memory`, so it shows up in
+ * `process.memoryUsage().external` and counts towards GC pressure.
+ */
+
+import { DatabaseError } from '../errors';
+import { NativeBindings } from './ffi/bindings';
+import { findExternalMemoryAddon } from './ffi/library-finder';
+
+export interface NativeMemoryUsage {
+    /** Active and immutable memtables */
+    memtableBytes: number;
+    /** Block cache contents */
+    blockCacheBytes: number;
+    /** Values pinned by open iterators and snapshots */
+    pinnedValueBytes: number;
+    /** Index/filter blocks, WAL buffers and other engine allocations */
+    otherBytes: number;
+    /** Sum of the above */
+    totalBytes: number;
+}
+
+/**
+ * Convert the native MemoryUsage struct
+ * @internal
+ */
+export function fromNativeMemoryUsage(usage: any): NativeMemoryUsage {
+    const memtableBytes = Number(usage.memtable_bytes);
+    const blockCacheBytes = Number(usage.block_cache_bytes);
+    const pinnedValueBytes = Number(usage.pinned_value_bytes);
+    const otherBytes = Number(usage.other_bytes);
+    return {
+        memtableBytes,
+        blockCacheBytes,
+        pinnedValueBytes,
+        otherBytes,
+        totalBytes: memtableBytes + blockCacheBytes + pinnedValueBytes + otherBytes,
+    };
+}
+
+/**
+ * Native memory held by every database open in this process
+ *
+ * @example
+ * ```typescript
+ * setInterval(() => {
+ *     const { heapUsed } = process.memoryUsage();
+ *     const native = nativeMemoryUsage();
+ *     gauge.set({ kind: 'js_heap' }, heapUsed);
+ *     gauge.set({ kind: 'sochdb_native' }, native.totalBytes);
+ * }, 10_000).unref();
+ * ```
+ */
+export function nativeMemoryUsage(): NativeMemoryUsage {
+    const bindings = NativeBindings.getInstance();
+    if (!bindings.sochdb_process_memory_usage) {
+        throw new DatabaseError(
+            'Native memory accounting is not supported by the loaded SochDB native library. ' +
+            'Please upgrade the native library.'
+        );
+    }
+    return fromNativeMemoryUsage(bindings.sochdb_process_memory_usage());
+}
+
+/** Default for `externalMemoryIntervalMs` */
+export const DEFAULT_EXTERNAL_MEMORY_INTERVAL_MS = 1000;
+
+/**
+ * Validate `externalMemoryIntervalMs`
+ * @internal
+ */
+export function resolveExternalMemoryInterval(intervalMs?: number): number {
+    if (intervalMs === undefined) {
+        return DEFAULT_EXTERNAL_MEMORY_INTERVAL_MS;
+    }
+    if (!Number.isFinite(intervalMs) || intervalMs < 0) {
+        throw new DatabaseError(
+            `externalMemoryIntervalMs must be a non-negative number, got ${intervalMs}`
+        );
+    }
+    return intervalMs;
+}
+
+let adjuster: ((deltaBytes: number) => number) | null | undefined;
+
+/**
+ * `napi_adjust_external_memory` from the optional addon, or null when it is
+ * not installed or fails to load
+ * @internal
+ */
+export function externalMemoryAdjuster(): ((deltaBytes: number) => number) | null {
+    if (adjuster === undefined) {
+        adjuster = null;
+        const addonPath = findExternalMemoryAddon();
+        if (addonPath) {
+            try {
+                // dlopen works from both the CJS and ESM builds
+                const addon = { exports: {} as { adjust?: (deltaBytes: number) => number } };
+                process.dlopen(addon, addonPath);
+                adjuster = addon.exports.adjust ?? null;
+            } catch {
+                adjuster = null;
+            }
+        }
+    }
+    return adjuster;
+}
+
+/**
+ * Periodically reports a database's native memory to V8 as external memory
+ * @internal
+ */
+export class ExternalMemoryReporter {
+    private reported = 0;
+    private stopped = false;
+    private timer: ReturnType<typeof setInterval> | null = null;
+
+    constructor(
+        private readonly read: () => number,
+        private readonly adjust: (deltaBytes: number) => number,
+        intervalMs: number
+    ) {
+        this.update();
+        if (!this.stopped) {
+            this.timer = setInterval(() => this.update(), intervalMs);
+            this.timer.unref?.();
+        }
+    }
+
+    /** Report the change since the last update */
+    update(): void {
+        if (this.stopped) {
+            return;
+        }
+        let total: number;
+        try {
+            total = this.read();
+        } catch {
+            // Usage became unreadable (e.g. the handle is closing); stop polling
+            this.stop();
+            return;
+        }
+        const delta = total - this.reported;
+        if (delta !== 0) {
+            this.adjust(delta);
+            this.reported = total;
+        }
+    }
+
+    /** Stop polling and give back everything reported so far */
+    stop(): void {
+        this.stopped = true;
+        if (this.timer) {
+            clearInterval(this.timer);
+            this.timer = null;
+        }
+        if (this.reported !== 0) {
+            this.adjust(-this.reported);
+            this.reported = 0;
+        }
+    }
+}
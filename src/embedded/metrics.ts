@@ -0,0 +1,85 @@
+/**
+ * Engine Metrics - Embedded Mode
+ *
+ * Latency histograms and throughput counters recorded inside the native
+ * engine, so they measure storage behaviour rather than FFI overhead.
+ */
+
+export interface OperationMetrics {
+    /** Completed operations since open */
+    count: number;
+    /** Operations that returned an error */
+    errors: number;
+    /** Latency percentiles in microseconds */
+    p50Us: number;
+    p90Us: number;
+    p99Us: number;
+    p999Us: number;
+    maxUs: number;
+    /** Operations per second over the engine's sliding window */
+    throughputPerSec: number;
+}
+
+export interface DatabaseMetrics {
+    /** Seconds since the database was opened */
+    uptimeSecs: number;
+    /** Metrics keyed by operation name (get, put, delete, scan, commit, checkpoint, ...) */
+    operations: Record<string, OperationMetrics>;
+}
+
+/**
+ * Convert the engine's metrics JSON into DatabaseMetrics
+ * @internal
+ */
+export function parseMetrics(json: string): DatabaseMetrics {
+    const raw = JSON.parse(json);
+    const operations: Record<string, OperationMetrics> = {};
+    for (const [name, op] of Object.entries<any>(raw.operations ?? {})) {
+        operations[name] = {
+            count: op.count ?? 0,
+            errors: op.errors ?? 0,
+            p50Us: op.p50_us ?? 0,
+            p90Us: op.p90_us ?? 0,
+            p99Us: op.p99_us ?? 0,
+            p999Us: op.p999_us ?? 0,
+            maxUs: op.max_us ?? 0,
+            throughputPerSec: op.throughput_per_sec ?? 0,
+        };
+    }
+    return { uptimeSecs: raw.uptime_secs ?? 0, operations };
+}
+
+export type StallReason = 'memtable_full' | 'wal_sync_backlog' | 'compaction_debt';
+
+export interface StallInfo {
+    /** Writes are blocked right now */
+    stalled: boolean;
+    /** Why writes are blocked (empty when not stalled) */
+    reasons: StallReason[];
+    /** How long the current stall has lasted */
+    stalledForMs: number;
+    /** Stalls since open */
+    totalStalls: number;
+    /** Time spent stalled since open */
+    totalStallMs: number;
+}
+
+const STALL_REASON_BITS: Array<[number, StallReason]> = [
+    [1, 'memtable_full'],
+    [2, 'wal_sync_backlog'],
+    [4, 'compaction_debt'],
+];
+
+/**
+ * Convert the native StallInfo struct
+ * @internal
+ */
+export function fromNativeStallInfo(info: any): StallInfo {
+    return {
+        stalled: !!info.stalled,
+        reasons: STALL_REASON_BITS.filter(([bit]) => (info.reasons & bit) !== 0).map(([, reason]) => reason),
+        stalledForMs: Number(info.stalled_for_ms),
+        totalStalls: Number(info.total_stalls),
+        totalStallMs: Number(info.total_stall_ms),
+    };
+}
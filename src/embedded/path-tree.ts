@@ -0,0 +1,82 @@
+/**
+ * Path Tree Listings - Embedded Mode
+ *
+ * Directory-style views over documents stored with `putPath`. Listings run
+ * inside a transaction, so passing a snapshot makes a listing and the
+ * document reads that follow it observe the same state.
+ */
+
+/**
+ * Order of listed and scanned paths
+ *
+ * - `bytes` - raw key order, so "a!" sorts before "a/b" ('!' < '/')
+ * - `components` - segment by segment, so everything under "a" sorts
+ *   before "a!"; segments compare as bytes
+ */
+export type PathOrder = 'bytes' | 'components';
+
+/** @internal */
+export const PATH_ORDER_CODES: Record<PathOrder, number> = {
+    bytes: 0,
+    components: 1,
+};
+
+/**
+ * A direct child of a listed path
+ */
+export interface PathEntry {
+    /** Last path component */
+    name: string;
+    /** Full path of the child */
+    path: string;
+    /** A document is stored at this path */
+    hasValue: boolean;
+    /** Deeper paths exist below this one */
+    hasChildren: boolean;
+    /** Size of the stored document in bytes (0 if none) */
+    size: number;
+}
+
+/**
+ * Aggregate counts for a subtree
+ */
+export interface TreeSummary {
+    path: string;
+    /** Documents stored at or below `path` */
+    documents: number;
+    /** Interior paths (paths with children) at or below `path` */
+    directories: number;
+    /** Total document bytes */
+    totalBytes: number;
+    /** Deepest level below `path` that holds a document */
+    depth: number;
+}
+
+/**
+ * Convert the native listing JSON
+ * @internal
+ */
+export function parsePathEntries(parent: string, raw: any[]): PathEntry[] {
+    const base = parent.endsWith('/') ? parent : `${parent}/`;
+    return raw.map((e) => ({
+        name: e.name,
+        path: `${base}${e.name}`,
+        hasValue: !!e.has_value,
+        hasChildren: !!e.has_children,
+        size: e.value_len ?? 0,
+    }));
+}
+
+/**
+ * Convert the native summary JSON
+ * @internal
+ */
+export function parseTreeSummary(path: string, raw: any): TreeSummary {
+    return {
+        path,
+        documents: raw.documents ?? 0,
+        directories: raw.directories ?? 0,
+        totalBytes: raw.total_bytes ?? 0,
+        depth: raw.depth ?? 0,
+    };
+}
@@ -0,0 +1,221 @@
+/**
+ * Path Segments - Embedded Mode
+ *
+ * Paths are "/"-separated segments. A segment holding a "/" would silently
+ * split into two tree levels, so segments are percent-escaped: "%", "/" and
+ * control characters (and, for binary segments, every non-printable byte)
+ * become `%XX`. `putPath` rejects paths that are not in this form.
+ *
+ * Once a database has been opened, escaping and validation are done by its
+ * native library, so the SDK and the engine agree on what a valid path is.
+ * The JS implementations here match it (the tests check them against the
+ * native functions) and are used before then or without a native library.
+ */
+
+import * as koffi from 'koffi';
+import { InvalidPathError } from '../errors';
+import type { NativeBindings } from './ffi/bindings';
+
+export const PATH_SEPARATOR = '/';
+
+let nativePaths: NativeBindings | null = null;
+
+/**
+ * Escape and validate paths with these bindings from now on (null reverts
+ * to the JS implementations)
+ * @internal
+ */
+export function useNativePathCodec(bindings: NativeBindings | null): void {
+    nativePaths = bindings;
+}
+
+/** Anything that can be turned into one path segment */
+export type PathSegment = string | number | bigint | Buffer;
+
+/**
+ * Escape one segment so it can be embedded in a path
+ *
+ * Strings keep printable characters (including non-ASCII) as-is; Buffers are
+ * treated as raw bytes and round-trip through `unescapeSegmentBytes`.
+ */
+export function escapeSegment(segment: PathSegment): string {
+    if (nativePaths?.sochdb_escape_path_segment) {
+        const binary = Buffer.isBuffer(segment);
+        const bytes = binary ? segment : Buffer.from(String(segment), 'utf8');
+        if (bytes.length === 0) {
+            throw new InvalidPathError('', 'segments must not be empty');
+        }
+        const [res, out] = callNativePathFn(nativePaths, (outPtr, outLen) =>
+            nativePaths!.sochdb_escape_path_segment(bytes, bytes.length, binary, outPtr, outLen));
+        if (res !== 0) {
+            throw new InvalidPathError(String(segment), `segment could not be escaped (Code ${res})`);
+        }
+        return out;
+    }
+
+    if (Buffer.isBuffer(segment)) {
+        if (segment.length === 0) {
+            throw new InvalidPathError('', 'segments must not be empty');
+        }
+        let out = '';
+        for (const byte of segment) {
+            out += byte > 0x20 && byte < 0x7f && byte !== 0x25 && byte !== 0x2f
+                ? String.fromCharCode(byte)
+                : percentEncode(byte);
+        }
+        return out;
+    }
+
+    const text = String(segment);
+    if (text.length === 0) {
+        throw new InvalidPathError('', 'segments must not be empty');
+    }
+    return text.replace(/[%/\u0000-\u001f\u007f]/g, (ch) => percentEncode(ch.charCodeAt(0)));
+}
+
+/**
+ * Decode an escaped segment back to a string
+ */
+export function unescapeSegment(segment: string): string {
+    return unescapeSegmentBytes(segment).toString('utf8');
+}
+
+/**
+ * Decode an escaped segment back to its raw bytes
+ */
+export function unescapeSegmentBytes(segment: string): Buffer {
+    const bytes: Buffer[] = [];
+    let literalStart = 0;
+    for (let i = 0; i < segment.length; i++) {
+        if (segment[i] !== '%') continue;
+        const hex = segment.slice(i + 1, i + 3);
+        if (!/^[0-9A-Fa-f]{2}$/.test(hex)) {
+            throw new InvalidPathError(segment, `malformed escape at offset ${i}`);
+        }
+        bytes.push(Buffer.from(segment.slice(literalStart, i), 'utf8'), Buffer.from([parseInt(hex, 16)]));
+        i += 2;
+        literalStart = i + 1;
+    }
+    bytes.push(Buffer.from(segment.slice(literalStart), 'utf8'));
+    return Buffer.concat(bytes);
+}
+
+/**
+ * Throw InvalidPathError unless `path` is a non-empty sequence of
+ * non-empty, properly escaped segments
+ */
+export function validatePath(path: string): void {
+    if (nativePaths?.sochdb_validate_path) {
+        const [res, reason] = callNativePathFn(nativePaths, (outPtr, outLen) =>
+            nativePaths!.sochdb_validate_path(path, outPtr, outLen));
+        if (res !== 0) {
+            throw new InvalidPathError(path, reason || `invalid path (Code ${res})`);
+        }
+        return;
+    }
+
+    if (path.length === 0) {
+        throw new InvalidPathError(path, 'path must not be empty');
+    }
+    if (path.startsWith(PATH_SEPARATOR)) {
+        throw new InvalidPathError(path, `paths are relative; drop the leading "${PATH_SEPARATOR}"`);
+    }
+    const segments = path.split(PATH_SEPARATOR);
+    for (const [index, segment] of segments.entries()) {
+        if (segment.length === 0) {
+            throw new InvalidPathError(path, `segment ${index} is empty`);
+        }
+        if (/[\u0000-\u001f\u007f]/.test(segment)) {
+            throw new InvalidPathError(path, `segment ${index} contains an unescaped control character`);
+        }
+        const bad = segment.search(/%(?![0-9A-Fa-f]{2})/);
+        if (bad !== -1) {
+            throw new InvalidPathError(
+                path,
+                `segment ${index} has a malformed escape at offset ${bad}; a literal "%" must be written as %25`
+            );
+        }
+    }
+}
+
+/**
+ * Immutable builder for escaped paths
+ *
+ * @example
+ * ```typescript
+ * const path = PathBuilder.of('users', email, 'files', 'a/b.txt');
+ * await db.putPath(path.toString(), data);   // "users/<email>/files/a%2Fb.txt"
+ *
+ * PathBuilder.parse(entry.path).segments;      // ['users', email, 'files', 'a/b.txt']
+ * ```
+ */
+export class PathBuilder {
+    private readonly parts: readonly string[];
+
+    private constructor(parts: readonly string[]) {
+        this.parts = parts;
+    }
+
+    /**
+     * Build a path from unescaped segments
+     */
+    static of(...segments: PathSegment[]): PathBuilder {
+        if (segments.length === 0) {
+            throw new InvalidPathError('', 'path must not be empty');
+        }
+        return new PathBuilder(segments.map(escapeSegment));
+    }
+
+    /**
+     * Parse an escaped path (as returned by `listPath`)
+     */
+    static parse(path: string): PathBuilder {
+        validatePath(path);
+        return new PathBuilder(path.split(PATH_SEPARATOR));
+    }
+
+    /**
+     * New path with `segments` appended
+     */
+    child(...segments: PathSegment[]): PathBuilder {
+        return new PathBuilder([...this.parts, ...segments.map(escapeSegment)]);
+    }
+
+    /**
+     * Path without its last segment, or null for a single-segment path
+     */
+    get parent(): PathBuilder | null {
+        return this.parts.length > 1 ? new PathBuilder(this.parts.slice(0, -1)) : null;
+    }
+
+    /**
+     * Unescaped segments
+     */
+    get segments(): string[] {
+        return this.parts.map(unescapeSegment);
+    }
+
+    toString(): string {
+        return this.parts.join(PATH_SEPARATOR);
+    }
+}
+
+/**
+ * Call a native `(..., out_ptr, out_len) -> int` path function; the output
+ * (escaped segment, or the reason a path is invalid) is UTF-8
+ */
+function callNativePathFn(bindings: NativeBindings, call: (outPtr: any[], outLen: any[]) => number): [number, string] {
+    const outPtr = [null];
+    const outLen = [0];
+    const res = call(outPtr, outLen);
+    if (!outPtr[0]) {
+        return [res, ''];
+    }
+    const text = Buffer.from(koffi.decode(outPtr[0], 'uint8', outLen[0])).toString('utf8');
+    bindings.sochdb_free_bytes(outPtr[0], outLen[0]);
+    return [res, text];
+}
+
+function percentEncode(byte: number): string {
+    return `%${byte.toString(16).toUpperCase().padStart(2, '0')}`;
+}
@@ -0,0 +1,40 @@
+/**
+ * Scan Projections - Embedded Mode
+ *
+ * Derived per-entry outputs for scans. The native engine computes these;
+ * the helper here decodes its output.
+ */
+
+/**
+ * What to return for each scanned entry
+ *
+ * - `'keys'`: only the key
+ * - `'length'`: the key and the value length in bytes
+ * - `{ jsonField }`: the key and one field of a JSON document (dot-separated path)
+ */
+export type ScanProjection = 'keys' | 'length' | { jsonField: string };
+
+export interface ProjectedEntry {
+    key: Buffer;
+    /** Value length in bytes (`'length'` projection) */
+    length?: number;
+    /** Extracted JSON field, undefined when missing (`jsonField` projection) */
+    value?: unknown;
+}
+
+/**
+ * Decode the value bytes produced by a native projected scan
+ *
+ * The engine returns an empty value for `'keys'`, a little-endian u64 for
+ * `'length'`, and the JSON text of the field (empty when missing) for `jsonField`.
+ * @internal
+ */
+export function decodeProjected(key: Buffer, raw: Buffer, projection: ScanProjection): ProjectedEntry {
+    if (projection === 'keys') {
+        return { key };
+    }
+    if (projection === 'length') {
+        return { key, length: Number(raw.readBigUInt64LE(0)) };
+    }
+    return { key, value: raw.length === 0 ? undefined : JSON.parse(raw.toString()) };
+}
@@ -0,0 +1,169 @@
+/**
+ * Reader Handles - Embedded Mode
+ *
+ * A reader handle is an extra, read-only handle on an open database. It
+ * shares the engine state of the database it came from (no new open, no
+ * extra file locks), reads through read-only transactions, and can be
+ * closed on its own, so each subsystem of an application can be given
+ * independent read access.
+ */
+
+import { DatabaseError } from '../errors';
+import type { EmbeddedDatabase, PathListOptions, PathScanOptions, ReadOptions, ScanOptions } from './database';
+import type { EmbeddedTransaction } from './transaction';
+import type { BytesLike } from './key-encoding';
+import { IoProfile, MaybeProfiled, Profiled, rejectScanProfile } from './io-profile';
+import type { PathEntry, TreeSummary } from './path-tree';
+import { Snapshot } from './snapshot';
+
+/**
+ * Read-only handle sharing an open database's engine
+ *
+ * @example
+ * ```typescript
+ * const reports = db.readerHandle();
+ * reportingService.start(reports);
+ *
+ * // Later: revoke the reporting subsystem's access without touching the database
+ * reports.close();
+ * ```
+ */
+export class ReaderHandle {
+    private db: EmbeddedDatabase;
+    private closed = false;
+    private snapshots = new Set<Snapshot>();
+
+    /**
+     * @internal
+     */
+    constructor(db: EmbeddedDatabase) {
+        this.db = db;
+    }
+
+    get isClosed(): boolean {
+        return this.closed;
+    }
+
+    async get<O extends ReadOptions | undefined = undefined>(
+        key: BytesLike,
+        options?: O
+    ): Promise<MaybeProfiled<Buffer | null, O>> {
+        this.ensureOpen();
+        if (options?.snapshot) {
+            return this.db.get(key, options);
+        }
+        return this.read(options, (txn) => txn.get(key, options)) as Promise<MaybeProfiled<Buffer | null, O>>;
+    }
+
+    async getPath<O extends ReadOptions | undefined = undefined>(
+        path: string,
+        options?: O
+    ): Promise<MaybeProfiled<Buffer | null, O>> {
+        this.ensureOpen();
+        if (options?.snapshot) {
+            return this.db.getPath(path, options);
+        }
+        return this.read(options, (txn) => txn.getPath(path, options)) as Promise<MaybeProfiled<Buffer | null, O>>;
+    }
+
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        this.ensureOpen();
+        rejectScanProfile(options);
+        yield* this.guard(options?.snapshot
+            ? this.db.scanPrefix(prefix, options)
+            : this.scan((txn) => txn.scanPrefix(prefix, options), options?.onProfile));
+    }
+
+    async *scanPath(path: string, options?: PathScanOptions): AsyncGenerator<[string, Buffer]> {
+        this.ensureOpen();
+        rejectScanProfile(options);
+        yield* this.guard(options?.snapshot
+            ? this.db.scanPath(path, options)
+            : this.scan((txn) => txn.scanPath(path, options)));
+    }
+
+    async listPath(path: string, options?: PathListOptions): Promise<PathEntry[]> {
+        this.ensureOpen();
+        if (options?.snapshot) {
+            return this.db.listPath(path, options);
+        }
+        return this.read(undefined, (txn) => txn.listPath(path, options?.order)) as Promise<PathEntry[]>;
+    }
+
+    async treeSummary(path: string, options?: PathListOptions): Promise<TreeSummary> {
+        this.ensureOpen();
+        if (options?.snapshot) {
+            return this.db.treeSummary(path, options);
+        }
+        return this.read(undefined, (txn) => txn.treeSummary(path, options?.maxDepth)) as Promise<TreeSummary>;
+    }
+
+    /**
+     * Pin a snapshot; it is released when this handle is closed
+     */
+    snapshot(): Snapshot {
+        this.ensureOpen();
+        const snapshot = new Snapshot(this.db.transaction({ readOnly: true }));
+        snapshot.onRelease = () => this.snapshots.delete(snapshot);
+        this.snapshots.add(snapshot);
+        return snapshot;
+    }
+
+    /**
+     * Close this handle and release its snapshots; the database stays open
+     */
+    close(): void {
+        if (this.closed) return;
+        this.closed = true;
+        for (const snapshot of [...this.snapshots]) {
+            snapshot.release();
+        }
+        this.db.releaseReader(this);
+    }
+
+    /**
+     * Run one read in a read-only transaction, so a reader can never write
+     * even through an internal path
+     */
+    private async read<T>(
+        options: ReadOptions | undefined,
+        fn: (txn: EmbeddedTransaction) => Promise<T>
+    ): Promise<T | Profiled<T>> {
+        const txn = this.db.transaction({ readOnly: true, timeoutMs: options?.timeoutMs });
+        try {
+            const value = await fn(txn);
+            return options?.profile ? { value, profile: txn.ioProfile() } : value;
+        } finally {
+            await txn.abort();
+        }
+    }
+
+    private async *scan<T>(
+        fn: (txn: EmbeddedTransaction) => AsyncGenerator<T>,
+        onProfile?: (profile: IoProfile) => void
+    ): AsyncGenerator<T> {
+        const txn = this.db.transaction({ readOnly: true });
+        try {
+            yield* fn(txn);
+            onProfile?.(txn.ioProfile());
+        } finally {
+            await txn.abort();
+        }
+    }
+
+    /**
+     * Stop yielding as soon as the handle is closed mid-scan
+     */
+    private async *guard<T>(source: AsyncGenerator<T>): AsyncGenerator<T> {
+        for await (const entry of source) {
+            yield entry;
+            this.ensureOpen();
+        }
+    }
+
+    private ensureOpen(): void {
+        if (this.closed) {
+            throw new DatabaseError('Reader handle is closed');
+        }
+    }
+}
@@ -0,0 +1,90 @@
+/**
+ * Retention Policies - Embedded Mode
+ *
+ * Declarative per-prefix retention enforced by the native engine during
+ * compaction: entries older than `maxAge`, or the oldest entries beyond
+ * `maxBytes`, are dropped as their files are rewritten.
+ */
+
+import { DatabaseError } from '../errors';
+
+export interface RetentionPolicy {
+    /** Maximum entry age, in ms or as a duration string (`'90s'`, `'12h'`, `'30d'`) */
+    maxAge?: number | string;
+    /** Maximum total size of the prefix; the oldest entries are dropped first */
+    maxBytes?: number;
+}
+
+export interface RetentionStats {
+    /** Prefix the policy applies to (decoded as UTF-8) */
+    prefix: string;
+    maxAgeMs: number | null;
+    maxBytes: number | null;
+    /** Entries dropped since open */
+    droppedKeys: number;
+    droppedBytes: number;
+    /** When compaction last enforced the policy, in ms since the epoch */
+    lastEnforcedAt: number | null;
+}
+
+const DURATION_UNITS: Record<string, number> = {
+    ms: 1,
+    s: 1000,
+    m: 60 * 1000,
+    h: 60 * 60 * 1000,
+    d: 24 * 60 * 60 * 1000,
+    w: 7 * 24 * 60 * 60 * 1000,
+};
+
+/**
+ * Parse `'30d'`-style durations into milliseconds (numbers pass through,
+ * rounded to whole milliseconds)
+ */
+export function parseDuration(duration: number | string): number {
+    if (typeof duration === 'number') {
+        if (!Number.isFinite(duration) || duration < 0) {
+            throw new DatabaseError(`Invalid duration: ${duration}`);
+        }
+        return Math.round(duration);
+    }
+    const match = /^(\d+(?:\.\d+)?)\s*(ms|s|m|h|d|w)$/.exec(duration.trim());
+    if (!match) {
+        throw new DatabaseError(`Invalid duration: '${duration}' (expected e.g. '500ms', '90s', '12h', '30d')`);
+    }
+    return Math.round(parseFloat(match[1]) * DURATION_UNITS[match[2]]);
+}
+
+/**
+ * Validate a policy and convert it to the native limits, where 0 means unlimited
+ * @internal
+ */
+export function toNativeRetention(policy: RetentionPolicy): { maxAgeMs: number; maxBytes: number } {
+    let maxAgeMs = 0;
+    if (policy.maxAge !== undefined) {
+        maxAgeMs = parseDuration(policy.maxAge);
+        // The engine reads 0 as "no age limit", the opposite of what was asked for
+        if (maxAgeMs === 0) {
+            throw new DatabaseError(`Retention maxAge must be at least 1ms, got ${String(policy.maxAge)}`);
+        }
+    }
+    const maxBytes = policy.maxBytes ?? 0;
+    if (policy.maxBytes !== undefined && (!Number.isSafeInteger(maxBytes) || maxBytes <= 0)) {
+        throw new DatabaseError(`Retention maxBytes must be a positive integer, got ${maxBytes}`);
+    }
+    return { maxAgeMs, maxBytes };
+}
+
+/**
+ * Convert the engine's retention stats JSON
+ * @internal
+ */
+export function parseRetentionStats(json: string): RetentionStats[] {
+    return JSON.parse(json).map((raw: any) => ({
+        prefix: Buffer.from(raw.prefix, 'base64').toString('utf8'),
+        maxAgeMs: raw.max_age_ms || null,
+        maxBytes: raw.max_bytes || null,
+        droppedKeys: raw.dropped_keys ?? 0,
+        droppedBytes: raw.dropped_bytes ?? 0,
+        lastEnforcedAt: raw.last_enforced_ms ?? null,
+    }));
+}
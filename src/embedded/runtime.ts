@@ -0,0 +1,120 @@
+/**
+ * Runtime Detection - Embedded Mode
+ *
+ * Describes the JavaScript runtime the SDK is running in (Node, Electron,
+ * Bun, Deno) and whether the loaded native library fits it, so desktop apps
+ * can check compatibility up front instead of failing on first use.
+ *
+ * The native library is called through koffi (N-API), so it does not depend
+ * on the runtime's module ABI; what has to match is the platform, CPU
+ * architecture and libc, and the process must be allowed to load native
+ * code at all (not a sandboxed Electron renderer).
+ */
+
+import { EngineInfo, engineInfo } from './engine-info';
+import { getTargetTriple, isMusl } from './ffi/library-finder';
+
+export type RuntimeName = 'node' | 'electron' | 'bun' | 'deno';
+
+export interface RuntimeInfo {
+    runtime: RuntimeName;
+    /** Version of `runtime` */
+    runtimeVersion: string;
+    /** Node.js version the runtime embeds or emulates */
+    nodeVersion: string;
+    /** Electron process type (`browser` for the main process, `renderer`, `worker`, `utility`), null outside Electron */
+    electronProcessType: string | null;
+    /** Node module ABI (`process.versions.modules`); Electron uses its own */
+    moduleAbi: string | null;
+    /** Highest N-API version the runtime supports */
+    napiVersion: string | null;
+    platform: NodeJS.Platform;
+    arch: string;
+    libc: 'glibc' | 'musl' | null;
+    /** Library target this runtime needs, e.g. `aarch64-unknown-linux-musl` */
+    expectedTarget: string | null;
+    /** Loaded native library, null if it could not be loaded */
+    engine: EngineInfo | null;
+    /** Reasons the native engine cannot be used here (empty when it can) */
+    problems: string[];
+    compatible: boolean;
+}
+
+/**
+ * Identify the JavaScript runtime
+ */
+export function detectRuntime(versions: NodeJS.ProcessVersions = process.versions): RuntimeName {
+    const extra = versions as Record<string, string | undefined>;
+    if (extra.electron) return 'electron';
+    if (extra.bun) return 'bun';
+    if (extra.deno) return 'deno';
+    return 'node';
+}
+
+/**
+ * Describe the runtime and check the native library against it
+ *
+ * Never throws; load failures are reported in `problems`.
+ *
+ * @example
+ * ```typescript
+ * // Electron main process, before opening any database
+ * const info = runtimeInfo();
+ * if (!info.compatible) {
+ *     dialog.showErrorBox('Storage unavailable', info.problems.join('\n'));
+ * }
+ * ```
+ */
+export function runtimeInfo(): RuntimeInfo {
+    const versions = process.versions as NodeJS.ProcessVersions & Record<string, string | undefined>;
+    const runtime = detectRuntime(versions);
+    const electronProcessType = runtime === 'electron'
+        ? ((process as { type?: string }).type ?? null)
+        : null;
+    const problems: string[] = [];
+
+    if (electronProcessType === 'renderer' && (process as { sandboxed?: boolean }).sandboxed) {
+        problems.push(
+            'Native libraries cannot be loaded in a sandboxed Electron renderer; ' +
+            'open the database in the main process and expose it over IPC'
+        );
+    }
+
+    let expectedTarget: string | null = null;
+    try {
+        expectedTarget = getTargetTriple();
+    } catch (error: any) {
+        problems.push(error.message);
+    }
+
+    let engine: EngineInfo | null = null;
+    if (problems.length === 0) {
+        try {
+            engine = engineInfo();
+        } catch (error: any) {
+            problems.push(`Failed to load the native library: ${error.message}`);
+        }
+    }
+    if (engine?.target && expectedTarget && engine.target !== expectedTarget) {
+        problems.push(
+            `Native library at ${engine.libraryPath} was built for ${engine.target}, ` +
+            `but this ${runtime} process needs ${expectedTarget}`
+        );
+    }
+
+    return {
+        runtime,
+        runtimeVersion: (runtime === 'node' ? versions.node : versions[runtime]) ?? versions.node,
+        nodeVersion: versions.node,
+        electronProcessType,
+        moduleAbi: versions.modules ?? null,
+        napiVersion: versions.napi ?? null,
+        platform: process.platform,
+        arch: process.arch,
+        libc: process.platform === 'linux' ? (isMusl() ? 'musl' : 'glibc') : null,
+        expectedTarget,
+        engine,
+        problems,
+        compatible: problems.length === 0,
+    };
+}
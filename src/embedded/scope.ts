@@ -0,0 +1,207 @@
+/**
+ * Scoped Views - Embedded Mode
+ *
+ * A scope is a lightweight handle whose keys and paths are transparently
+ * namespaced under a prefix. Libraries can be handed a scope instead of the
+ * whole database; nothing outside the prefix is reachable through it.
+ *
+ * Prefixes always end in "/" (one is appended if missing), so the scope
+ * `tenants/a` cannot reach keys of `tenants/ab`.
+ */
+
+import { DatabaseError } from '../errors';
+import type { EmbeddedDatabase, NativeReadOptions, PutOptions, ScanOptions } from './database';
+import type { CommitOptions, EmbeddedTransaction, TransactionCallback } from './transaction';
+import { BytesLike, toBuffer } from './key-encoding';
+
+export interface ScopeOptions {
+    /**
+     * Open every transaction of the scope read-only, so writes are rejected
+     * by the native engine with a ReadOnlyError. Narrower scopes inherit it.
+     */
+    readOnly?: boolean;
+}
+
+/**
+ * Database view confined to a key/path prefix
+ *
+ * @example
+ * ```typescript
+ * const tenant = db.scope('tenants/acme/');
+ * await tenant.put('users/1', Buffer.from('Alice'));  // stored at "tenants/acme/users/1"
+ *
+ * for await (const [key] of tenant.scanPrefix('users/')) {
+ *     console.log(key.toString());                      // "users/1"
+ * }
+ * ```
+ */
+export class ScopedDatabase {
+    private db: EmbeddedDatabase;
+    private _prefix: string;
+    private prefixBytes: Buffer;
+    private _readOnly: boolean;
+
+    /**
+     * @internal
+     */
+    constructor(db: EmbeddedDatabase, prefix: string, options: ScopeOptions = {}) {
+        if (!prefix) {
+            throw new DatabaseError('Scope prefix must not be empty');
+        }
+        this.db = db;
+        this._prefix = prefix.endsWith('/') ? prefix : `${prefix}/`;
+        this.prefixBytes = Buffer.from(this._prefix);
+        this._readOnly = options.readOnly ?? false;
+    }
+
+    /**
+     * Full prefix of this scope within the database
+     */
+    get prefix(): string {
+        return this._prefix;
+    }
+
+    get readOnly(): boolean {
+        return this._readOnly;
+    }
+
+    async put(key: BytesLike, value: BytesLike, options?: PutOptions): Promise<void> {
+        return this.withTransaction((txn) => txn.put(key, value, options));
+    }
+
+    async get(key: BytesLike, options?: NativeReadOptions): Promise<Buffer | null> {
+        return this.withTransaction((txn) => txn.get(key, options));
+    }
+
+    async delete(key: BytesLike): Promise<void> {
+        return this.withTransaction((txn) => txn.delete(key));
+    }
+
+    async putPath(path: string, value: BytesLike): Promise<void> {
+        return this.withTransaction((txn) => txn.putPath(path, value));
+    }
+
+    async getPath(path: string, options?: NativeReadOptions): Promise<Buffer | null> {
+        return this.withTransaction((txn) => txn.getPath(path, options));
+    }
+
+    /**
+     * Scan keys with prefix inside this scope (keys are yielded without the scope prefix)
+     */
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        const txn = this.transaction();
+        try {
+            yield* txn.scanPrefix(prefix, options);
+            await txn.commit();
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Begin a transaction confined to this scope
+     */
+    transaction(): ScopedTransaction {
+        return new ScopedTransaction(this.db.transaction({ readOnly: this._readOnly }), this._prefix);
+    }
+
+    /**
+     * Execute operations within a scoped transaction (with auto-commit/abort)
+     */
+    async withTransaction<T>(fn: (txn: ScopedTransaction) => Promise<T>): Promise<T> {
+        const txn = this.transaction();
+        try {
+            const result = await fn(txn);
+            await txn.commit();
+            return result;
+        } catch (error) {
+            await txn.abort();
+            throw error;
+        }
+    }
+
+    /**
+     * Narrow this scope further (a read-only scope stays read-only)
+     */
+    scope(prefix: string, options: ScopeOptions = {}): ScopedDatabase {
+        return new ScopedDatabase(this.db, `${this._prefix}${prefix}`, {
+            readOnly: this._readOnly || options.readOnly,
+        });
+    }
+
+    /**
+     * Full storage key for a key in this scope
+     */
+    encodeKey(key: BytesLike): Buffer {
+        return Buffer.concat([this.prefixBytes, toBuffer(key)]);
+    }
+}
+
+/**
+ * Transaction confined to a scope
+ */
+export class ScopedTransaction {
+    private txn: EmbeddedTransaction;
+    private prefix: string;
+    private prefixBytes: Buffer;
+
+    /**
+     * @internal
+     */
+    constructor(txn: EmbeddedTransaction, prefix: string) {
+        this.txn = txn;
+        this.prefix = prefix;
+        this.prefixBytes = Buffer.from(prefix);
+    }
+
+    async put(key: BytesLike, value: BytesLike, options?: PutOptions): Promise<void> {
+        return this.txn.put(this.encodeKey(key), value, options);
+    }
+
+    async get(key: BytesLike, options?: NativeReadOptions): Promise<Buffer | null> {
+        return this.txn.get(this.encodeKey(key), options);
+    }
+
+    async delete(key: BytesLike): Promise<void> {
+        return this.txn.delete(this.encodeKey(key));
+    }
+
+    async putPath(path: string, value: BytesLike): Promise<void> {
+        return this.txn.putPath(`${this.prefix}${path}`, value);
+    }
+
+    async getPath(path: string, options?: NativeReadOptions): Promise<Buffer | null> {
+        return this.txn.getPath(`${this.prefix}${path}`, options);
+    }
+
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        // A snapshot scan would read outside this transaction
+        if (options?.snapshot) {
+            throw new DatabaseError('Scoped scans do not support `snapshot`; they read at the transaction\'s snapshot');
+        }
+        for await (const [key, value] of this.txn.scanPrefix(this.encodeKey(prefix), options)) {
+            yield [key.subarray(this.prefixBytes.length), value];
+        }
+    }
+
+    onCommit(callback: TransactionCallback): void {
+        this.txn.onCommit(callback);
+    }
+
+    onRollback(callback: TransactionCallback): void {
+        this.txn.onRollback(callback);
+    }
+
+    async commit(options?: CommitOptions): Promise<void> {
+        return this.txn.commit(options);
+    }
+
+    async abort(): Promise<void> {
+        return this.txn.abort();
+    }
+
+    private encodeKey(key: BytesLike): Buffer {
+        return Buffer.concat([this.prefixBytes, toBuffer(key)]);
+    }
+}
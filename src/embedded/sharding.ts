@@ -0,0 +1,40 @@
+/**
+ * Client-Side Sharding - Embedded Mode
+ *
+ * Exposes the native engine's consistent hash, the same function that
+ * routes keys across partitions, so queues, caches and other external
+ * systems can place work on the shard that owns a key.
+ */
+
+import { DatabaseError } from '../errors';
+import { NativeBindings } from './ffi/bindings';
+import { BytesLike, toBuffer } from './key-encoding';
+
+/**
+ * Shard (0 to `shards - 1`) that owns `key`
+ *
+ * Consistent: growing from N to N+1 shards moves only about 1/(N+1) of
+ * the keys, and every key that moves goes to the new shard.
+ *
+ * @example
+ * ```typescript
+ * const shard = shardFor(`user/${userId}`, 16);
+ * await queues[shard].enqueue({ userId, action: 'reindex' });
+ * ```
+ */
+export function shardFor(key: BytesLike, shards: number): number {
+    if (!Number.isInteger(shards) || shards < 1 || shards > 0xffffffff) {
+        throw new DatabaseError(`Shard count must be a positive 32-bit integer, got ${shards}`);
+    }
+
+    const bindings = NativeBindings.getInstance();
+    if (!bindings.sochdb_shard_for) {
+        throw new DatabaseError(
+            'Consistent hashing is not supported by the loaded SochDB native library. ' +
+            'Please upgrade the native library.'
+        );
+    }
+
+    const buf = toBuffer(key);
+    return bindings.sochdb_shard_for(buf, buf.length, shards);
+}
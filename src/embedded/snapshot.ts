@@ -0,0 +1,108 @@
+/**
+ * Snapshots - Embedded Mode
+ *
+ * A snapshot pins a consistent point-in-time view of the database. Every
+ * read through the same snapshot observes the same state, regardless of
+ * concurrent writes, until it is released.
+ */
+
+import { DatabaseError } from '../errors';
+import type { NativeReadOptions, PathScanOptions, ScanOptions } from './database';
+import type { EmbeddedTransaction } from './transaction';
+import type { BytesLike } from './key-encoding';
+import type { PathEntry, PathOrder, TreeSummary } from './path-tree';
+
+/**
+ * Read-only, point-in-time view of the database
+ *
+ * @example
+ * ```typescript
+ * const snap = db.snapshot();
+ * try {
+ *     const a = await db.get('a', { snapshot: snap });
+ *     const b = await snap.get('b');
+ * } finally {
+ *     snap.release();
+ * }
+ * ```
+ */
+export class Snapshot {
+    private txn: EmbeddedTransaction;
+    private released = false;
+    /**
+     * Called once when the snapshot is released
+     * @internal
+     */
+    onRelease?: () => void;
+
+    constructor(txn: EmbeddedTransaction) {
+        this.txn = txn;
+        txn.handleKind = 'snapshot';
+    }
+
+    /**
+     * LSN the snapshot reads at
+     */
+    get lsn(): bigint {
+        return this.txn.snapshotTs;
+    }
+
+    get isReleased(): boolean {
+        return this.released;
+    }
+
+    async get(key: BytesLike, options?: NativeReadOptions): Promise<Buffer | null> {
+        this.ensureLive();
+        return this.txn.get(key, options);
+    }
+
+    async getPath(path: string, options?: NativeReadOptions): Promise<Buffer | null> {
+        this.ensureLive();
+        return this.txn.getPath(path, options);
+    }
+
+    async *scanPrefix(prefix: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
+        this.ensureLive();
+        yield* this.txn.scanPrefix(prefix, options);
+    }
+
+    async listPath(path: string, order?: PathOrder): Promise<PathEntry[]> {
+        this.ensureLive();
+        return this.txn.listPath(path, order);
+    }
+
+    async *scanPath(path: string, options?: PathScanOptions): AsyncGenerator<[string, Buffer]> {
+        this.ensureLive();
+        yield* this.txn.scanPath(path, options);
+    }
+
+    async treeSummary(path: string, maxDepth?: number): Promise<TreeSummary> {
+        this.ensureLive();
+        return this.txn.treeSummary(path, maxDepth);
+    }
+
+    /**
+     * Release the snapshot so the engine can reclaim the versions it pins
+     */
+    release(): void {
+        if (this.released) return;
+        this.released = true;
+        void this.txn.abort();
+        this.onRelease?.();
+    }
+
+    /**
+     * Underlying read transaction
+     * @internal
+     */
+    getTransaction(): EmbeddedTransaction {
+        this.ensureLive();
+        return this.txn;
+    }
+
+    private ensureLive(): void {
+        if (this.released) {
+            throw new DatabaseError('Snapshot has been released');
+        }
+    }
+}
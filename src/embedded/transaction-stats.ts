@@ -0,0 +1,87 @@
+/**
+ * Transaction Labels - Embedded Mode
+ *
+ * Transactions can be named (`beginTransaction({ label: 'checkout' })`).
+ * Outcomes and durations are aggregated per label so contention and slow
+ * transactions can be attributed to the code path that started them.
+ */
+
+export type TransactionOutcome = 'committed' | 'conflict' | 'failed' | 'aborted';
+
+/** Label used for transactions started without one */
+export const UNLABELED = '(unlabeled)';
+
+export interface TransactionLabelStats {
+    label: string;
+    committed: number;
+    /** Commits rejected because of a conflicting concurrent write */
+    conflicts: number;
+    /** Commits that failed for another reason */
+    failed: number;
+    aborted: number;
+    /** Transactions that ran longer than `slowTransactionMs` */
+    slow: number;
+    totalDurationMs: number;
+    maxDurationMs: number;
+}
+
+/**
+ * Payload of the database's `'transaction:slow'` event
+ */
+export interface SlowTransactionEvent {
+    label: string;
+    /** `'open'` when reported while the transaction is still running */
+    outcome: TransactionOutcome | 'open';
+    durationMs: number;
+    snapshotTs: bigint;
+}
+
+/**
+ * Per-label transaction counters
+ * @internal
+ */
+export class TransactionStatsRegistry {
+    private byLabel = new Map<string, TransactionLabelStats>();
+
+    /**
+     * Record a finished transaction
+     */
+    record(label: string | undefined, outcome: TransactionOutcome, durationMs: number, slow: boolean): void {
+        const key = label ?? UNLABELED;
+        let stats = this.byLabel.get(key);
+        if (!stats) {
+            stats = {
+                label: key,
+                committed: 0,
+                conflicts: 0,
+                failed: 0,
+                aborted: 0,
+                slow: 0,
+                totalDurationMs: 0,
+                maxDurationMs: 0,
+            };
+            this.byLabel.set(key, stats);
+        }
+
+        if (outcome === 'committed') stats.committed++;
+        else if (outcome === 'conflict') stats.conflicts++;
+        else if (outcome === 'failed') stats.failed++;
+        else stats.aborted++;
+        if (slow) stats.slow++;
+        stats.totalDurationMs += durationMs;
+        stats.maxDurationMs = Math.max(stats.maxDurationMs, durationMs);
+    }
+
+    /**
+     * Snapshot of all counters, most conflicting labels first
+     */
+    snapshot(): TransactionLabelStats[] {
+        return [...this.byLabel.values()]
+            .map((stats) => ({ ...stats }))
+            .sort((a, b) => b.conflicts - a.conflicts || b.slow - a.slow || a.label.localeCompare(b.label));
+    }
+
+    reset(): void {
+        this.byLabel.clear();
+    }
+}
@@ -1,8 +1,68 @@
-import { TransactionError, DatabaseError } from '../errors';
+import { TransactionError, DatabaseError, AbortError, CorruptionError, ReadOnlyError, HandleInvalidatedError, AppendOnlyViolationError, TimeoutError, DurabilityTimeoutError } from '../errors';
 import { NativeBindings } from './ffi/bindings';
-import { EmbeddedDatabase } from './database';
+import { EmbeddedDatabase, PutOptions, ScanOptions, NativeReadOptions, PathScanOptions } from './database';
+import { ScanProjection, ProjectedEntry, decodeProjected } from './projection';
+import { BytesLike, toBuffer } from './key-encoding';
+import { IoProfile, fromNativeIoStats } from './io-profile';
+import { COMPRESSION_CODES, Keyspace, TransactionKeyspace } from './keyspace';
+import { PATH_ORDER_CODES, PathEntry, PathOrder, TreeSummary, parsePathEntries, parseTreeSummary } from './path-tree';
+import { channels } from './diagnostics';
+import { checkEntrySize, checkKeySize } from './limits';
+import { checkTtl } from './clock';
+import { validatePath } from './path';
+import { hidesJournal, isJournalKey } from './undo-journal';
 import * as koffi from 'koffi';
 
+/** Returned by native writes on a read-only transaction */
+const READ_ONLY_VIOLATION = -4;
+/** Returned by native reads when compaction removed files the handle had pinned */
+const HANDLE_INVALIDATED = -5;
+/** Returned by native writes that would modify a key under an append-only prefix */
+const APPEND_ONLY_VIOLATION = -6;
+/** Returned by native operations cancelled by the transaction's deadline */
+const TIMED_OUT = -7;
+
+export type InvalidationReason = 'database_closed' | 'compaction';
+
+/**
+ * Payload of the database's `'handle:invalidated'` event
+ */
+export interface HandleInvalidatedEvent {
+    kind: 'transaction' | 'snapshot' | 'iterator';
+    reason: InvalidationReason;
+    /** Snapshot timestamp of the affected transaction */
+    snapshotTs: bigint;
+}
+
+/**
+ * Stage at which a commit is acknowledged
+ *
+ * - `applied` - visible to other transactions; the WAL write is still queued
+ * - `walWritten` - written to the WAL (survives a process crash)
+ * - `fsynced` - fsynced to disk (survives an OS crash or power loss)
+ *
+ * WAL writes and fsyncs are batched across concurrent commits, so waiting
+ * for a later stage adds latency to the caller without costing throughput.
+ */
+export type AckLevel = 'applied' | 'walWritten' | 'fsynced';
+
+const ACK_LEVEL_CODES: Record<Exclude<AckLevel, 'applied'>, number> = {
+    walWritten: 1,
+    fsynced: 2,
+};
+
+export interface CommitOptions {
+    /** Resolve once the commit reaches this stage (default: the database's `syncMode`) */
+    ack?: AckLevel;
+    /** Cancel the commit and reject with a TimeoutError if it takes longer than this */
+    timeoutMs?: number;
+}
+
+/**
+ * Callback registered with `onCommit()` / `onRollback()`
+ */
+export type TransactionCallback = () => void | Promise<void>;
+
 export class EmbeddedTransaction {
     private db: EmbeddedDatabase;
     private dbHandle: any;
@@ -10,6 +70,21 @@ export class EmbeddedTransaction {
     private bindings: NativeBindings;
     private committed = false;
     private aborted = false;
+    private invalidation: InvalidationReason | null = null;
+    /** @internal */
+    handleKind: 'transaction' | 'snapshot' = 'transaction';
+    /** Acknowledgment level used when `commit()` is called without one @internal */
+    defaultAck?: AckLevel;
+    /** Name given with `beginTransaction({ label })` @internal */
+    label?: string;
+    private startedAt = performance.now();
+    /** Already reported as slow while still open @internal */
+    reportedSlow = false;
+    private deadlineMs = 0;
+    /** `performance.now()` at which the deadline passes, 0 = none */
+    private deadlineAt = 0;
+    private commitCallbacks: TransactionCallback[] = [];
+    private rollbackCallbacks: TransactionCallback[] = [];
 
     constructor(db: EmbeddedDatabase, dbHandle: any, txnHandle: any) {
         this.db = db;
@@ -18,26 +93,110 @@ export class EmbeddedTransaction {
         this.bindings = NativeBindings.getInstance();
     }
 
-    async put(key: Buffer, value: Buffer): Promise<void> {
+    /**
+     * Snapshot timestamp (LSN) this transaction reads at
+     */
+    get snapshotTs(): bigint {
+        return BigInt(this.txnHandle.snapshot_ts);
+    }
+
+    async put(keyLike: BytesLike, valueLike: BytesLike, options?: PutOptions): Promise<void> {
         this.ensureActive();
-        const res = this.bindings.sochdb_put(this.dbHandle, this.txnHandle, key, key.length, value, value.length);
-        if (res !== 0) {
-            throw new DatabaseError('Failed to put value');
+        const key = toBuffer(keyLike);
+        const value = toBuffer(valueLike);
+        checkEntrySize(key, value, this.db.sizeLimits);
+        if (options?.ttlMs !== undefined) {
+            checkTtl(options.ttlMs);
+        }
+        let res: number;
+        if (options?.compression !== undefined) {
+            const codec = Object.prototype.hasOwnProperty.call(COMPRESSION_CODES, options.compression)
+                ? COMPRESSION_CODES[options.compression]
+                : undefined;
+            if (codec === undefined) {
+                throw new DatabaseError(`Unsupported compression codec: ${options.compression}`);
+            }
+            if (!this.bindings.sochdb_put_compressed) {
+                throw new DatabaseError('Per-put compression is not supported by the loaded SochDB native library');
+            }
+            // Compressed in the native layer; get() decompresses transparently
+            res = this.bindings.sochdb_put_compressed(
+                this.dbHandle, this.txnHandle, key, key.length, value, value.length, codec
+            );
+        } else {
+            res = this.bindings.sochdb_put(this.dbHandle, this.txnHandle, key, key.length, value, value.length);
+        }
+        this.checkWrite(res, 'Failed to put value', key);
+
+        if (options?.ttlMs !== undefined) {
+            await this.expire(key, options.ttlMs);
         }
     }
 
-    async get(key: Buffer): Promise<Buffer | null> {
+    /**
+     * Expire a key `ttlMs` from now (per the database clock), replacing any earlier deadline
+     */
+    async expire(keyLike: BytesLike, ttlMs: number): Promise<void> {
         this.ensureActive();
+        if (!this.bindings.sochdb_expire) {
+            throw new DatabaseError(
+                'Key expiry is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        const key = toBuffer(keyLike);
+        checkKeySize(key, this.db.sizeLimits);
+        checkTtl(ttlMs);
+        const expiresAt = BigInt(Math.floor(this.db.now() + ttlMs));
+        const res = this.bindings.sochdb_expire(this.dbHandle, this.txnHandle, key, key.length, expiresAt);
+        this.checkWrite(res, 'Failed to set expiry', key);
+    }
+
+    /**
+     * Put a value, letting the native engine resolve conflicts with an existing key
+     *
+     * @param policyCode - 1 = skip, 2 = overwrite, 3 = fail
+     * @returns 0 = inserted, 1 = overwritten, 2 = skipped, 3 = conflict (nothing written)
+     * @internal
+     */
+    async putWithPolicy(key: Buffer, value: Buffer, policyCode: number): Promise<number> {
+        this.ensureActive();
+        if (!this.bindings.sochdb_put_with_policy) {
+            throw new DatabaseError(
+                'Import conflict policies are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        checkEntrySize(key, value, this.db.sizeLimits);
+        const res = this.bindings.sochdb_put_with_policy(this.dbHandle, this.txnHandle, key, key.length, value, value.length, policyCode);
+        if (res < 0) {
+            this.checkWrite(res, 'Failed to put value', key);
+        }
+        return res;
+    }
+
+    async get(keyLike: BytesLike, options?: NativeReadOptions): Promise<Buffer | null> {
+        this.ensureActive();
+        const key = toBuffer(keyLike);
+        checkKeySize(key, this.db.sizeLimits);
 
         const outPtr = [null];
         const outLen = [0];
 
-        // returns 0 on success, 1 on not found, -1 on error
-        const res = this.bindings.sochdb_get(this.dbHandle, this.txnHandle, key, key.length, outPtr, outLen);
+        // returns 0 on success, 1 on not found, -1 on error, -2 on checksum mismatch
+        const nativeOptions = this.nativeReadOptions(options, this.bindings.sochdb_get_opts);
+        const res = nativeOptions
+            ? this.bindings.sochdb_get_opts(this.dbHandle, this.txnHandle, key, key.length, nativeOptions, outPtr, outLen)
+            : this.bindings.sochdb_get(this.dbHandle, this.txnHandle, key, key.length, outPtr, outLen);
 
+        this.checkTimeout(res, 'Get');
+        this.checkInvalidated(res);
         if (res === 1) { // Not found
             return null;
         }
+        if (res === -2) {
+            throw new CorruptionError(`Checksum mismatch reading key '${key.toString()}'`);
+        }
         if (res !== 0) {
             throw new DatabaseError('Failed to get value');
         }
@@ -53,33 +212,49 @@ export class EmbeddedTransaction {
         return buffer;
     }
 
-    async delete(key: Buffer): Promise<void> {
+    async delete(keyLike: BytesLike): Promise<void> {
         this.ensureActive();
+        const key = toBuffer(keyLike);
+        checkKeySize(key, this.db.sizeLimits);
         const res = this.bindings.sochdb_delete(this.dbHandle, this.txnHandle, key, key.length);
-        if (res !== 0) {
-            throw new DatabaseError('Failed to delete value');
-        }
+        this.checkWrite(res, 'Failed to delete value', key);
     }
 
-    async putPath(path: string, value: Buffer): Promise<void> {
+    /**
+     * Put value at path
+     *
+     * The path must pass `validatePath`: paths with a leading "/", an empty
+     * segment or a literal "%" are rejected with InvalidPathError (they were
+     * accepted before 0.5). Build paths with `PathBuilder` to escape segments.
+     */
+    async putPath(path: string, valueLike: BytesLike): Promise<void> {
         this.ensureActive();
+        const value = toBuffer(valueLike);
+        validatePath(path);
+        checkEntrySize(Buffer.from(path), value, this.db.sizeLimits);
         const res = this.bindings.sochdb_put_path(this.dbHandle, this.txnHandle, path, value, value.length);
-        if (res !== 0) {
-            throw new DatabaseError('Failed to put path');
-        }
+        this.checkWrite(res, 'Failed to put path', Buffer.from(path));
     }
 
-    async getPath(path: string): Promise<Buffer | null> {
+    async getPath(path: string, options?: NativeReadOptions): Promise<Buffer | null> {
         this.ensureActive();
 
         const outPtr = [null];
         const outLen = [0];
 
-        const res = this.bindings.sochdb_get_path(this.dbHandle, this.txnHandle, path, outPtr, outLen);
+        const nativeOptions = this.nativeReadOptions(options, this.bindings.sochdb_get_path_opts);
+        const res = nativeOptions
+            ? this.bindings.sochdb_get_path_opts(this.dbHandle, this.txnHandle, path, nativeOptions, outPtr, outLen)
+            : this.bindings.sochdb_get_path(this.dbHandle, this.txnHandle, path, outPtr, outLen);
 
+        this.checkTimeout(res, 'Get path');
+        this.checkInvalidated(res);
         if (res === 1) {
             return null;
         }
+        if (res === -2) {
+            throw new CorruptionError(`Checksum mismatch reading path '${path}'`);
+        }
         if (res !== 0) {
             throw new DatabaseError('Failed to get path');
         }
@@ -92,12 +267,115 @@ export class EmbeddedTransaction {
         return buffer;
     }
 
-    async *scanPrefix(prefix: Buffer): AsyncGenerator<[Buffer, Buffer]> {
+    /**
+     * List the direct children of a path
+     */
+    async listPath(path: string, order: PathOrder = 'bytes'): Promise<PathEntry[]> {
+        this.ensureActive();
+        if (order !== 'bytes') {
+            const json = this.readNativeJson('Ordered path listing', this.bindings.sochdb_list_path_ordered, path, PATH_ORDER_CODES[order]);
+            return parsePathEntries(path, json);
+        }
+        return parsePathEntries(path, this.readNativeJson('Path listing', this.bindings.sochdb_list_path, path));
+    }
+
+    /**
+     * Scan every document at or below a path, in the requested order
+     *
+     * The order is applied by the native engine, so it holds across
+     * separators at any depth (not just among siblings).
+     */
+    async *scanPath(path: string, options?: PathScanOptions): AsyncGenerator<[string, Buffer]> {
+        this.ensureActive();
+        if (!this.bindings.sochdb_scan_path) {
+            throw new DatabaseError(
+                'Path scans are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        AbortError.throwIfAborted(options?.signal);
+
+        const iter = this.bindings.sochdb_scan_path(this.dbHandle, this.txnHandle, path, PATH_ORDER_CODES[options?.order ?? 'bytes']);
+        if (!iter) return;
+
+        const hideJournal = hidesJournal(path);
+        for await (const [key, value] of this.iterate(iter, options?.signal)) {
+            if (hideJournal && isJournalKey(key)) continue;
+            yield [key.toString('utf8'), value];
+        }
+    }
+
+    /**
+     * Count documents, directories and bytes below a path
+     *
+     * @param maxDepth - Levels below `path` to include (0 = unlimited)
+     */
+    async treeSummary(path: string, maxDepth = 0): Promise<TreeSummary> {
+        this.ensureActive();
+        const json = this.readNativeJson('Tree summary', this.bindings.sochdb_tree_summary, path, maxDepth);
+        return parseTreeSummary(path, json);
+    }
+
+    async *scanPrefix(prefixLike: BytesLike, options?: ScanOptions): AsyncGenerator<[Buffer, Buffer]> {
         this.ensureActive();
+        const prefix = toBuffer(prefixLike);
+        AbortError.throwIfAborted(options?.signal);
 
-        const iter = this.bindings.sochdb_scan_prefix(this.dbHandle, this.txnHandle, prefix, prefix.length);
+        const nativeOptions = this.nativeReadOptions(options, this.bindings.sochdb_scan_prefix_opts);
+        const iter = nativeOptions
+            ? this.bindings.sochdb_scan_prefix_opts(this.dbHandle, this.txnHandle, prefix, prefix.length, nativeOptions)
+            : this.bindings.sochdb_scan_prefix(this.dbHandle, this.txnHandle, prefix, prefix.length);
         if (!iter) return;
 
+        // Undo journal entries only show up in scans inside the journal
+        const hideJournal = hidesJournal(prefix);
+        for await (const entry of this.iterate(iter, options?.signal)) {
+            if (hideJournal && isJournalKey(entry[0])) continue;
+            yield entry;
+        }
+    }
+
+    /**
+     * Scan keys with prefix, returning only a derived output per entry
+     *
+     * The projection is computed by the native engine, so only the requested
+     * part of each value crosses the FFI boundary.
+     *
+     * @example
+     * ```typescript
+     * for await (const { key, value } of txn.scanProjected(Buffer.from('docs/'), { jsonField: 'title' })) {
+     *     console.log(key.toString(), value);
+     * }
+     * ```
+     */
+    async *scanProjected(prefixLike: BytesLike, projection: ScanProjection, options?: ScanOptions): AsyncGenerator<ProjectedEntry> {
+        this.ensureActive();
+        const prefix = toBuffer(prefixLike);
+        AbortError.throwIfAborted(options?.signal);
+
+        if (!this.bindings.sochdb_scan_prefix_projected) {
+            throw new DatabaseError(
+                'Projected scans are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+
+        const [mode, arg] = projection === 'keys' ? [1, '']
+            : projection === 'length' ? [2, '']
+            : [3, projection.jsonField];
+        const iter = this.bindings.sochdb_scan_prefix_projected(
+            this.dbHandle, this.txnHandle, prefix, prefix.length, mode, arg
+        );
+        if (!iter) return;
+
+        const hideJournal = hidesJournal(prefix);
+        for await (const [key, raw] of this.iterate(iter, options?.signal)) {
+            if (hideJournal && isJournalKey(key)) continue;
+            yield decodeProjected(key, raw, projection);
+        }
+    }
+
+    private async *iterate(iter: any, signal?: AbortSignal): AsyncGenerator<[Buffer, Buffer]> {
         try {
             const keyPtr = [null];
             const keyLen = [0];
@@ -105,9 +383,19 @@ export class EmbeddedTransaction {
             const valLen = [0];
 
             while (true) {
+                // Checked before every native step so a cancelled scan stops promptly
+                AbortError.throwIfAborted(signal);
+                this.ensureActive();
+
                 // Returns 0 on success, 1 on done, -1 on error
                 const res = this.bindings.sochdb_iterator_next(iter, keyPtr, keyLen, valPtr, valLen);
                 if (res === 1) break; // Done
+                if (res === HANDLE_INVALIDATED) {
+                    this.db.notifyHandleInvalidated({ kind: 'iterator', reason: 'compaction', snapshotTs: this.snapshotTs });
+                    throw new HandleInvalidatedError('Scan iterator was invalidated by compaction', 'compaction');
+                }
+                if (res === TIMED_OUT) throw new TimeoutError(this.deadlineMs, 'Scan');
+                if (res === -2) throw new CorruptionError('Checksum mismatch during scan');
                 if (res !== 0) throw new DatabaseError('Scan failed');
 
                 // Decode key
@@ -133,27 +421,343 @@ export class EmbeddedTransaction {
                 yield [k, v];
             }
         } finally {
-            this.bindings.sochdb_iterator_close(iter);
+            // Closing the database already released every native iterator
+            if (this.invalidation !== 'database_closed') {
+                this.bindings.sochdb_iterator_close(iter);
+            }
+        }
+    }
+
+    /**
+     * Storage I/O performed by this transaction so far
+     */
+    ioProfile(): IoProfile {
+        if (!this.bindings.sochdb_txn_io_stats) {
+            throw new DatabaseError(
+                'I/O profiling is not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
         }
+        return fromNativeIoStats(this.bindings.sochdb_txn_io_stats(this.dbHandle, this.txnHandle));
     }
 
-    async commit(): Promise<void> {
+    /**
+     * Map a native write result to an error
+     */
+    private checkWrite(res: number, message: string, key?: Buffer): void {
+        this.checkTimeout(res, 'Write');
+        if (res === READ_ONLY_VIOLATION) {
+            throw new ReadOnlyError('Cannot write through a read-only transaction');
+        }
+        if (res === APPEND_ONLY_VIOLATION && key) {
+            throw new AppendOnlyViolationError(key);
+        }
+        if (res !== 0) {
+            throw new DatabaseError(message);
+        }
+    }
+
+    private readNativeJson(feature: string, fn: any, ...args: any[]): any {
+        if (!fn) {
+            throw new DatabaseError(
+                `${feature} is not supported by the loaded SochDB native library. ` +
+                'Please upgrade the native library.'
+            );
+        }
+
+        const outPtr = [null];
+        const outLen = [0];
+        const res = fn(this.dbHandle, this.txnHandle, ...args, outPtr, outLen);
+        this.checkTimeout(res, feature);
+        if (res !== 0) {
+            throw new DatabaseError(`${feature} failed (Code ${res})`);
+        }
+
+        const text = Buffer.from(koffi.decode(outPtr[0], 'uint8', outLen[0])).toString('utf8');
+        this.bindings.sochdb_free_bytes(outPtr[0], outLen[0]);
+        return JSON.parse(text);
+    }
+
+    /**
+     * Access a keyspace within this transaction
+     *
+     * Operations on several keyspaces in one transaction are committed atomically.
+     *
+     * @example
+     * ```typescript
+     * await db.withTransaction(async (txn) => {
+     *     await txn.keyspace(blobs).put(Buffer.from('img:1'), data);
+     *     await txn.keyspace(index).put(Buffer.from('by_name:cat'), Buffer.from('img:1'));
+     * });
+     * ```
+     */
+    keyspace(keyspace: Keyspace): TransactionKeyspace {
         this.ensureActive();
+        return new TransactionKeyspace(this, keyspace);
+    }
 
-        const result = this.bindings.sochdb_commit(this.dbHandle, this.txnHandle);
+    /**
+     * Run `callback` after this transaction commits successfully
+     *
+     * Use it for side effects that must not happen for a transaction that
+     * ends up aborted or fails to commit (cache invalidation, publishing
+     * events). Callbacks run in registration order once the commit is
+     * fsynced, whatever `ack` it was made with, so a side effect never
+     * outlives data a crash could still lose; `commit()` resolves after they
+     * ran. If one throws, the rest still run and `commit()` rejects with the
+     * first error, although the transaction itself stays committed.
+     *
+     * @example
+     * ```typescript
+     * await db.withTransaction(async (txn) => {
+     *     await txn.put(`users/${id}`, profile);
+     *     txn.onCommit(() => cache.invalidate(`users/${id}`));
+     * });
+     * ```
+     */
+    onCommit(callback: TransactionCallback): void {
+        this.ensureActive();
+        if (!this.bindings.sochdb_commit_nowait || !this.bindings.sochdb_wait_durable) {
+            throw new DatabaseError(
+                'Commit callbacks are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        this.commitCallbacks.push(callback);
+    }
+
+    /**
+     * Run `callback` once this transaction ends without a durable commit:
+     * it is aborted, fails to commit, is not confirmed durable in time
+     * (the commit may still become visible) or its database is closed
+     *
+     * Callbacks run by `close()` are not awaited; their errors are emitted
+     * as `'error'` on the database.
+     */
+    onRollback(callback: TransactionCallback): void {
+        this.ensureActive();
+        this.rollbackCallbacks.push(callback);
+    }
+
+    /**
+     * Cancel every later native operation on this transaction, including the
+     * commit, once `timeoutMs` has passed; they reject with a TimeoutError.
+     * Pass 0 to clear the deadline.
+     */
+    setDeadline(timeoutMs: number): void {
+        this.ensureActive();
+        if (!this.bindings.sochdb_txn_set_deadline) {
+            throw new DatabaseError(
+                'Operation timeouts are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        if (!Number.isFinite(timeoutMs) || timeoutMs < 0) {
+            throw new DatabaseError(`timeoutMs must be a non-negative number, got ${timeoutMs}`);
+        }
+        this.bindings.sochdb_txn_set_deadline(this.dbHandle, this.txnHandle, Math.ceil(timeoutMs));
+        this.deadlineMs = timeoutMs;
+        this.deadlineAt = timeoutMs > 0 ? performance.now() + timeoutMs : 0;
+    }
+
+    /**
+     * Time since the transaction began
+     * @internal
+     */
+    elapsedMs(): number {
+        return performance.now() - this.startedAt;
+    }
+
+    /**
+     * Time left before the current deadline, 0 when none is set (at least 1 ms
+     * once set, so restoring an expired deadline does not clear it)
+     * @internal
+     */
+    remainingDeadlineMs(): number {
+        return this.deadlineAt > 0 ? Math.max(1, Math.ceil(this.deadlineAt - performance.now())) : 0;
+    }
+
+    /**
+     * Commit the transaction
+     *
+     * @example
+     * ```typescript
+     * // Metrics can be lost in a power failure; resolve as soon as they are visible
+     * await txn.commit({ ack: 'applied' });
+     *
+     * // Don't let a stuck disk hold the request handler
+     * await txn.commit({ ack: 'fsynced', timeoutMs: 2000 });
+     * ```
+     */
+    async commit(options?: CommitOptions): Promise<void> {
+        this.ensureActive();
+        if (options?.timeoutMs !== undefined) {
+            this.setDeadline(options.timeoutMs);
+        }
+
+        const ack = options?.ack ?? this.defaultAck;
+        // onCommit side effects must not outlive data a crash could still lose
+        const durableAt = this.commitCallbacks.length > 0
+            ? 'fsynced'
+            : ack === 'applied' ? undefined : ack;
+        if (ack && (!this.bindings.sochdb_commit_nowait || !this.bindings.sochdb_wait_durable)) {
+            throw new DatabaseError(
+                'Commit acknowledgment levels are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+
+        const result = ack || durableAt
+            ? this.bindings.sochdb_commit_nowait(this.dbHandle, this.txnHandle)
+            : this.bindings.sochdb_commit(this.dbHandle, this.txnHandle);
         this.committed = true;
+        this.db.releaseTransaction(this);
 
-        if (result.error_code !== 0) {
+        const error = result.error_code === TIMED_OUT
+            ? new TimeoutError(this.deadlineMs, 'Commit')
             // -1 indicates error, -2 indicates SSI conflict
-            throw new TransactionError(`Transaction failed to commit (Code ${result.error_code})`);
+            : result.error_code !== 0
+                ? new TransactionError(
+                    `Transaction ${this.label ? `'${this.label}' ` : ''}failed to commit (Code ${result.error_code})`
+                )
+                : undefined;
+        // An applied commit stays visible even if it never becomes durable; onRollback
+        // still runs then, since the caller cannot count on it
+        const durabilityError = !error && durableAt
+            ? await this.waitDurable(BigInt(result.commit_ts), durableAt)
+            : undefined;
+        const durationMs = performance.now() - this.startedAt;
+        this.db.recordTransaction(
+            this,
+            !error ? 'committed' : result.error_code === -2 ? 'conflict' : 'failed',
+            durationMs
+        );
+        if (channels.transactionCommit.hasSubscribers) {
+            channels.transactionCommit.publish({
+                database: this.db.location,
+                label: this.label,
+                snapshotTs: this.snapshotTs,
+                durationMs,
+                error: error ?? durabilityError,
+            });
+        }
+        if (error) {
+            await runCallbacks(this.rollbackCallbacks);
+            throw error;
         }
+        if (durabilityError) {
+            this.commitCallbacks = [];
+            await runCallbacks(this.rollbackCallbacks);
+            throw durabilityError;
+        }
+        await runCallbacks(this.commitCallbacks);
+    }
+
+    /**
+     * Wait on a worker thread until an applied commit reaches `ack`
+     */
+    private waitDurable(
+        commitTs: bigint,
+        ack: Exclude<AckLevel, 'applied'>
+    ): Promise<TransactionError | DurabilityTimeoutError | undefined> {
+        const timeoutMs = this.remainingDeadlineMs();
+        return new Promise((resolve) => {
+            // The commit is already applied and a native wait cannot be cancelled,
+            // so a timeout reports "applied, not yet durable"; the late result is dropped
+            const timer = timeoutMs > 0
+                ? setTimeout(() => resolve(new DurabilityTimeoutError(commitTs, ack, this.deadlineMs)), timeoutMs)
+                : undefined;
+            this.bindings.sochdb_wait_durable.async(
+                this.dbHandle,
+                commitTs,
+                ACK_LEVEL_CODES[ack],
+                (err: any, res: number) => {
+                    clearTimeout(timer);
+                    resolve(err || res !== 0
+                        ? new TransactionError(`Commit ${commitTs} was applied but did not reach '${ack}' (Code ${err ? -1 : res})`)
+                        : undefined);
+                }
+            );
+        });
     }
 
     async abort(): Promise<void> {
-        if (!this.isActive()) return;
+        // Closing the database already released the native transaction and ran
+        // the rollback callbacks; one invalidated by compaction still holds it
+        // and is aborted normally
+        if (!this.isActive() || this.invalidation === 'database_closed') return;
 
         this.bindings.sochdb_abort(this.dbHandle, this.txnHandle);
         this.aborted = true;
+        this.db.releaseTransaction(this);
+        const durationMs = performance.now() - this.startedAt;
+        this.db.recordTransaction(this, 'aborted', durationMs);
+        if (channels.transactionAbort.hasSubscribers) {
+            channels.transactionAbort.publish({
+                database: this.db.location,
+                label: this.label,
+                snapshotTs: this.snapshotTs,
+                durationMs,
+            });
+        }
+        await runCallbacks(this.rollbackCallbacks);
+    }
+
+    /**
+     * Mark the transaction unusable; later calls throw HandleInvalidatedError.
+     * After compaction, `abort()` still releases the native handle and runs
+     * the rollback callbacks.
+     * @internal
+     */
+    invalidate(reason: InvalidationReason): void {
+        if (!this.isActive() || this.invalidation === reason || this.invalidation === 'database_closed') return;
+        this.invalidation = reason;
+        // Stay tracked after compaction so close() can still mark the handle closed
+        if (reason === 'database_closed') {
+            this.db.releaseTransaction(this);
+        }
+        this.db.notifyHandleInvalidated({ kind: this.handleKind, reason, snapshotTs: this.snapshotTs });
+        if (reason === 'database_closed') {
+            // abort() returns early from here on, so the transaction ends now
+            this.commitCallbacks = [];
+            runCallbacks(this.rollbackCallbacks).catch((error) =>
+                this.db.reportBackgroundError(error, 'Rollback callback')
+            );
+        }
+    }
+
+    private checkTimeout(res: number, operation: string): void {
+        if (res === TIMED_OUT) {
+            throw new TimeoutError(this.deadlineMs, operation);
+        }
+    }
+
+    private checkInvalidated(res: number): void {
+        if (res === HANDLE_INVALIDATED) {
+            this.invalidate('compaction');
+            this.ensureActive();
+        }
+    }
+
+    /**
+     * Build the native ReadOptions struct, or null when no native-only option is set
+     */
+    private nativeReadOptions(options: NativeReadOptions | undefined, fn: any): Record<string, boolean> | null {
+        if (options?.fillCache === undefined && options?.verifyChecksums === undefined) {
+            return null;
+        }
+        if (!fn) {
+            throw new DatabaseError(
+                'fillCache/verifyChecksums read options are not supported by the loaded SochDB native library. ' +
+                'Please upgrade the native library.'
+            );
+        }
+        return {
+            fill_cache: options.fillCache ?? true,
+            fill_cache_set: options.fillCache !== undefined,
+            verify_checksums: options.verifyChecksums ?? false,
+            verify_checksums_set: options.verifyChecksums !== undefined,
+        };
     }
 
     private isActive(): boolean {
@@ -161,8 +765,33 @@ export class EmbeddedTransaction {
     }
 
     private ensureActive(): void {
+        if (this.invalidation) {
+            throw new HandleInvalidatedError(
+                this.invalidation === 'database_closed'
+                    ? `This ${this.handleKind} was invalidated because the database was closed`
+                    : `This ${this.handleKind} was invalidated because compaction removed data it pinned`,
+                this.invalidation
+            );
+        }
         if (!this.isActive()) {
             throw new TransactionError('Transaction is no longer active');
         }
     }
 }
+
+/**
+ * Run every callback in order, then rethrow the first error (if any)
+ */
+async function runCallbacks(callbacks: TransactionCallback[]): Promise<void> {
+    let failure: { error: unknown } | undefined;
+    for (const callback of callbacks.splice(0)) {
+        try {
+            await callback();
+        } catch (error) {
+            if (!failure) failure = { error };
+        }
+    }
+    if (failure) {
+        throw failure.error;
+    }
+}
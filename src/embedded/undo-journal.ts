@@ -0,0 +1,306 @@
+/**
+ * Admin Undo Journal - Embedded Mode
+ *
+ * When enabled, destructive admin operations (prefix/range/tree deletes and
+ * imports) record the operation and the prior state of every key they
+ * touch, in the same transaction as the change, so a transaction that
+ * aborts leaves nothing behind. `undoLastAdminOp()` restores that state.
+ *
+ * Journal keys are hidden from scans and prefix deletes unless the scanned
+ * prefix is itself inside the journal.
+ *
+ * Layout (op ids are a persisted, zero-padded sequence, so key order is
+ * creation order):
+ * - `_admin/undo/seq` - last op id allocated
+ * - `_admin/undo/ops/<opId>` - JSON `{ op, details, createdAt }`
+ * - `_admin/undo/e/<opId>/<hex key>` - JSON `{ value, after }`: the key's
+ *   prior and post-operation values, base64 or null when absent
+ */
+
+import { DatabaseError } from '../errors';
+import type { EmbeddedDatabase } from './database';
+import type { EmbeddedTransaction } from './transaction';
+
+export interface UndoJournalConfig {
+    /** How long an operation stays undoable (default: 24 hours) */
+    retentionMs?: number;
+}
+
+export interface UndoOptions {
+    /** Restore keys changed since the operation ran instead of refusing (default: false) */
+    force?: boolean;
+    /** Keys restored per transaction (default: 1000) */
+    batchSize?: number;
+}
+
+export interface UndoResult {
+    /** The operation that was undone */
+    op: string;
+    details: Record<string, unknown>;
+    createdAt: number;
+    /** Keys restored to their prior state */
+    restored: number;
+    /** Restored keys that had changed since the operation ran (only with `force`) */
+    diverged: number;
+}
+
+/**
+ * An admin operation being journaled
+ * @internal
+ */
+export interface JournaledOp {
+    opId: string;
+    op: string;
+    details: Record<string, unknown>;
+    createdAt: number;
+}
+
+export const JOURNAL_ROOT = '_admin/undo/';
+const JOURNAL_ROOT_BYTES = Buffer.from(JOURNAL_ROOT);
+const SEQ_KEY = `${JOURNAL_ROOT}seq`;
+const OPS_PREFIX = `${JOURNAL_ROOT}ops/`;
+const ENTRIES_PREFIX = `${JOURNAL_ROOT}e/`;
+const DEFAULT_RETENTION_MS = 24 * 60 * 60 * 1000;
+const DEFAULT_BATCH_SIZE = 1000;
+/** Diverged keys named in the error that refuses an undo */
+const DIVERGED_SAMPLE = 5;
+
+interface OpMeta {
+    op: string;
+    details: Record<string, unknown>;
+    createdAt: number;
+}
+
+interface JournalEntry {
+    value: string | null;
+    /** Missing in entries written before post-images were journaled */
+    after?: string | null;
+}
+
+function encodeValue(value: Buffer | null): string | null {
+    return value === null ? null : value.toString('base64');
+}
+
+function parseJson<T>(key: Buffer, value: Buffer): T {
+    try {
+        return JSON.parse(value.toString());
+    } catch {
+        throw new DatabaseError(`Corrupt undo journal entry: ${key.toString()}`);
+    }
+}
+
+/**
+ * Whether a scan of `prefix` should skip journal keys
+ * @internal
+ */
+export function hidesJournal(prefix: Buffer | string): boolean {
+    return typeof prefix === 'string'
+        ? !prefix.startsWith(JOURNAL_ROOT)
+        : !prefix.subarray(0, JOURNAL_ROOT_BYTES.length).equals(JOURNAL_ROOT_BYTES);
+}
+
+/**
+ * @internal
+ */
+export function isJournalKey(key: Buffer | string): boolean {
+    return typeof key === 'string'
+        ? key.startsWith(JOURNAL_ROOT)
+        : key.subarray(0, JOURNAL_ROOT_BYTES.length).equals(JOURNAL_ROOT_BYTES);
+}
+
+/**
+ * @internal
+ */
+export class UndoJournal {
+    private retentionMs: number;
+
+    constructor(private db: EmbeddedDatabase, config: UndoJournalConfig = {}) {
+        this.retentionMs = config.retentionMs ?? DEFAULT_RETENTION_MS;
+    }
+
+    /**
+     * Allocate an id for a new operation and prune expired ones. Nothing else
+     * is written until the operation records itself with `recordOp()`.
+     */
+    async begin(op: string, details: Record<string, unknown>): Promise<JournaledOp> {
+        await this.prune();
+
+        // A persisted sequence keeps ids ordered across restarts and clock changes
+        const opId = await this.db.withTransaction(async (txn) => {
+            const last = await txn.get(SEQ_KEY);
+            const next = (last === null ? 0 : Number(last.toString())) + 1;
+            await txn.put(SEQ_KEY, String(next));
+            return next.toString().padStart(16, '0');
+        });
+        return { opId, op, details, createdAt: this.db.now() };
+    }
+
+    /**
+     * Write the operation's meta record in the transaction that performs it.
+     * Operations spanning several transactions call this in each one.
+     */
+    async recordOp(txn: EmbeddedTransaction, entry: JournaledOp): Promise<void> {
+        const metaKey = `${OPS_PREFIX}${entry.opId}`;
+        if ((await txn.get(metaKey)) !== null) return;
+        const meta: OpMeta = { op: entry.op, details: entry.details, createdAt: entry.createdAt };
+        await txn.put(metaKey, JSON.stringify(meta));
+    }
+
+    /**
+     * Run `write` on `key` and journal the key's value before and after it.
+     * A key written more than once keeps its first prior value.
+     */
+    async recordWrite(txn: EmbeddedTransaction, entry: JournaledOp, key: Buffer, write: () => Promise<void>): Promise<void> {
+        const entryKey = `${ENTRIES_PREFIX}${entry.opId}/${key.toString('hex')}`;
+        const journaled = await txn.get(entryKey);
+        const value = journaled !== null
+            ? parseJson<JournalEntry>(Buffer.from(entryKey), journaled).value
+            : encodeValue(await txn.get(key));
+
+        await write();
+
+        const record: JournalEntry = { value, after: encodeValue(await txn.get(key)) };
+        await txn.put(entryKey, JSON.stringify(record));
+    }
+
+    /**
+     * Restore the most recent journaled operation still inside the retention window
+     *
+     * Refuses when a key the operation wrote has changed since, unless
+     * `force` is set. Keys are restored in batches; if one fails, the
+     * entries already restored are gone from the journal and calling this
+     * again resumes with the rest.
+     */
+    async undoLast(options?: UndoOptions): Promise<UndoResult | null> {
+        const batchSize = options?.batchSize ?? DEFAULT_BATCH_SIZE;
+        if (!Number.isInteger(batchSize) || batchSize <= 0) {
+            throw new DatabaseError(`batchSize must be a positive integer, got ${batchSize}`);
+        }
+
+        const last = await this.lastOp();
+        if (!last || last.meta.createdAt < this.db.now() - this.retentionMs) return null;
+        const opPrefix = `${ENTRIES_PREFIX}${last.opId}/`;
+
+        const diverged = await this.divergedKeys(opPrefix);
+        if (diverged.length > 0 && !options?.force) {
+            const sample = diverged.slice(0, DIVERGED_SAMPLE).map((key) => key.toString()).join(', ');
+            throw new DatabaseError(
+                `Cannot undo ${last.meta.op}: ${diverged.length} key(s) changed since it ran (${sample}). ` +
+                'Pass { force: true } to restore them anyway.'
+            );
+        }
+
+        let restored = 0;
+        for (;;) {
+            const count = await this.db.withTransaction(async (txn) => {
+                const entries: Array<[Buffer, Buffer]> = [];
+                for await (const entry of txn.scanPrefix(opPrefix)) {
+                    entries.push(entry);
+                    if (entries.length >= batchSize) break;
+                }
+                for (const [entryKey, entryValue] of entries) {
+                    const key = Buffer.from(entryKey.subarray(opPrefix.length).toString(), 'hex');
+                    const { value } = parseJson<JournalEntry>(entryKey, entryValue);
+                    if (value === null) {
+                        await txn.delete(key);
+                    } else {
+                        await txn.put(key, Buffer.from(value, 'base64'));
+                    }
+                    await txn.delete(entryKey);
+                }
+                if (entries.length === 0) {
+                    await txn.delete(`${OPS_PREFIX}${last.opId}`);
+                }
+                return entries.length;
+            });
+            if (count === 0) break;
+            restored += count;
+        }
+
+        return { ...last.meta, restored, diverged: diverged.length };
+    }
+
+    /**
+     * Keys whose current value differs from the operation's post-image
+     */
+    private async divergedKeys(opPrefix: string): Promise<Buffer[]> {
+        const diverged: Buffer[] = [];
+        const txn = this.db.transaction();
+        try {
+            for await (const [entryKey, entryValue] of txn.scanPrefix(opPrefix)) {
+                const { after } = parseJson<JournalEntry>(entryKey, entryValue);
+                if (after === undefined) continue;
+                const key = Buffer.from(entryKey.subarray(opPrefix.length).toString(), 'hex');
+                if (encodeValue(await txn.get(key)) !== after) {
+                    diverged.push(key);
+                }
+            }
+        } finally {
+            await txn.abort();
+        }
+        return diverged;
+    }
+
+    /**
+     * Drop journaled operations older than the retention window
+     *
+     * Op ids are ordered by creation, so this walks the oldest ops and their
+     * entries only, stopping at the first op still inside the window.
+     */
+    async prune(): Promise<void> {
+        const cutoff = this.db.now() - this.retentionMs;
+        const expired: Buffer[] = [];
+        let batch: Buffer[] = [];
+        const flush = async () => {
+            const keys = batch;
+            batch = [];
+            await this.db.withTransaction(async (txn) => {
+                for (const key of keys) {
+                    await txn.delete(key);
+                }
+            });
+        };
+
+        // The scans run in their own transaction, so deleted batches don't shift them
+        const scan = this.db.transaction();
+        try {
+            let firstLive: string | null = null;
+            for await (const [key, value] of scan.scanPrefix(OPS_PREFIX)) {
+                if (parseJson<OpMeta>(key, value).createdAt >= cutoff) {
+                    firstLive = key.subarray(OPS_PREFIX.length).toString();
+                    break;
+                }
+                expired.push(key);
+            }
+            if (expired.length === 0) return;
+
+            // Entries of every op before the first live one
+            const bound = firstLive === null ? null : Buffer.from(`${ENTRIES_PREFIX}${firstLive}/`);
+            for await (const [key] of scan.scanPrefix(ENTRIES_PREFIX)) {
+                if (bound && Buffer.compare(key, bound) >= 0) break;
+                batch.push(key);
+                if (batch.length >= DEFAULT_BATCH_SIZE) await flush();
+            }
+        } finally {
+            await scan.abort();
+        }
+        batch.push(...expired);
+        await flush();
+    }
+
+    /**
+     * The op with the highest id
+     */
+    private async lastOp(): Promise<{ opId: string; meta: OpMeta } | null> {
+        let last: { opId: string; meta: OpMeta } | null = null;
+        const txn = this.db.transaction();
+        try {
+            for await (const [key, value] of txn.scanPrefix(OPS_PREFIX)) {
+                last = { opId: key.subarray(OPS_PREFIX.length).toString(), meta: parseJson<OpMeta>(key, value) };
+            }
+        } finally {
+            await txn.abort();
+        }
+        return last;
+    }
+}
@@ -29,6 +29,15 @@ export enum ErrorCode {
   // Internal errors (9xxx)
   INTERNAL_ERROR = 9001,
   STORAGE_ERROR = 9003,
+  OPERATION_ABORTED = 9004,
+  DATA_CORRUPTION = 9005,
+  READ_ONLY = 9006,
+  HANDLE_INVALIDATED = 9007,
+  APPEND_ONLY_VIOLATION = 9008,
+  KEY_TOO_LARGE = 9009,
+  VALUE_TOO_LARGE = 9010,
+  INVALID_PATH = 9011,
+  OPERATION_TIMEOUT = 9012,
   
   // Lock/Concurrency errors (10xxx) - v0.4.1
   DATABASE_LOCKED = 10001,
@@ -98,6 +107,200 @@ export class DatabaseError extends SochDBError {
   }
 }
 
+/**
+ * Error thrown when a read with checksum verification detects corrupted data.
+ */
+export class CorruptionError extends SochDBError {
+  constructor(message: string) {
+    super(message, ErrorCode.DATA_CORRUPTION, 'Restore the affected data from a backup or checkpoint');
+    this.name = 'CorruptionError';
+    Object.setPrototypeOf(this, CorruptionError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a write is attempted through a read-only transaction or view.
+ */
+export class ReadOnlyError extends SochDBError {
+  constructor(message: string) {
+    super(message, ErrorCode.READ_ONLY, 'Use a writable transaction or scope for this operation');
+    this.name = 'ReadOnlyError';
+    Object.setPrototypeOf(this, ReadOnlyError.prototype);
+  }
+}
+
+/**
+ * Error thrown when an import hits an existing key under the 'fail' conflict policy.
+ */
+export class ImportConflictError extends SochDBError {
+  public readonly key: Buffer;
+
+  constructor(key: Buffer) {
+    super(`Import conflict: key '${key.toString()}' already exists`, ErrorCode.STORAGE_ERROR);
+    this.name = 'ImportConflictError';
+    this.key = key;
+    Object.setPrototypeOf(this, ImportConflictError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a write would update or delete an existing key under an append-only prefix.
+ */
+export class AppendOnlyViolationError extends SochDBError {
+  public readonly key: Buffer;
+
+  constructor(key: Buffer) {
+    super(
+      `Key '${key.toString()}' is under an append-only prefix and cannot be updated or deleted`,
+      ErrorCode.APPEND_ONLY_VIOLATION,
+      'Write a new key instead of modifying an existing one'
+    );
+    this.name = 'AppendOnlyViolationError';
+    this.key = key;
+    Object.setPrototypeOf(this, AppendOnlyViolationError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a key exceeds the database's `maxKeyBytes` limit.
+ */
+export class KeyTooLargeError extends SochDBError {
+  public readonly size: number;
+  public readonly limit: number;
+
+  constructor(size: number, limit: number) {
+    super(
+      `Key is ${size} bytes, which exceeds the ${limit}-byte limit`,
+      ErrorCode.KEY_TOO_LARGE,
+      'Use a shorter key, e.g. a hash of the natural key, or raise maxKeyBytes'
+    );
+    this.name = 'KeyTooLargeError';
+    this.size = size;
+    this.limit = limit;
+    Object.setPrototypeOf(this, KeyTooLargeError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a value exceeds the database's `maxValueBytes` limit.
+ */
+export class ValueTooLargeError extends SochDBError {
+  public readonly key: Buffer;
+  public readonly size: number;
+  public readonly limit: number;
+
+  constructor(key: Buffer, size: number, limit: number) {
+    super(
+      `Value for key '${key.toString()}' is ${size} bytes, which exceeds the ${limit}-byte limit`,
+      ErrorCode.VALUE_TOO_LARGE,
+      'Split the value across several keys or raise maxValueBytes'
+    );
+    this.name = 'ValueTooLargeError';
+    this.key = key;
+    this.size = size;
+    this.limit = limit;
+    Object.setPrototypeOf(this, ValueTooLargeError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a path is ambiguous or malformed (empty or unescaped segments).
+ */
+export class InvalidPathError extends SochDBError {
+  public readonly path: string;
+
+  constructor(path: string, reason: string) {
+    super(
+      `Invalid path '${path}': ${reason}`,
+      ErrorCode.INVALID_PATH,
+      'Build paths with PathBuilder so segments containing "/" or "%" are escaped'
+    );
+    this.name = 'InvalidPathError';
+    this.path = path;
+    Object.setPrototypeOf(this, InvalidPathError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a transaction, snapshot or iterator is used after the
+ * engine invalidated it (database closed, pinned files compacted away).
+ */
+export class HandleInvalidatedError extends SochDBError {
+  public readonly reason: 'database_closed' | 'compaction';
+
+  constructor(message: string, reason: 'database_closed' | 'compaction') {
+    super(message, ErrorCode.HANDLE_INVALIDATED, 'Re-create the handle and retry the operation');
+    this.name = 'HandleInvalidatedError';
+    this.reason = reason;
+    Object.setPrototypeOf(this, HandleInvalidatedError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a native operation is cancelled because it ran past its `timeoutMs` deadline.
+ */
+export class TimeoutError extends SochDBError {
+  public readonly timeoutMs: number;
+
+  constructor(timeoutMs: number, operation: string = 'Operation') {
+    super(
+      `${operation} exceeded its ${timeoutMs}ms deadline`,
+      ErrorCode.OPERATION_TIMEOUT,
+      'Retry the operation; if timeouts persist, check disk health and load'
+    );
+    this.name = 'TimeoutError';
+    this.timeoutMs = timeoutMs;
+    Object.setPrototypeOf(this, TimeoutError.prototype);
+  }
+}
+
+/**
+ * Error thrown when a commit was applied but did not reach the requested
+ * acknowledgment level before its `timeoutMs` deadline. The write is visible
+ * and may still become durable, so it must not be retried as a failed commit.
+ */
+export class DurabilityTimeoutError extends SochDBError {
+  public readonly commitTs: bigint;
+  public readonly ack: string;
+  public readonly timeoutMs: number;
+
+  constructor(commitTs: bigint, ack: string, timeoutMs: number) {
+    super(
+      `Commit ${commitTs} was applied but did not reach '${ack}' within ${timeoutMs}ms`,
+      ErrorCode.OPERATION_TIMEOUT,
+      'The write is already visible; check disk health rather than retrying it'
+    );
+    this.name = 'DurabilityTimeoutError';
+    this.commitTs = commitTs;
+    this.ack = ack;
+    this.timeoutMs = timeoutMs;
+    Object.setPrototypeOf(this, DurabilityTimeoutError.prototype);
+  }
+}
+
+/**
+ * Error thrown when an operation is cancelled through its AbortSignal.
+ */
+export class AbortError extends SochDBError {
+  public readonly reason?: unknown;
+
+  constructor(message: string = 'Operation was aborted', reason?: unknown) {
+    super(message, ErrorCode.OPERATION_ABORTED);
+    this.name = 'AbortError';
+    this.reason = reason;
+    Object.setPrototypeOf(this, AbortError.prototype);
+  }
+
+  /**
+   * Throw if the given signal has been aborted.
+   */
+  static throwIfAborted(signal?: AbortSignal): void {
+    if (signal?.aborted) {
+      throw new AbortError('Operation was aborted', signal.reason);
+    }
+  }
+}
+
 // ============================================================================
 // Lock/Concurrency Errors (v0.4.1)
 // ============================================================================
@@ -54,8 +54,74 @@
 export const VERSION = '0.5.1';
 
 // Embedded mode (FFI) - NEW
-export { EmbeddedDatabase, EmbeddedDatabaseConfig } from './embedded';
-export { EmbeddedTransaction } from './embedded';
+export {
+  EmbeddedDatabase,
+  EmbeddedDatabaseConfig,
+  PutOptions,
+  ScanOptions,
+  ReadOptions,
+  NativeReadOptions,
+  CheckpointToOptions,
+  CheckpointInfo,
+  DeleteOptions,
+  DeleteResult,
+  PathListOptions,
+  TransactionOptions,
+  SnapshotOptions,
+  WriteOptions,
+  PathScanOptions,
+} from './embedded';
+export { EmbeddedTransaction, TransactionCallback, AckLevel, CommitOptions } from './embedded';
+export { Snapshot } from './embedded';
+export { Keyspace, KeyspaceOptions, TransactionKeyspace, CompressionCodec, CachePriority } from './embedded';
+export { WriteBatch } from './embedded';
+export { KeyPrefix, KeyPart, KeyConstructors } from './embedded';
+export { ScanProjection, ProjectedEntry } from './embedded';
+export { ExportOptions, ExportResult, ExportHeader, parseExportHeader } from './embedded';
+export { ImportOptions, ImportResult, ConflictPolicy, MergeCallback } from './embedded';
+export {
+  encodeU64Key,
+  decodeU64Key,
+  encodeI64Key,
+  decodeI64Key,
+  encodeF64Key,
+  decodeF64Key,
+  encodeTupleKey,
+  decodeTupleKey,
+  TupleElement,
+  BytesLike,
+} from './embedded';
+export { EncodedView, ValueEncoding } from './embedded';
+export { DatabaseMetrics, OperationMetrics, StallInfo, StallReason } from './embedded';
+export { UndoJournalConfig, UndoOptions, UndoResult } from './embedded';
+export { BulkLoader, BulkLoadResult } from './embedded';
+export { IoProfile, Profiled, MaybeProfiled } from './embedded';
+export { BackgroundEvent } from './embedded';
+export { Clock, ManualClock, offsetClock, systemClock, jitterTtl } from './embedded';
+export { PathEntry, TreeSummary, PathOrder } from './embedded';
+export { FormatMigrationOptions, FormatMigrationProgress, FormatMigrationResult } from './embedded';
+export { ScopedDatabase, ScopedTransaction, ScopeOptions } from './embedded';
+export { ReaderHandle } from './embedded';
+export { CompareOptions, CompareResult, CompareMismatch, isSampled } from './embedded';
+export { TransactionLabelStats, TransactionOutcome, SlowTransactionEvent } from './embedded';
+export { shardFor } from './embedded';
+export { engineInfo, EngineInfo } from './embedded';
+export { runtimeInfo, detectRuntime, RuntimeInfo, RuntimeName } from './embedded';
+export { FixtureOptions, FixtureResult } from './embedded';
+export { AlertCallback, AlertConfig, AlertEvent, AlertMetric, AlertSample, AlertThresholds } from './embedded';
+export { nativeMemoryUsage, NativeMemoryUsage } from './embedded';
+export { HandleInvalidatedEvent, InvalidationReason } from './embedded';
+export { QueryMessage, TransactionMessage, DatabaseLifecycleMessage } from './embedded';
+export { parseDuration, RetentionPolicy, RetentionStats } from './embedded';
+export { SizeLimits, DEFAULT_MAX_KEY_BYTES, DEFAULT_MAX_VALUE_BYTES } from './embedded';
+export { PathBuilder, PathSegment, escapeSegment, unescapeSegment, unescapeSegmentBytes, validatePath } from './embedded';
+export {
+  verifyExportManifest,
+  DatasetExportOptions,
+  ExportManifest,
+  ManifestFile,
+  ManifestVerification,
+} from './embedded';
 export { HnswIndex, HnswConfig, HnswBindings } from './embedded';
 export { SearchResult as HnswSearchResult } from './embedded';
 
@@ -69,7 +135,10 @@ import { EmbeddedDatabase, EmbeddedDatabaseConfig } from './embedded';
  * Open a database in concurrent mode (multi-process safe).
  * Alias for EmbeddedDatabase.openConcurrent()
  */
-export function openConcurrent(path: string, options?: { fallbackToStandard?: boolean }): EmbeddedDatabase {
+export function openConcurrent(
+  path: string,
+  options?: EmbeddedDatabaseConfig & { fallbackToStandard?: boolean }
+): EmbeddedDatabase {
   return EmbeddedDatabase.openConcurrent(path, options);
 }
 
@@ -81,6 +150,24 @@ export function open(path: string, config?: EmbeddedDatabaseConfig): EmbeddedDat
   return EmbeddedDatabase.open(path, config);
 }
 
+// Portable fallback for platforms without a native library
+import { PortableDatabase, PortableOptions, selectBackend } from './portable';
+export { PortableDatabase, PortableOptions, Backend, selectBackend } from './portable';
+
+/**
+ * Open a database with the selected backend: the embedded engine when a
+ * native library is available, otherwise a PortableDatabase served by a
+ * SochDB server. An invalid `SOCHDB_BACKEND` is reported here.
+ */
+export function openDatabase(
+  path: string,
+  options?: EmbeddedDatabaseConfig & PortableOptions
+): EmbeddedDatabase | PortableDatabase {
+  return selectBackend() === 'native'
+    ? EmbeddedDatabase.open(path, options)
+    : PortableDatabase.connect(path, options);
+}
+
 // Namespace API (v0.4.1)
 export {
   Namespace,
@@ -119,8 +206,19 @@ export type {
   CacheEntry,
   CacheHit,
   CacheStats,
+  SemanticCacheOptions,
 } from './semantic-cache';
 
+// Read-Through Cache
+export {
+  ReadThroughCache,
+} from './read-through-cache';
+export type {
+  CachePolicy,
+  ReadThroughCacheOptions,
+  ReadThroughCacheStats,
+} from './read-through-cache';
+
 // Context Builder (v0.4.1)
 export {
   ContextQueryBuilder,
@@ -222,6 +320,17 @@ export {
   TransactionError,
   ProtocolError,
   DatabaseError,
+  AbortError,
+  ImportConflictError,
+  CorruptionError,
+  ReadOnlyError,
+  HandleInvalidatedError,
+  AppendOnlyViolationError,
+  KeyTooLargeError,
+  ValueTooLargeError,
+  InvalidPathError,
+  TimeoutError,
+  DurabilityTimeoutError,
   // Lock errors (v0.4.1)
   ErrorCode,
   LockError,
@@ -16,6 +16,7 @@
  */
 
 import { SochDBError, DatabaseError } from './errors';
+import type { Snapshot } from './embedded/snapshot';
 
 // ============================================================================
 // Native HNSW FFI Bindings (for high-performance batch insert and search)
@@ -146,6 +147,12 @@ export interface SearchRequest {
   k: number;
   filter?: Record<string, any>;
   includeMetadata?: boolean;
+  /**
+   * Search the collection as of this snapshot (see `db.snapshot({ lsn })`)
+   * instead of the live index. Several searches through the same snapshot
+   * see the same vectors, whatever is written in between.
+   */
+  snapshot?: Snapshot;
 }
 
 export interface SearchResult {
@@ -476,6 +483,10 @@ export class Collection {
    * Uses NATIVE HNSW search (O(log N)) when available, falls back to JS brute-force
    */
   async search(request: SearchRequest): Promise<SearchResult[]> {
+    if (request.snapshot) {
+      return this.searchSnapshot(request, request.snapshot);
+    }
+
     const k = request.k;
     const queryVector = request.queryVector;
     
@@ -555,6 +566,33 @@ export class Collection {
     }));
   }
 
+  /**
+   * Brute-force search over the vectors visible in a snapshot
+   *
+   * The live index tracks the latest state, so it cannot answer for an
+   * older one; the snapshot's vectors are scanned into a throwaway index.
+   */
+  private async searchSnapshot(request: SearchRequest, snapshot: Snapshot): Promise<SearchResult[]> {
+    const prefix = this.vectorKeyPrefix();
+    const index = new VectorIndex(
+      this.config.dimension || 384,
+      this.config.metric || DistanceMetric.Cosine
+    );
+
+    for await (const [keyBuffer, valueBuffer] of snapshot.scanPrefix(Buffer.from(prefix))) {
+      const id = keyBuffer.toString().replace(prefix, '');
+      const data = JSON.parse(valueBuffer.toString());
+      index.add(id, data.vector, data.metadata);
+    }
+
+    return index.search(request.queryVector, request.k, request.filter).map(r => ({
+      id: r.id,
+      score: r.score,
+      vector: request.includeMetadata ? r.vector : undefined,
+      metadata: request.includeMetadata ? r.metadata : undefined,
+    }));
+  }
+
   /**
    * Get a vector by ID
    */
@@ -0,0 +1,131 @@
+/**
+ * SochDB Portable Backend
+ *
+ * Fallback for platforms without a prebuilt native library (e.g. Alpine on
+ * ARM). Instead of loading the storage engine in-process, key/value and path
+ * operations are forwarded to a SochDB server over gRPC, so the SDK stays
+ * usable with nothing but JavaScript dependencies.
+ *
+ * `openDatabase()` chooses the backend each time it is called:
+ * - `SOCHDB_BACKEND=native|portable` forces a backend
+ * - otherwise `native` when a native library is found, `portable` if not
+ *
+ * @packageDocumentation
+ */
+
+// Copyright 2025 Sushanth (https://github.com/sushanthpy)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+
+import { DatabaseError } from './errors';
+import { SochDBClient } from './grpc-client';
+import { isNativeLibraryAvailable } from './embedded/ffi/library-finder';
+import { validatePath } from './embedded/path';
+
+export type Backend = 'native' | 'portable';
+
+export interface PortableOptions {
+  /** Server to forward operations to (default: `$SOCHDB_SERVER_ADDRESS` or `localhost:50051`) */
+  serverAddress?: string;
+  /** Use TLS for the server connection */
+  secure?: boolean;
+  /** Server-side namespace holding this database's keys (default: the database path) */
+  namespace?: string;
+}
+
+/**
+ * Pick the backend for this process
+ */
+export function selectBackend(env: NodeJS.ProcessEnv = process.env): Backend {
+  const forced = env.SOCHDB_BACKEND;
+  if (forced === 'native' || forced === 'portable') {
+    return forced;
+  }
+  if (forced) {
+    throw new DatabaseError(`Invalid SOCHDB_BACKEND '${forced}' (expected 'native' or 'portable')`);
+  }
+  return isNativeLibraryAvailable() ? 'native' : 'portable';
+}
+
+/**
+ * Key/value and path subset of the embedded Database, served remotely
+ *
+ * @example
+ * ```typescript
+ * import { openDatabase } from '@sochdb/sochdb';
+ *
+ * // EmbeddedDatabase where a native library exists, PortableDatabase elsewhere
+ * const db = openDatabase('./mydb', { serverAddress: 'sochdb:50051' });
+ * await db.put('key', Buffer.from('value'));
+ * ```
+ */
+export class PortableDatabase {
+  private client: SochDBClient;
+  private namespace: string;
+  private closed = false;
+
+  private constructor(client: SochDBClient, namespace: string) {
+    this.client = client;
+    this.namespace = namespace;
+  }
+
+  /**
+   * Connect to the server backing the database at `path`
+   */
+  static connect(path: string, options: PortableOptions = {}): PortableDatabase {
+    const client = new SochDBClient({
+      address: options.serverAddress ?? process.env.SOCHDB_SERVER_ADDRESS ?? 'localhost:50051',
+      secure: options.secure,
+    });
+    return new PortableDatabase(client, options.namespace ?? path);
+  }
+
+  get backend(): Backend {
+    return 'portable';
+  }
+
+  async put(key: Buffer | string, value: Buffer | string, options?: { ttlMs?: number }): Promise<void> {
+    this.ensureOpen();
+    const ttlSeconds = options?.ttlMs ? Math.ceil(options.ttlMs / 1000) : 0;
+    const ok = await this.client.put(toBuffer(key), toBuffer(value), this.namespace, ttlSeconds);
+    if (!ok) {
+      throw new DatabaseError(`Failed to put key '${key.toString()}'`);
+    }
+  }
+
+  async get(key: Buffer | string): Promise<Buffer | null> {
+    this.ensureOpen();
+    return this.client.get(toBuffer(key), this.namespace);
+  }
+
+  async delete(key: Buffer | string): Promise<void> {
+    this.ensureOpen();
+    await this.client.delete(toBuffer(key), this.namespace);
+  }
+
+  async putPath(path: string, value: Buffer | string): Promise<void> {
+    validatePath(path);
+    return this.put(path, value);
+  }
+
+  async getPath(path: string): Promise<Buffer | null> {
+    return this.get(path);
+  }
+
+  async close(): Promise<void> {
+    if (this.closed) return;
+    this.closed = true;
+    this.client.close();
+  }
+
+  private ensureOpen(): void {
+    if (this.closed) {
+      throw new DatabaseError('Database is closed');
+    }
+  }
+}
+
+function toBuffer(value: Buffer | string): Buffer {
+  return typeof value === 'string' ? Buffer.from(value) : value;
+}
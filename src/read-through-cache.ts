@@ -0,0 +1,215 @@
+/**
+ * Read-Through Cache
+ *
+ * Caches the results of an expensive loader (remote API, slower store) in
+ * the embedded database. Misses call the loader and store the result with a
+ * TTL. With stale-while-revalidate, an expired entry is served immediately
+ * while a single background refresh replaces it, so callers don't all pay
+ * the loader latency at the TTL boundary.
+ */
+
+import { EmbeddedDatabase } from './embedded';
+import { jitterTtl } from './embedded/clock';
+
+export interface CachePolicy {
+  /** How long a loaded value is fresh */
+  ttlMs: number;
+  /**
+   * How long after expiry a stale value may still be served while it is
+   * refreshed in the background (0 = always block on the loader)
+   */
+  staleWhileRevalidateMs?: number;
+  /**
+   * Fraction of the TTL (0-1) by which each entry's expiry is randomly
+   * shortened, so entries loaded together don't all expire together
+   */
+  ttlJitter?: number;
+}
+
+export interface ReadThroughCacheOptions<T> {
+  /** Produces the value for a key on a miss or refresh; null means "not found" and is not cached */
+  loader: (key: string) => Promise<T | null>;
+  /** Default policy for keys without a more specific prefix policy */
+  ttlMs: number;
+  staleWhileRevalidateMs?: number;
+  ttlJitter?: number;
+  /** Policies by key prefix; the longest matching prefix wins */
+  policies?: Record<string, CachePolicy>;
+  /** Called when a background refresh fails; the stale value stays in place */
+  onRefreshError?: (key: string, error: unknown) => void;
+}
+
+export interface ReadThroughCacheStats {
+  hits: number;
+  staleHits: number;
+  misses: number;
+  /** Background refreshes started by stale hits */
+  refreshes: number;
+  /** Background refreshes that failed */
+  refreshErrors: number;
+}
+
+interface StoredEntry<T> {
+  value: T;
+  storedAt: number;
+  expiresAt: number;
+}
+
+/**
+ * Read-through cache backed by an embedded database
+ *
+ * @example
+ * ```typescript
+ * const users = new ReadThroughCache(db, 'users', {
+ *   loader: (id) => api.fetchUser(id),
+ *   ttlMs: 60_000,
+ *   staleWhileRevalidateMs: 30_000,
+ *   ttlJitter: 0.1,
+ *   policies: {
+ *     'admin/': { ttlMs: 5_000 }, // always fresh for admins
+ *   },
+ * });
+ *
+ * const user = await users.get('u42');
+ * ```
+ */
+export class ReadThroughCache<T = any> {
+  private db: EmbeddedDatabase;
+  private prefix: string;
+  private options: ReadThroughCacheOptions<T>;
+  private policyPrefixes: string[];
+  private inflight = new Map<string, Promise<T | null>>();
+  private counters: ReadThroughCacheStats = {
+    hits: 0,
+    staleHits: 0,
+    misses: 0,
+    refreshes: 0,
+    refreshErrors: 0,
+  };
+
+  constructor(db: EmbeddedDatabase, name: string, options: ReadThroughCacheOptions<T>) {
+    this.db = db;
+    this.prefix = `_cache/rt/${name}/`;
+    this.options = options;
+    this.policyPrefixes = Object.keys(options.policies ?? {}).sort((a, b) => b.length - a.length);
+  }
+
+  /**
+   * Get a value, loading it on a miss. Stale values inside the
+   * stale-while-revalidate window are returned immediately and refreshed
+   * in the background.
+   */
+  async get(key: string): Promise<T | null> {
+    const entry = await this.read(key);
+    const now = this.db.now();
+
+    if (entry && now <= entry.expiresAt) {
+      this.counters.hits++;
+      return entry.value;
+    }
+
+    if (entry) {
+      const { staleWhileRevalidateMs = 0 } = this.policyFor(key);
+      if (now <= entry.expiresAt + staleWhileRevalidateMs) {
+        this.counters.staleHits++;
+        if (!this.inflight.has(key)) {
+          this.counters.refreshes++;
+          this.load(key).catch((error) => {
+            this.counters.refreshErrors++;
+            this.options.onRefreshError?.(key, error);
+          });
+        }
+        return entry.value;
+      }
+    }
+
+    this.counters.misses++;
+    return this.load(key);
+  }
+
+  /**
+   * Store a value directly, bypassing the loader
+   */
+  async set(key: string, value: T): Promise<void> {
+    const { ttlMs, ttlJitter = 0 } = this.policyFor(key);
+    const storedAt = this.db.now();
+    const entry: StoredEntry<T> = {
+      value,
+      storedAt,
+      expiresAt: storedAt + jitterTtl(ttlMs, ttlJitter),
+    };
+    await this.db.put(this.entryKey(key), JSON.stringify(entry));
+  }
+
+  /**
+   * Reload a key now, regardless of freshness
+   */
+  async refresh(key: string): Promise<T | null> {
+    return this.load(key);
+  }
+
+  /**
+   * Drop a cached key so the next read goes to the loader
+   */
+  async invalidate(key: string): Promise<void> {
+    await this.db.delete(this.entryKey(key));
+  }
+
+  stats(): ReadThroughCacheStats {
+    return { ...this.counters };
+  }
+
+  /**
+   * Policy for a key: longest matching prefix policy, else the defaults
+   */
+  policyFor(key: string): CachePolicy {
+    const match = this.policyPrefixes.find((p) => key.startsWith(p));
+    if (match) {
+      return this.options.policies![match];
+    }
+    return {
+      ttlMs: this.options.ttlMs,
+      staleWhileRevalidateMs: this.options.staleWhileRevalidateMs,
+      ttlJitter: this.options.ttlJitter,
+    };
+  }
+
+  /**
+   * Call the loader once per key at a time and store its result
+   */
+  private load(key: string): Promise<T | null> {
+    const pending = this.inflight.get(key);
+    if (pending) return pending;
+
+    const promise = (async () => {
+      try {
+        const value = await this.options.loader(key);
+        if (value === null) {
+          await this.invalidate(key);
+        } else {
+          await this.set(key, value);
+        }
+        return value;
+      } finally {
+        this.inflight.delete(key);
+      }
+    })();
+
+    this.inflight.set(key, promise);
+    return promise;
+  }
+
+  private async read(key: string): Promise<StoredEntry<T> | null> {
+    const raw = await this.db.get(this.entryKey(key));
+    if (!raw) return null;
+    try {
+      return JSON.parse(raw.toString());
+    } catch {
+      return null;
+    }
+  }
+
+  private entryKey(key: string): string {
+    return `${this.prefix}${key}`;
+  }
+}
@@ -6,6 +6,7 @@
  */
 
 import { EmbeddedDatabase } from './embedded';
+import { jitterTtl } from './embedded/clock';
 
 /**
  * Calculate cosine similarity between two vectors
@@ -45,6 +46,14 @@ export interface CacheHit extends CacheEntry {
   score: number;
 }
 
+export interface SemanticCacheOptions {
+  /**
+   * Fraction of the TTL (0-1) by which each entry's expiry is randomly
+   * shortened, so entries written together don't all expire together
+   */
+  ttlJitter?: number;
+}
+
 export interface CacheStats {
   count: number;
   hits: number;
@@ -81,11 +90,13 @@ export class SemanticCache {
   private prefix: Buffer;
   private hits = 0;
   private misses = 0;
+  private ttlJitter: number;
 
-  constructor(db: EmbeddedDatabase, cacheName: string) {
+  constructor(db: EmbeddedDatabase, cacheName: string, options: SemanticCacheOptions = {}) {
     this.db = db;
     this.cacheName = cacheName;
     this.prefix = Buffer.from(`cache:${cacheName}:`);
+    this.ttlJitter = options.ttlJitter ?? 0;
   }
 
   /**
@@ -102,8 +113,9 @@ export class SemanticCache {
       key,
       value,
       embedding,
-      timestamp: Date.now(),
-      ttl: ttlSeconds > 0 ? ttlSeconds : undefined,
+      timestamp: this.db.now(),
+      // Jitter in milliseconds; entries store the TTL in seconds
+      ttl: ttlSeconds > 0 ? jitterTtl(ttlSeconds * 1000, this.ttlJitter) / 1000 : undefined,
       metadata,
     };
 
@@ -126,7 +138,7 @@ export class SemanticCache {
     queryEmbedding: number[],
     threshold = 0.85
   ): Promise<CacheHit | null> {
-    const now = Date.now();
+    const now = this.db.now();
     let bestMatch: CacheHit | null = null;
     let bestScore = threshold;
 
@@ -210,7 +222,7 @@ export class SemanticCache {
    * Get cache statistics
    */
   async stats(): Promise<CacheStats> {
-    const now = Date.now();
+    const now = this.db.now();
     let count = 0;
     let memoryUsage = 0;
 
@@ -249,7 +261,7 @@ export class SemanticCache {
    * Purge expired entries
    */
   async purgeExpired(): Promise<number> {
-    const now = Date.now();
+    const now = this.db.now();
     let purged = 0;
 
     try {
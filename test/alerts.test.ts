@@ -0,0 +1,57 @@
+/**
+ * Tests for soft-limit alert transitions
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { AlertSample, AlertTracker } from '../src/embedded/alerts';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+function sample(overrides: Partial<AlertSample>): AlertSample {
+  return { walSizeBytes: 0, diskUsageBytes: 0, activeTransactions: 0, memtableSizeBytes: 0, ...overrides };
+}
+
+describe('Alert Tracker', () => {
+  test('raises once per crossing and clears when back below', () => {
+    const tracker = new AlertTracker({ walSizeBytes: 100, activeTransactions: 10 });
+
+    expect(tracker.update(sample({ walSizeBytes: 50 }))).toEqual([]);
+    expect(tracker.update(sample({ walSizeBytes: 100 }))).toEqual([
+      { metric: 'walSizeBytes', state: 'raised', value: 100, threshold: 100 },
+    ]);
+    expect(tracker.update(sample({ walSizeBytes: 150 }))).toEqual([]);
+    expect(tracker.update(sample({ walSizeBytes: 20, activeTransactions: 12 }))).toEqual([
+      { metric: 'walSizeBytes', state: 'cleared', value: 20, threshold: 100 },
+      { metric: 'activeTransactions', state: 'raised', value: 12, threshold: 10 },
+    ]);
+  });
+
+  test('ignores metrics without a threshold', () => {
+    const tracker = new AlertTracker({ memtableSizeBytes: 10 });
+    expect(tracker.update(sample({ walSizeBytes: 1e12 }))).toEqual([]);
+  });
+});
+
+describe('Alert interval validation', () => {
+  beforeEach(() => native.reset());
+
+  test('rejects a non-positive interval before opening a handle', () => {
+    const open = jest.spyOn(native, 'sochdb_open');
+    const alerts = (intervalMs: number) => ({ thresholds: { walSizeBytes: 100 }, intervalMs });
+    expect(() => EmbeddedDatabase.open('alerts-db', { alerts: alerts(0) })).toThrow(DatabaseError);
+    expect(() => EmbeddedDatabase.open('alerts-db', { alerts: alerts(NaN) }))
+      .toThrow('intervalMs must be a positive number, got NaN');
+    expect(open).not.toHaveBeenCalled();
+    open.mockRestore();
+  });
+
+  test('setAlertThresholds rejects a non-positive interval', () => {
+    const db = EmbeddedDatabase.open('alerts-db');
+    expect(() => db.setAlertThresholds({ walSizeBytes: 100 }, { intervalMs: -5 }))
+      .toThrow('intervalMs must be a positive number, got -5');
+    db.close();
+  });
+});
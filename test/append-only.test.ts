@@ -0,0 +1,74 @@
+/**
+ * Tests for append-only prefixes
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { AppendOnlyViolationError, DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Append-only prefixes', () => {
+  let db: EmbeddedDatabase;
+
+  /** Reject writes to existing keys under a marked prefix, as the engine does */
+  function enableAppendOnly(): void {
+    const prefixes: Buffer[] = [];
+    native.sochdb_set_append_only = (_db: unknown, prefix: Buffer, len: number) => {
+      prefixes.push(Buffer.from(prefix.subarray(0, len)));
+      return 0;
+    };
+    const guard = (write: (...args: any[]) => number) =>
+      (h: unknown, txn: unknown, key: Buffer, klen: number, ...rest: any[]) => {
+        const k = key.subarray(0, klen);
+        const guarded = prefixes.some((p) => k.subarray(0, p.length).equals(p));
+        if (guarded && native.store.has(k.toString('hex'))) return -6;
+        return write(h, txn, key, klen, ...rest);
+      };
+    native.sochdb_put = guard(native.sochdb_put);
+    native.sochdb_delete = guard(native.sochdb_delete);
+  }
+
+  beforeEach(() => {
+    native.reset();
+    db = EmbeddedDatabase.open('append-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('new keys can be written but existing ones cannot be changed', async () => {
+    enableAppendOnly();
+    db.setAppendOnly('audit/');
+    await db.put('audit/1', 'created');
+    await db.put('audit/2', 'updated');
+
+    const update = db.put('audit/1', 'tampered');
+    await expect(update).rejects.toThrow(AppendOnlyViolationError);
+    await expect(db.delete('audit/1')).rejects.toThrow(
+      "Key 'audit/1' is under an append-only prefix and cannot be updated or deleted"
+    );
+    expect((await db.get('audit/1'))?.toString()).toBe('created');
+
+    await db.put('other/1', 'a');
+    await db.put('other/1', 'b');
+    expect((await db.get('other/1'))?.toString()).toBe('b');
+  });
+
+  test('the violation carries the offending key', async () => {
+    enableAppendOnly();
+    db.setAppendOnly('audit/');
+    await db.put('audit/1', 'created');
+    const error = await db.delete('audit/1').catch((e) => e);
+    expect(error).toBeInstanceOf(AppendOnlyViolationError);
+    expect(error.key.toString()).toBe('audit/1');
+  });
+
+  test('fails clearly when the native library has no append-only support', () => {
+    expect(() => db.setAppendOnly('audit/')).toThrow(DatabaseError);
+    native.sochdb_set_append_only = () => -1;
+    expect(() => db.setAppendOnly('audit/')).toThrow("Failed to mark prefix 'audit/' append-only (Code -1)");
+  });
+});
@@ -0,0 +1,89 @@
+/**
+ * Tests for presorted bulk loading
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Bulk loading', () => {
+  let db: EmbeddedDatabase;
+  let aborted: number;
+
+  /** Loader that installs its entries into the store on finish */
+  function enableBulkLoader(): void {
+    native.sochdb_bulk_loader_new = () => ({ entries: [] as Array<[Buffer, Buffer]> });
+    native.sochdb_bulk_loader_add = (loader: any, key: Buffer, klen: number, value: Buffer, vlen: number) => {
+      loader.entries.push([Buffer.from(key.subarray(0, klen)), Buffer.from(value.subarray(0, vlen))]);
+      return 0;
+    };
+    native.sochdb_bulk_loader_finish = (loader: any) => {
+      for (const [key, value] of loader.entries) native.store.set(key.toString('hex'), value);
+      return 0;
+    };
+    native.sochdb_bulk_loader_abort = () => {
+      aborted++;
+    };
+  }
+
+  beforeEach(() => {
+    native.reset();
+    aborted = 0;
+    db = EmbeddedDatabase.open('bulk-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('installs presorted entries on finish', async () => {
+    enableBulkLoader();
+    const loader = db.bulkLoader();
+    loader.add('a', '1');
+    loader.add('b', '2');
+    expect(await db.get('a')).toBeNull();
+
+    expect(loader.finish()).toEqual({ count: 2 });
+    expect((await db.get('a'))?.toString()).toBe('1');
+    expect((await db.get('b'))?.toString()).toBe('2');
+    expect(() => loader.add('c', '3')).toThrow('Bulk loader has already been finished or aborted');
+  });
+
+  test('rejects out-of-order keys and aborts the whole ingest', async () => {
+    enableBulkLoader();
+    await expect(db.ingestSorted([['b', '2'], ['a', '1']])).rejects.toThrow(
+      "Bulk load keys must be strictly increasing: 'a' after 'b'"
+    );
+    expect(aborted).toBe(1);
+    expect(await db.get('b')).toBeNull();
+  });
+
+  test('ingests a presorted JSONL file, skipping the export header', async () => {
+    enableBulkLoader();
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-bulk-'));
+    const file = path.join(dir, 'sorted.jsonl');
+    try {
+      await db.put('x/1', 'one');
+      await db.put('x/2', 'two');
+      await db.exportJsonl('x/', file);
+      native.reset();
+      enableBulkLoader();
+
+      expect(await db.ingestSorted(file)).toEqual({ count: 2 });
+      expect((await db.get('x/2'))?.toString()).toBe('two');
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+
+  test('fails clearly when the native library cannot bulk load', () => {
+    expect(() => db.bulkLoader()).toThrow(DatabaseError);
+    expect(() => db.bulkLoader()).toThrow('Bulk loading is not supported by the loaded SochDB native library');
+  });
+});
@@ -0,0 +1,100 @@
+/**
+ * Tests for checkpoint cancellation
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { AbortError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Checkpoint Cancellation', () => {
+  let db: EmbeddedDatabase;
+  let finish: ((res: number) => void) | null;
+  let cancelled: number;
+
+  beforeEach(() => {
+    native.reset();
+    finish = null;
+    cancelled = 0;
+    const checkpoint: any = () => 7;
+    checkpoint.async = (_db: unknown, callback: (err: unknown, res: number) => void) => {
+      finish = (res) => callback(null, res);
+    };
+    native.sochdb_checkpoint = checkpoint;
+    db = EmbeddedDatabase.open('checkpoint-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('runs without a signal as before', async () => {
+    expect(await db.checkpoint()).toBe(7n);
+  });
+
+  test('aborting the signal cancels the running native checkpoint', async () => {
+    native.sochdb_checkpoint_cancel = () => {
+      cancelled++;
+      finish!(-8);
+      return 0;
+    };
+    const controller = new AbortController();
+    const running = db.checkpoint({ signal: controller.signal });
+    await Promise.resolve();
+    expect(finish).not.toBeNull();
+
+    controller.abort();
+    await expect(running).rejects.toBeInstanceOf(AbortError);
+    expect(cancelled).toBe(1);
+  });
+
+  test('a checkpoint that finishes first resolves and ignores later aborts', async () => {
+    native.sochdb_checkpoint_cancel = () => {
+      cancelled++;
+      return 0;
+    };
+    const controller = new AbortController();
+    const running = db.checkpoint({ signal: controller.signal });
+    await Promise.resolve();
+    finish!(9);
+    expect(await running).toBe(9n);
+
+    controller.abort();
+    expect(cancelled).toBe(0);
+  });
+
+  test('requires native support to cancel', async () => {
+    await expect(db.checkpoint({ signal: new AbortController().signal })).rejects.toThrow(
+      'Cancelling checkpoints is not supported by the loaded SochDB native library'
+    );
+  });
+
+  test('aborting the signal cancels a running checkpointTo', async () => {
+    let finishTo: ((res: bigint) => void) | null = null;
+    const checkpointTo: any = () => 3n;
+    checkpointTo.async = (_db: unknown, _dir: string, _inc: boolean, _base: bigint, callback: any) => {
+      finishTo = (res) => callback(null, res);
+    };
+    native.sochdb_checkpoint_to = checkpointTo;
+    native.sochdb_checkpoint_cancel = () => {
+      cancelled++;
+      finishTo!(-8n);
+      return 0;
+    };
+    const controller = new AbortController();
+    const running = db.checkpointTo('/backups/ckpt', { signal: controller.signal });
+    await Promise.resolve();
+    expect(finishTo).not.toBeNull();
+
+    controller.abort();
+    await expect(running).rejects.toBeInstanceOf(AbortError);
+    expect(cancelled).toBe(1);
+  });
+
+  test('checkpointTo reports the native error code', async () => {
+    native.sochdb_checkpoint_to = () => -3n;
+    await expect(db.checkpointTo('/backups/ckpt')).rejects.toThrow('Failed to write checkpoint to /backups/ckpt (Code -3)');
+  });
+});
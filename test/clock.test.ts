@@ -0,0 +1,36 @@
+/**
+ * Tests for clock sources
+ */
+
+import { ManualClock, jitterTtl, offsetClock } from '../src/embedded/clock';
+
+describe('Clock Sources', () => {
+  test('manual clock only moves when advanced', () => {
+    const clock = new ManualClock(1_000);
+    expect(clock.now()).toBe(1_000);
+    clock.advance(500);
+    expect(clock.now()).toBe(1_500);
+    clock.set(10);
+    expect(clock.now()).toBe(10);
+    expect(() => clock.advance(-1)).toThrow(RangeError);
+  });
+
+  test('offset clock shifts its base', () => {
+    const base = new ManualClock(5_000);
+    const skewed = offsetClock(-1_500, base);
+    expect(skewed.now()).toBe(3_500);
+    base.advance(100);
+    expect(skewed.now()).toBe(3_600);
+  });
+
+  test('ttl jitter only shortens, within the configured fraction', () => {
+    expect(jitterTtl(60_000, 0.1, () => 0)).toBe(60_000);
+    expect(jitterTtl(60_000, 0.1, () => 1)).toBe(54_000);
+    for (let i = 0; i < 100; i++) {
+      const ttl = jitterTtl(60_000, 0.25);
+      expect(ttl).toBeGreaterThanOrEqual(45_000);
+      expect(ttl).toBeLessThanOrEqual(60_000);
+    }
+    expect(() => jitterTtl(1_000, 1.5)).toThrow(RangeError);
+  });
+});
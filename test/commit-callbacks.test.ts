@@ -0,0 +1,121 @@
+/**
+ * Tests for onCommit / onRollback callbacks
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+describe('Commit Callbacks', () => {
+  let db: EmbeddedDatabase;
+  let settle: Array<(res: number) => void>;
+
+  beforeEach(() => {
+    native.reset();
+    settle = [];
+    native.enableAckLevels((done) => void settle.push(done));
+    db = EmbeddedDatabase.open('callbacks-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('onCommit waits for the requested durability', async () => {
+    const txn = db.transaction();
+    const ran: string[] = [];
+    txn.onCommit(() => void ran.push('commit'));
+    await txn.put('k', 'v');
+
+    const commit = txn.commit({ ack: 'walWritten' });
+    await new Promise((resolve) => setImmediate(resolve));
+    expect(ran).toEqual([]);
+
+    settle[0](0);
+    await commit;
+    expect(ran).toEqual(['commit']);
+  });
+
+  test("with ack 'applied' or the default ack, onCommit still waits for fsync", async () => {
+    const levels: number[] = [];
+    const wait = native.sochdb_wait_durable.async;
+    native.sochdb_wait_durable.async = (db: unknown, ts: bigint, level: number, cb: any) => {
+      levels.push(level);
+      wait(db, ts, level, cb);
+    };
+
+    for (const options of [{ ack: 'applied' as const }, undefined]) {
+      const txn = db.transaction();
+      const ran: string[] = [];
+      txn.onCommit(() => void ran.push('commit'));
+      await txn.put('k', 'v');
+
+      const commit = txn.commit(options);
+      await new Promise((resolve) => setImmediate(resolve));
+      expect(ran).toEqual([]);
+      settle.shift()!(0);
+      await commit;
+      expect(ran).toEqual(['commit']);
+    }
+    expect(levels).toEqual([2, 2]);
+  });
+
+  test('without onCommit callbacks, the default ack does not wait', async () => {
+    const txn = db.transaction();
+    await txn.put('k', 'v');
+    await txn.commit();
+    expect(settle).toHaveLength(0);
+  });
+
+  test('a commit that is not confirmed durable runs onRollback', async () => {
+    const txn = db.transaction();
+    const ran: string[] = [];
+    txn.onCommit(() => void ran.push('commit'));
+    txn.onRollback(() => void ran.push('rollback'));
+    await txn.put('k', 'v');
+
+    const commit = txn.commit();
+    await new Promise((resolve) => setImmediate(resolve));
+    settle[0](-1);
+    await expect(commit).rejects.toThrow("did not reach 'fsynced'");
+    expect(ran).toEqual(['rollback']);
+  });
+
+  test('closing the database runs onRollback for open transactions', async () => {
+    const local = EmbeddedDatabase.open('callbacks-close-db');
+    const txn = local.transaction();
+    const ran: string[] = [];
+    txn.onCommit(() => void ran.push('commit'));
+    txn.onRollback(() => void ran.push('rollback'));
+    txn.onRollback(() => {
+      throw new Error('boom');
+    });
+    const errors: unknown[] = [];
+    local.on('error', (error) => errors.push(error));
+
+    local.close();
+    await new Promise((resolve) => setImmediate(resolve));
+    expect(ran).toEqual(['rollback']);
+    expect(errors).toEqual([new Error('boom')]);
+
+    await txn.abort();
+    expect(ran).toEqual(['rollback']);
+  });
+
+  test('onCommit requires durability acknowledgments', () => {
+    delete native.sochdb_wait_durable;
+    const txn = db.transaction();
+    expect(() => txn.onCommit(() => undefined)).toThrow('Commit callbacks are not supported');
+  });
+
+  test('an aborted transaction only runs onRollback', async () => {
+    const txn = db.transaction();
+    const ran: string[] = [];
+    txn.onCommit(() => void ran.push('commit'));
+    txn.onRollback(() => void ran.push('rollback'));
+    await txn.abort();
+    expect(ran).toEqual(['rollback']);
+  });
+});
@@ -0,0 +1,67 @@
+/**
+ * Tests for sampled database comparison
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { isSampled } from '../src/embedded/compare';
+import { native } from './helpers/mock-native';
+
+describe('Sampled Compare', () => {
+  beforeEach(() => {
+    native.reset();
+  });
+
+  test('sampling is deterministic and roughly proportional', () => {
+    const keys = Array.from({ length: 2000 }, (_, i) => Buffer.from(`key-${i}`));
+    const picked = keys.filter((k) => isSampled(k, 10, 7));
+    expect(picked.length).toBeGreaterThan(120);
+    expect(picked.length).toBeLessThan(280);
+    expect(keys.filter((k) => isSampled(k, 10, 7))).toEqual(picked);
+    expect(keys.every((k) => isSampled(k, 100))).toBe(true);
+  });
+
+  test('reports each kind of mismatch from the native comparison', async () => {
+    const calls: unknown[][] = [];
+    native.sochdb_compare_sampled = (...args: any[]) => {
+      calls.push(args.slice(0, 7));
+      const json = Buffer.from(JSON.stringify({
+        sample_percent: 100,
+        sampled: 4,
+        matched: 1,
+        mismatch_count: 3,
+        mismatches: [
+          { key: Buffer.from('changed').toString('base64'), problem: 'value_differs' },
+          { key: Buffer.from('onlyA').toString('base64'), problem: 'missing_in_b' },
+          { key: Buffer.from('onlyB').toString('base64'), problem: 'missing_in_a' },
+        ],
+      }));
+      args[7][0] = json;
+      args[8][0] = json.length;
+      return 0;
+    };
+
+    const result = await EmbeddedDatabase.compare('./a', './b', { samplePercent: 100, seed: 3 });
+    expect(calls[0][0]).toBe('./a');
+    expect(calls[0][5]).toBe(3n);
+    expect(result.matched).toBe(1);
+    expect(result.mismatches.map((m) => `${m.key}:${m.problem}`)).toEqual([
+      'changed:value_differs',
+      'onlyA:missing_in_b',
+      'onlyB:missing_in_a',
+    ]);
+  });
+
+  test('fails without native support instead of opening either database', async () => {
+    const opened: string[] = [];
+    native.sochdb_open = (p: string) => {
+      opened.push(p);
+      return { db: true };
+    };
+
+    await expect(EmbeddedDatabase.compare('./a', './typo')).rejects.toThrow('not supported by the loaded SochDB native library');
+    expect(opened).toEqual([]);
+  });
+});
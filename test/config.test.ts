@@ -0,0 +1,111 @@
+/**
+ * Tests for config file / environment resolution
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+/** Native resolution that fills unset fields the way a config file would */
+function resolveWith(defaults: Record<string, unknown>): void {
+  native.sochdb_resolve_config = (explicit: any, _file: string | null, _env: boolean, out: any) => {
+    Object.assign(out, explicit);
+    for (const [field, value] of Object.entries(defaults)) {
+      const set = `${field}_set`;
+      if (set in explicit ? !explicit[set] : !Number(explicit[field])) {
+        out[field] = value;
+        if (set in explicit) out[set] = true;
+      }
+    }
+    return 0;
+  };
+}
+
+describe('Config Resolution', () => {
+  beforeEach(() => {
+    native.reset();
+    native.isConcurrentModeAvailable = () => true;
+    native.sochdb_open_concurrent = () => ({ db: true });
+  });
+
+  test('openConcurrent resolves options like open()', () => {
+    resolveWith({ sync_mode: 2 });
+    const configs: any[] = [];
+    native.sochdb_open_concurrent_with_config = (_path: string, config: unknown) => {
+      configs.push(config);
+      return { db: true };
+    };
+
+    const db = EmbeddedDatabase.openConcurrent('concurrent-db', { walEnabled: true });
+    db.close();
+    expect(configs).toHaveLength(1);
+    expect(configs[0]).toMatchObject({ wal_enabled: true, wal_enabled_set: true, sync_mode: 2, sync_mode_set: true });
+  });
+
+  test('openConcurrent refuses options the native library cannot apply', () => {
+    resolveWith({});
+    expect(() => EmbeddedDatabase.openConcurrent('concurrent-db', { syncMode: 'full' })).toThrow(
+      'Open options in concurrent mode are not supported by the loaded SochDB native library'
+    );
+
+    // Nothing to apply: plain concurrent open
+    EmbeddedDatabase.openConcurrent('concurrent-db').close();
+  });
+
+  test('an explicit memtable size wins over resolved defaults, including 0', () => {
+    resolveWith({ memtable_size_bytes: 4096n });
+    expect(EmbeddedDatabase.resolveConfig({}).memtableSizeBytes).toBe(4096);
+    expect(EmbeddedDatabase.resolveConfig({ memtableSizeBytes: 0 }).memtableSizeBytes).toBe(0);
+    expect(EmbeddedDatabase.resolveConfig({ memtableSizeBytes: 1024 }).memtableSizeBytes).toBe(1024);
+  });
+
+  test('openConcurrent applies compression and the write format like open()', () => {
+    const calls: unknown[][] = [];
+    native.sochdb_set_compression = (_db: unknown, codec: number, minBytes: number) => {
+      calls.push(['compression', codec, minBytes]);
+      return 0;
+    };
+    native.sochdb_set_write_format_version = (_db: unknown, version: number) => {
+      calls.push(['format', version]);
+      return 0;
+    };
+
+    const options = { compression: 'zstd' as const, compressionMinBytes: 64, writeFormatVersion: 3 };
+    EmbeddedDatabase.openConcurrent('concurrent-db', options).close();
+    EmbeddedDatabase.open('standard-db', options).close();
+    expect(calls).toEqual([['compression', 2, 64], ['format', 3], ['compression', 2, 64], ['format', 3]]);
+  });
+
+  test('openConcurrent closes the handle when compression is unsupported', () => {
+    let closed = 0;
+    native.sochdb_close = () => {
+      closed++;
+    };
+    expect(() => EmbeddedDatabase.openConcurrent('concurrent-db', { compression: 'lz4' })).toThrow(
+      'Value compression is not supported by the loaded SochDB native library'
+    );
+    expect(closed).toBe(1);
+  });
+
+  test('per-put compression passes the codec and rejects unknown ones', async () => {
+    const codecs: number[] = [];
+    native.sochdb_put_compressed = (db: unknown, txn: unknown, key: Buffer, klen: number, value: Buffer, vlen: number, codec: number) => {
+      codecs.push(codec);
+      return native.sochdb_put(db, txn, key, klen, value, vlen);
+    };
+    const db = EmbeddedDatabase.open('standard-db');
+    try {
+      await db.put('k', 'v', { compression: 'lz4' });
+      await expect(db.put('k', 'v', { compression: 'brotli' as any })).rejects.toThrow(
+        'Unsupported compression codec: brotli'
+      );
+      await expect(db.put('k', 'v', { compression: 'toString' as any })).rejects.toThrow(DatabaseError);
+      expect(codecs).toEqual([1]);
+    } finally {
+      db.close();
+    }
+  });
+});
@@ -0,0 +1,59 @@
+/**
+ * Tests for the IPC Database clock
+ */
+
+jest.mock('../src/server-manager', () => ({
+  startEmbeddedServer: async () => 'mock.sock',
+  stopEmbeddedServer: async () => undefined,
+}));
+jest.mock('../src/ipc-client', () => {
+  const store = new Map<string, Buffer>();
+  const client = {
+    get: async (key: Buffer) => store.get(key.toString()) ?? null,
+    put: async (key: Buffer, value: Buffer) => {
+      store.set(key.toString(), value);
+    },
+    scan: async (prefix: string) =>
+      [...store].filter(([key]) => key.startsWith(prefix)).map(([key, value]) => ({ key: Buffer.from(key), value })),
+    close: async () => undefined,
+  };
+  return { IpcClient: { connect: async () => client } };
+});
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { Database } from '../src/database';
+import { ManualClock } from '../src/embedded/clock';
+
+describe('Database Clock', () => {
+  let dir: string;
+  let clock: ManualClock;
+  let db: Database;
+
+  beforeEach(async () => {
+    process.env.SOCHDB_DISABLE_ANALYTICS = 'true';
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-clock-'));
+    clock = new ManualClock(1_000_000);
+    db = await Database.open({ path: dir, clock });
+  });
+
+  afterEach(async () => {
+    await db.close();
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  test('cache TTLs expire by the configured clock', async () => {
+    await db.cachePut('answers', 'q', 'a', [1, 0], 10);
+    clock.advance(10_000);
+    expect(await db.cacheGet('answers', [1, 0])).toBe('a');
+    clock.advance(1_000);
+    expect(await db.cacheGet('answers', [1, 0])).toBeNull();
+  });
+
+  test('span durations are measured by the configured clock', async () => {
+    const { traceId, rootSpanId } = await db.startTrace('request');
+    clock.advance(25);
+    expect(await db.endSpan(traceId, rootSpanId)).toBe(25_000);
+  });
+});
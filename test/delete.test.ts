@@ -0,0 +1,77 @@
+/**
+ * Tests for prefix, range and tree deletes
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+async function keys(db: EmbeddedDatabase): Promise<string[]> {
+  const out: string[] = [];
+  for await (const [key] of db.scanPrefix('')) {
+    out.push(key.toString());
+  }
+  return out;
+}
+
+describe('Deletes', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(async () => {
+    native.reset();
+    db = EmbeddedDatabase.open('delete-db', { undoJournal: true });
+    for (const key of ['users/alice', 'users/alice/posts/1', 'users/alice/posts/2', 'users/alicia', 'users/bob']) {
+      await db.put(key, 'v');
+    }
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('deleteTree removes a path and its descendants but not siblings sharing a prefix', async () => {
+    const result = await db.deleteTree('users/alice');
+    expect(result.count).toBe(3);
+    expect(await keys(db)).toEqual(['users/alicia', 'users/bob']);
+
+    const undone = await db.undoLastAdminOp();
+    expect(undone?.op).toBe('deleteTree');
+    expect(undone?.restored).toBe(3);
+  });
+
+  test('a dry run reports the matches without deleting', async () => {
+    const result = await db.deleteTree('users/alice', { dryRun: true, sampleSize: 2 });
+    expect(result).toMatchObject({ count: 3, dryRun: true });
+    expect(result.sampleKeys.map(String)).toEqual(['users/alice', 'users/alice/posts/1']);
+    expect(await keys(db)).toHaveLength(5);
+  });
+
+  test('deletes in batches of batchSize keys per transaction', async () => {
+    const commit = native.sochdb_commit;
+    let commits = 0;
+    native.sochdb_commit = (...args: any[]) => {
+      commits++;
+      return commit(...args);
+    };
+
+    const result = await db.deletePrefix('users/', { batchSize: 2 });
+    expect(result.count).toBe(5);
+    expect(commits).toBe(3);
+    expect(await keys(db)).toEqual([]);
+
+    // Every batch belongs to the same undoable operation
+    expect((await db.undoLastAdminOp())?.restored).toBe(5);
+    expect(await keys(db)).toHaveLength(5);
+  });
+
+  test('a failed batch leaves earlier batches committed', async () => {
+    const del = native.sochdb_delete;
+    let deletes = 0;
+    native.sochdb_delete = (...args: any[]) => (++deletes === 3 ? -1 : del(...args));
+
+    await expect(db.deletePrefix('users/', { batchSize: 2 })).rejects.toThrow('Failed to delete value');
+    expect(await keys(db)).toEqual(['users/alice/posts/2', 'users/alicia', 'users/bob']);
+  });
+});
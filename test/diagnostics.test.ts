@@ -0,0 +1,60 @@
+/**
+ * Tests for diagnostics_channel events
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import * as diagnostics_channel from 'diagnostics_channel';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { QueryMessage } from '../src/embedded/diagnostics';
+import { native } from './helpers/mock-native';
+
+function record(name: string): { messages: any[]; stop: () => void } {
+  const messages: any[] = [];
+  const listener = (message: unknown) => void messages.push({ ...(message as object) });
+  diagnostics_channel.subscribe(name, listener);
+  return { messages, stop: () => diagnostics_channel.unsubscribe(name, listener) };
+}
+
+describe('Diagnostics Channels', () => {
+  beforeEach(() => {
+    native.reset();
+    native.isConcurrentModeAvailable = () => true;
+    native.sochdb_open_concurrent = () => ({ db: true });
+  });
+
+  test('concurrent opens publish an open event paired with close', () => {
+    const open = record('sochdb:database:open');
+    const close = record('sochdb:database:close');
+    const db = EmbeddedDatabase.openConcurrent('concurrent-db');
+    db.close();
+    open.stop();
+    close.stop();
+    expect(open.messages).toEqual([{ database: 'concurrent-db' }]);
+    expect(close.messages).toEqual([{ database: 'concurrent-db' }]);
+  });
+
+  test('scans are traced from the first entry until they finish', async () => {
+    const db = EmbeddedDatabase.open('traced-db');
+    await db.put('a/1', 'one');
+
+    const start = record('sochdb:query:start');
+    const finish = record('sochdb:query:finish');
+    const keys: string[] = [];
+    for await (const [key] of db.scanPrefix('a/')) {
+      keys.push(key.toString());
+      expect(finish.messages).toEqual([]);
+    }
+    start.stop();
+    finish.stop();
+    db.close();
+
+    expect(keys).toEqual(['a/1']);
+    expect(start.messages).toEqual([{ operation: 'scanPrefix', database: 'traced-db', target: 'a/' }]);
+    const finished: QueryMessage = finish.messages[0];
+    expect(finished.operation).toBe('scanPrefix');
+    expect(finished.durationMs).toBeGreaterThanOrEqual(0);
+    expect(finished.error).toBeUndefined();
+  });
+});
@@ -0,0 +1,66 @@
+/**
+ * Tests for encryption at rest open options
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Encryption at rest', () => {
+  let openEncrypted: jest.Mock;
+
+  beforeEach(() => {
+    native.reset();
+    openEncrypted = jest.fn(() => ({ db: true }));
+    native.sochdb_open_encrypted = openEncrypted;
+  });
+
+  test('passes the key and cipher to the native open', () => {
+    const key = Buffer.alloc(32, 7);
+    const db = EmbeddedDatabase.open('encrypted-db', { encryptionKey: key, cipher: 'chacha20' });
+
+    expect(openEncrypted).toHaveBeenCalledTimes(1);
+    const [path, , passedKey, keyLen, cipher] = openEncrypted.mock.calls[0] as any[];
+    expect(path).toBe('encrypted-db');
+    expect(passedKey).toBe(key);
+    expect(keyLen).toBe(32);
+    expect(cipher).toBe(2);
+    db.close();
+  });
+
+  test('defaults to AES-256-GCM', () => {
+    const db = EmbeddedDatabase.open('encrypted-db', { encryptionKey: Buffer.alloc(32) });
+    expect(openEncrypted.mock.calls[0][4]).toBe(1);
+    db.close();
+  });
+
+  test('rejects a key that is not 32 bytes', () => {
+    expect(() => EmbeddedDatabase.open('encrypted-db', { encryptionKey: Buffer.alloc(16) }))
+      .toThrow('Encryption key must be 32 bytes, got 16');
+    expect(openEncrypted).not.toHaveBeenCalled();
+  });
+
+  test('rejects an unknown cipher', () => {
+    expect(() => EmbeddedDatabase.open('encrypted-db', {
+      encryptionKey: Buffer.alloc(32),
+      cipher: 'rot13' as any,
+    })).toThrow('Unsupported cipher: rot13');
+    expect(openEncrypted).not.toHaveBeenCalled();
+  });
+
+  test('throws when the native library has no encryption support', () => {
+    delete native.sochdb_open_encrypted;
+    expect(() => EmbeddedDatabase.open('encrypted-db', { encryptionKey: Buffer.alloc(32) }))
+      .toThrow(DatabaseError);
+  });
+
+  test('is rejected in concurrent mode', () => {
+    native.isConcurrentModeAvailable = () => true;
+    native.sochdb_open_concurrent = () => ({ db: true });
+    expect(() => EmbeddedDatabase.openConcurrent('encrypted-db', { encryptionKey: Buffer.alloc(32) }))
+      .toThrow('Encryption at rest is not supported in concurrent mode');
+  });
+});
@@ -0,0 +1,54 @@
+/**
+ * Tests for native engine introspection
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { engineInfo } from '../src/embedded/engine-info';
+import { native } from './helpers/mock-native';
+
+describe('engineInfo', () => {
+  beforeEach(() => {
+    native.reset();
+    native.libraryPath = '/opt/sochdb/libsochdb_storage.so';
+  });
+
+  test('reports the version, features and target of the loaded library', () => {
+    let freed = 0;
+    native.sochdb_free_bytes = () => {
+      freed++;
+    };
+    native.sochdb_engine_info = (outPtr: any[], outLen: any[]) => {
+      outPtr[0] = Buffer.from(JSON.stringify({
+        version: '0.5.1',
+        features: ['compression-zstd', 'encryption'],
+        target: 'x86_64-unknown-linux-gnu',
+      }));
+      outLen[0] = outPtr[0].length;
+      return 0;
+    };
+
+    expect(engineInfo()).toEqual({
+      version: '0.5.1',
+      features: ['compression-zstd', 'encryption'],
+      target: 'x86_64-unknown-linux-gnu',
+      libraryPath: '/opt/sochdb/libsochdb_storage.so',
+    });
+    expect(freed).toBe(1);
+  });
+
+  test('libraries that predate engineInfo still report where they were loaded from', () => {
+    expect(engineInfo()).toEqual({
+      version: null,
+      features: [],
+      target: null,
+      libraryPath: '/opt/sochdb/libsochdb_storage.so',
+    });
+  });
+
+  test('surfaces native failures', () => {
+    native.sochdb_engine_info = () => -1;
+    expect(() => engineInfo()).toThrow('Failed to read engine info (Code -1)');
+  });
+});
@@ -0,0 +1,47 @@
+/**
+ * Tests for background event polling
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+describe('Background Events', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    jest.useFakeTimers();
+    native.reset();
+    native.sochdb_poll_events = () => -1;
+    db = EmbeddedDatabase.open('events-db', { eventPollIntervalMs: 10 });
+  });
+
+  afterEach(() => {
+    jest.useRealTimers();
+  });
+
+  test('a failed poll is emitted as an error instead of escaping the timer', () => {
+    const errors: Error[] = [];
+    db.on('error', (error: Error) => errors.push(error));
+    db.on('flush:finish', () => undefined);
+
+    expect(() => jest.advanceTimersByTime(10)).not.toThrow();
+    expect(errors).toHaveLength(1);
+    expect(errors[0].message).toBe('Background events failed (Code -1)');
+    db.close();
+  });
+
+  test('close still releases the native handle when stopping the poller fails', () => {
+    const warn = jest.spyOn(console, 'warn').mockImplementation(() => undefined);
+    const close = jest.fn();
+    native.sochdb_close = close;
+    db.on('flush:finish', () => undefined);
+
+    expect(() => db.close()).not.toThrow();
+    expect(close).toHaveBeenCalledTimes(1);
+    expect(warn).toHaveBeenCalledWith(expect.stringContaining('Polling background events failed'));
+    warn.mockRestore();
+  });
+});
@@ -0,0 +1,63 @@
+/**
+ * Tests for export manifest verification
+ */
+
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { verifyExportManifest, MANIFEST_FILE } from '../src/embedded/export-manifest';
+
+function writeRelease(dir: string, contents: string): void {
+  fs.writeFileSync(path.join(dir, '0000.jsonl'), contents);
+  const manifest = {
+    sochdb_export_manifest: 1,
+    snapshot_lsn: '42',
+    created_at: new Date().toISOString(),
+    files: [{
+      file: '0000.jsonl',
+      prefix: Buffer.from('users/').toString('base64'),
+      count: 1,
+      bytes: Buffer.byteLength(contents),
+      sha256: crypto.createHash('sha256').update(contents).digest('hex'),
+    }],
+  };
+  fs.writeFileSync(path.join(dir, MANIFEST_FILE), JSON.stringify(manifest));
+}
+
+describe('Export Manifest Verification', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-manifest-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  test('accepts an untouched release', async () => {
+    writeRelease(dir, '{"key":"a","value":"b"}\n');
+    expect(await verifyExportManifest(dir)).toEqual({ ok: true, mismatches: [] });
+  });
+
+  test('reports tampered and missing files', async () => {
+    writeRelease(dir, '{"key":"a","value":"b"}\n');
+    fs.writeFileSync(path.join(dir, '0000.jsonl'), '{"key":"a","value":"c"}\n');
+    expect((await verifyExportManifest(dir)).mismatches).toEqual([{ file: '0000.jsonl', problem: 'sha256' }]);
+
+    fs.unlinkSync(path.join(dir, '0000.jsonl'));
+    expect((await verifyExportManifest(dir)).mismatches).toEqual([{ file: '0000.jsonl', problem: 'missing' }]);
+  });
+
+  test('rejects manifests that point outside the export directory', async () => {
+    for (const file of ['../outside.jsonl', path.join(os.tmpdir(), 'outside.jsonl')]) {
+      writeRelease(dir, '{"key":"a","value":"b"}\n');
+      const manifestPath = path.join(dir, MANIFEST_FILE);
+      const manifest = JSON.parse(fs.readFileSync(manifestPath, 'utf8'));
+      manifest.files[0].file = file;
+      fs.writeFileSync(manifestPath, JSON.stringify(manifest));
+      await expect(verifyExportManifest(dir)).rejects.toThrow('outside the export directory');
+    }
+  });
+});
@@ -0,0 +1,68 @@
+/**
+ * Tests for JSONL export
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { ManualClock } from '../src/embedded/clock';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { AbortError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('JSONL Export', () => {
+  let db: EmbeddedDatabase;
+  let dir: string;
+  let file: string;
+
+  beforeEach(async () => {
+    native.reset();
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-export-'));
+    file = path.join(dir, 'users.jsonl');
+    db = EmbeddedDatabase.open('export-db');
+    await db.put('users/a', '1');
+    await db.put('users/b', '2');
+  });
+
+  afterEach(() => {
+    db.close();
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  test('writes a header and one line per entry', async () => {
+    const result = await db.exportJsonl('users/', file);
+    expect(result.count).toBe(2);
+    expect(fs.readFileSync(file, 'utf8').trim().split('\n')).toHaveLength(3);
+    expect(fs.readdirSync(dir)).toEqual(['users.jsonl']);
+  });
+
+  test('timestamps the header and manifest with the database clock', async () => {
+    const at = Date.UTC(2030, 0, 2);
+    const clocked = EmbeddedDatabase.open('export-clock-db', { clock: new ManualClock(at) });
+    await clocked.exportJsonl('users/', file);
+    const header = JSON.parse(fs.readFileSync(file, 'utf8').split('\n')[0]);
+    expect(header.exported_at).toBe('2030-01-02T00:00:00.000Z');
+
+    const manifest = await clocked.exportDataset(path.join(dir, 'release'), { prefixes: ['users/'] });
+    expect(manifest.created_at).toBe('2030-01-02T00:00:00.000Z');
+    clocked.close();
+  });
+
+  test('an aborted export leaves no partial file and keeps an existing one', async () => {
+    fs.writeFileSync(file, 'previous export\n');
+    const controller = new AbortController();
+    const next = native.sochdb_iterator_next;
+    native.sochdb_iterator_next = (...args: any[]) => {
+      const res = next(...args);
+      controller.abort();
+      return res;
+    };
+
+    await expect(db.exportJsonl('users/', file, { signal: controller.signal })).rejects.toBeInstanceOf(AbortError);
+    expect(fs.readdirSync(dir)).toEqual(['users.jsonl']);
+    expect(fs.readFileSync(file, 'utf8')).toBe('previous export\n');
+  });
+});
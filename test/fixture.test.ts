@@ -0,0 +1,82 @@
+/**
+ * Tests for deterministic fixtures
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { readFixture, writeFixture } from '../src/embedded/fixture';
+import { ReadOnlyError } from '../src/errors';
+import { fakeTxn } from './helpers/fake-txn';
+import { native } from './helpers/mock-native';
+
+describe('Fixtures', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-fixture-test-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  test('same contents give identical files and round-trip', async () => {
+    const entries: Array<[string, string]> = [['_admin/undo/1', 'x'], ['a', '1'], ['b/c', '2']];
+    const first = path.join(dir, 'first');
+    const second = path.join(dir, 'second');
+
+    expect(await writeFixture(fakeTxn(entries), first)).toEqual({ count: 2 });
+    await writeFixture(fakeTxn(entries), second);
+    expect(fs.readFileSync(first).equals(fs.readFileSync(second))).toBe(true);
+
+    const loaded: string[] = [];
+    for await (const [key, value] of readFixture(first)) {
+      loaded.push(`${key}=${value}`);
+    }
+    expect(loaded).toEqual(['a=1', 'b/c=2']);
+  });
+
+  test('opens the loaded fixture through a native read-only handle', async () => {
+    native.reset();
+    const file = path.join(dir, 'seeded');
+    await writeFixture(fakeTxn({ a: '1', b: '2' }), file);
+
+    // Every transaction on the reopened handle rejects writes, as the engine's would
+    native.enableReadOnlyTransactions();
+    const beginReadOnly = native.sochdb_begin_txn_readonly;
+    delete native.sochdb_begin_txn_readonly;
+    const opened: string[] = [];
+    native.sochdb_open_read_only = (dbPath: string) => {
+      opened.push(dbPath);
+      native.sochdb_begin_txn = beginReadOnly;
+      return { db: true };
+    };
+
+    const db = await EmbeddedDatabase.openFixture(file);
+    expect(opened).toHaveLength(1);
+    expect((await db.get('b'))?.toString()).toBe('2');
+    await expect(db.put('c', '3')).rejects.toBeInstanceOf(ReadOnlyError);
+    db.close();
+    expect(fs.existsSync(opened[0])).toBe(false);
+  });
+
+  test('requires native read-only opens', async () => {
+    native.reset();
+    const file = path.join(dir, 'seeded');
+    await writeFixture(fakeTxn({ a: '1' }), file);
+    await expect(EmbeddedDatabase.openFixture(file)).rejects.toThrow(
+      'Read-only fixture databases are not supported by the loaded SochDB native library'
+    );
+  });
+
+  test('rejects files that are not fixtures', async () => {
+    const file = path.join(dir, 'export.jsonl');
+    fs.writeFileSync(file, '{"sochdb_export":1}\n');
+    await expect(readFixture(file).next()).rejects.toThrow('not a SochDB fixture');
+  });
+});
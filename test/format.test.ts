@@ -0,0 +1,85 @@
+/**
+ * Tests for in-place format migration
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { AbortError, DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Format migration', () => {
+  let aborted: number;
+
+  /** Migration from version 1 that takes `steps` steps, or fails with `failWith` */
+  function enableMigration(steps: number, failWith?: number): void {
+    native.sochdb_migration_begin = (_path: string, target: number) => ({ target, done: 0 });
+    native.sochdb_migration_step = (handle: any, out: any) => {
+      if (failWith !== undefined) return failWith;
+      handle.done++;
+      Object.assign(out, {
+        from_version: 1,
+        to_version: handle.target,
+        files_done: BigInt(handle.done),
+        files_total: BigInt(steps),
+        bytes_done: BigInt(handle.done * 100),
+        bytes_total: BigInt(steps * 100),
+      });
+      return handle.done === steps ? 1 : 0;
+    };
+    native.sochdb_migration_abort = () => {
+      aborted++;
+    };
+  }
+
+  beforeEach(() => {
+    native.reset();
+    aborted = 0;
+  });
+
+  test('steps to completion and reports progress after each step', async () => {
+    enableMigration(3);
+    const seen: number[] = [];
+    const result = await EmbeddedDatabase.upgradeFormat('format-db', 2, {
+      onProgress: (p) => seen.push(p.bytesDone),
+    });
+    expect(result).toEqual({ fromVersion: 1, toVersion: 2, migrated: true });
+    expect(seen).toEqual([100, 200, 300]);
+    expect(aborted).toBe(0);
+  });
+
+  test('already at the target version is not a migration', async () => {
+    enableMigration(1);
+    expect(await EmbeddedDatabase.upgradeFormat('format-db', 1)).toEqual({
+      fromVersion: 1,
+      toVersion: 1,
+      migrated: false,
+    });
+  });
+
+  test('aborting between steps abandons the migration', async () => {
+    enableMigration(5);
+    const controller = new AbortController();
+    const run = EmbeddedDatabase.upgradeFormat('format-db', 2, {
+      signal: controller.signal,
+      onProgress: (p) => {
+        if (p.filesDone === 2) controller.abort();
+      },
+    });
+    await expect(run).rejects.toThrow(AbortError);
+    expect(aborted).toBe(1);
+  });
+
+  test('a lossy downgrade is refused and abandoned', async () => {
+    enableMigration(1, -3);
+    await expect(EmbeddedDatabase.upgradeFormat('format-db', 0)).rejects.toThrow(
+      'Cannot downgrade format-db to format version 0'
+    );
+    expect(aborted).toBe(1);
+  });
+
+  test('fails clearly when the native library cannot migrate', async () => {
+    await expect(EmbeddedDatabase.upgradeFormat('format-db', 2)).rejects.toThrow(DatabaseError);
+  });
+});
@@ -0,0 +1,54 @@
+/**
+ * Tests for handles invalidated by compaction or close
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { HandleInvalidatedError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+const HANDLE_INVALIDATED = -5;
+
+describe('Handle Invalidation', () => {
+  beforeEach(() => {
+    native.reset();
+  });
+
+  test('aborting a transaction invalidated by compaction releases it natively', async () => {
+    const db = EmbeddedDatabase.open('invalidation-db');
+    const events: unknown[] = [];
+    db.on('handle:invalidated', (e) => events.push(e));
+
+    const txn = db.transaction({ label: 'report' });
+    let rolledBack = false;
+    txn.onRollback(() => {
+      rolledBack = true;
+    });
+
+    native.failNext('sochdb_get', HANDLE_INVALIDATED);
+    await expect(txn.get('k')).rejects.toBeInstanceOf(HandleInvalidatedError);
+    expect(events).toEqual([expect.objectContaining({ kind: 'transaction', reason: 'compaction' })]);
+    expect(native.openTxns.size).toBe(1);
+
+    await txn.abort();
+    expect(native.openTxns.size).toBe(0);
+    expect(rolledBack).toBe(true);
+    expect(db.transactionStats()).toEqual([expect.objectContaining({ label: 'report', aborted: 1 })]);
+    db.close();
+  });
+
+  test('closing the database invalidates a transaction already invalidated by compaction', async () => {
+    const db = EmbeddedDatabase.open('invalidation-db');
+    const txn = db.transaction();
+    native.failNext('sochdb_get', HANDLE_INVALIDATED);
+    await expect(txn.get('k')).rejects.toBeInstanceOf(HandleInvalidatedError);
+
+    db.close();
+    await txn.abort();
+    // The handle was freed with the database, so abort() must not touch it
+    expect(native.openTxns.size).toBe(1);
+    await expect(txn.get('k')).rejects.toMatchObject({ reason: 'database_closed' });
+  });
+});
@@ -0,0 +1,20 @@
+/**
+ * Minimal stand-in for EmbeddedTransaction reads, for pure modules that only
+ * scan and get
+ */
+
+export function fakeTxn(entries: Record<string, string> | Array<[string, string]>): any {
+  const data = new Map(Array.isArray(entries) ? entries : Object.entries(entries));
+  const sorted = [...data.keys()].sort();
+  return {
+    async *scanPrefix(prefix: Buffer = Buffer.alloc(0)) {
+      for (const key of sorted) {
+        if (key.startsWith(prefix.toString())) yield [Buffer.from(key), Buffer.from(data.get(key)!)];
+      }
+    },
+    async get(key: Buffer) {
+      const value = data.get(key.toString());
+      return value === undefined ? null : Buffer.from(value);
+    },
+  };
+}
@@ -0,0 +1,341 @@
+/**
+ * In-memory stand-in for the SochDB native library
+ *
+ * Suites that exercise EmbeddedDatabase without a native build replace the
+ * bindings and koffi modules with this one:
+ *
+ * ```typescript
+ * jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+ * jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+ * ```
+ *
+ * Byte outputs are passed as Buffers in place of native pointers. Optional
+ * symbols start out missing; tests install them on `native` as needed.
+ */
+
+type Store = Map<string, Buffer>;
+
+interface MockTxn {
+  txn_id: number;
+  snapshot_ts: number;
+  writes: Map<string, Buffer | null>;
+  readOnly: boolean;
+  deadlineMs: number;
+}
+
+const hex = (key: Buffer) => key.toString('hex');
+
+class MockNative {
+  store: Store = new Map();
+  lsn = 0;
+  nextTxnId = 1;
+  openTxns = new Map<number, MockTxn>();
+  /** Return codes forced on the next calls of a binding, consumed in order */
+  private forced = new Map<string, number[]>();
+  [name: string]: any;
+
+  constructor() {
+    this.install();
+  }
+
+  /**
+   * Make the next call to `name` return `code` without doing anything
+   */
+  failNext(name: string, code: number): void {
+    const codes = this.forced.get(name) ?? [];
+    codes.push(code);
+    this.forced.set(name, codes);
+  }
+
+  reset(): void {
+    for (const key of Object.keys(this)) {
+      if (key.startsWith('sochdb_')) delete this[key];
+    }
+    this.store = new Map();
+    this.lsn = 0;
+    this.nextTxnId = 1;
+    this.openTxns = new Map();
+    this.forced = new Map();
+    this.install();
+  }
+
+  private forcedCode(name: string): number | undefined {
+    const codes = this.forced.get(name);
+    return codes && codes.length > 0 ? codes.shift() : undefined;
+  }
+
+  private txn(handle: { txn_id: number }): MockTxn {
+    const txn = this.openTxns.get(handle.txn_id);
+    if (!txn) throw new Error(`mock-native: unknown transaction ${handle.txn_id}`);
+    return txn;
+  }
+
+  private read(txn: MockTxn, key: string): Buffer | null {
+    if (txn.writes.has(key)) return txn.writes.get(key) ?? null;
+    return this.store.get(key) ?? null;
+  }
+
+  private visible(txn: MockTxn): Store {
+    const view = new Map(this.store);
+    for (const [key, value] of txn.writes) {
+      if (value === null) view.delete(key);
+      else view.set(key, value);
+    }
+    return view;
+  }
+
+  private begin(readOnly: boolean) {
+    const txn: MockTxn = {
+      txn_id: this.nextTxnId++,
+      snapshot_ts: this.lsn,
+      writes: new Map(),
+      readOnly,
+      deadlineMs: 0,
+    };
+    this.openTxns.set(txn.txn_id, txn);
+    return { txn_id: txn.txn_id, snapshot_ts: txn.snapshot_ts };
+  }
+
+  private write(name: string, handle: { txn_id: number }, key: Buffer, value: Buffer | null): number {
+    const forced = this.forcedCode(name);
+    if (forced !== undefined) return forced;
+    const txn = this.txn(handle);
+    if (txn.readOnly) return -4;
+    txn.writes.set(hex(key), value);
+    return 0;
+  }
+
+  private iterator(entries: Array<[Buffer, Buffer]>) {
+    return { entries, pos: 0 };
+  }
+
+  private install(): void {
+    this.sochdb_open = (_path: string) => ({ db: true });
+    this.sochdb_open_with_config = (_path: string, _config: unknown) => ({ db: true });
+    this.sochdb_close = () => undefined;
+    this.sochdb_begin_txn = () => this.begin(false);
+    this.sochdb_abort = (_db: unknown, handle: { txn_id: number }) => {
+      this.openTxns.delete(handle.txn_id);
+      return 0;
+    };
+    this.sochdb_commit = (_db: unknown, handle: { txn_id: number }) => {
+      const forced = this.forcedCode('sochdb_commit');
+      const txn = this.txn(handle);
+      this.openTxns.delete(handle.txn_id);
+      if (forced !== undefined) return { commit_ts: 0, error_code: forced };
+      for (const [key, value] of txn.writes) {
+        if (value === null) this.store.delete(key);
+        else this.store.set(key, value);
+      }
+      return { commit_ts: ++this.lsn, error_code: 0 };
+    };
+    this.sochdb_put = (_db: unknown, handle: { txn_id: number }, key: Buffer, klen: number, value: Buffer, vlen: number) =>
+      this.write('sochdb_put', handle, key.subarray(0, klen), Buffer.from(value.subarray(0, vlen)));
+    this.sochdb_delete = (_db: unknown, handle: { txn_id: number }, key: Buffer, klen: number) =>
+      this.write('sochdb_delete', handle, key.subarray(0, klen), null);
+    this.sochdb_put_path = (_db: unknown, handle: { txn_id: number }, path: string, value: Buffer, vlen: number) =>
+      this.write('sochdb_put_path', handle, Buffer.from(path), Buffer.from(value.subarray(0, vlen)));
+    this.sochdb_get = (_db: unknown, handle: { txn_id: number }, key: Buffer, klen: number, outPtr: any[], outLen: any[]) => {
+      const forced = this.forcedCode('sochdb_get');
+      if (forced !== undefined) return forced;
+      const value = this.read(this.txn(handle), hex(key.subarray(0, klen)));
+      if (value === null) return 1;
+      outPtr[0] = value;
+      outLen[0] = value.length;
+      return 0;
+    };
+    this.sochdb_get_path = (_db: unknown, handle: { txn_id: number }, path: string, outPtr: any[], outLen: any[]) =>
+      this.sochdb_get(_db, handle, Buffer.from(path), Buffer.byteLength(path), outPtr, outLen);
+    this.sochdb_scan_prefix = (_db: unknown, handle: { txn_id: number }, prefix: Buffer, plen: number) => {
+      const p = prefix.subarray(0, plen);
+      const entries = [...this.visible(this.txn(handle))]
+        .map(([key, value]): [Buffer, Buffer] => [Buffer.from(key, 'hex'), value])
+        .filter(([key]) => key.subarray(0, p.length).equals(p))
+        .sort(([a], [b]) => Buffer.compare(a, b));
+      return this.iterator(entries);
+    };
+    this.sochdb_iterator_next = (iter: any, keyPtr: any[], keyLen: any[], valPtr: any[], valLen: any[]) => {
+      const forced = this.forcedCode('sochdb_iterator_next');
+      if (forced !== undefined) return forced;
+      if (iter.pos >= iter.entries.length) return 1;
+      const [key, value] = iter.entries[iter.pos++];
+      keyPtr[0] = key;
+      keyLen[0] = key.length;
+      valPtr[0] = value;
+      valLen[0] = value.length;
+      return 0;
+    };
+    this.sochdb_iterator_close = () => undefined;
+    this.sochdb_free_bytes = () => undefined;
+  }
+
+  /**
+   * Install the optional read-only transaction symbol
+   */
+  enableReadOnlyTransactions(): void {
+    this.sochdb_begin_txn_readonly = () => this.begin(true);
+  }
+
+  /**
+   * Install the optional deadline symbol; operations are not actually timed,
+   * use `failNext(name, -7)` to simulate one that ran past its deadline
+   */
+  enableDeadlines(): void {
+    this.sochdb_txn_set_deadline = (_db: unknown, handle: { txn_id: number }, ms: number) => {
+      this.txn(handle).deadlineMs = Number(ms);
+      return 0;
+    };
+  }
+
+  /**
+   * Install commit acknowledgment levels; `settle` decides when (and whether)
+   * a durability wait completes, with 0 meaning the level was reached
+   */
+  enableAckLevels(settle: (done: (res: number) => void) => void = (done) => done(0)): void {
+    this.sochdb_commit_nowait = this.sochdb_commit;
+    const wait: any = () => 0;
+    wait.async = (_db: unknown, _commitTs: bigint, _level: number, callback: (err: unknown, res: number) => void) =>
+      settle((res) => callback(null, res));
+    this.sochdb_wait_durable = wait;
+  }
+
+  /**
+   * Install the optional ordered key codec, written independently of the
+   * SDK's JS encoders so the two can be checked against each other
+   */
+  enableKeyCodec(): void {
+    const ordered = (write: (view: DataView) => void, flip: (bytes: Uint8Array) => void): Buffer => {
+      const bytes = new Uint8Array(8);
+      write(new DataView(bytes.buffer));
+      flip(bytes);
+      return Buffer.from(bytes);
+    };
+    const flipSign = (bytes: Uint8Array) => { bytes[0] ^= 0x80; };
+    const f64 = (value: number) => ordered(
+      (view) => view.setFloat64(0, value),
+      (bytes) => (bytes[0] & 0x80 ? bytes.forEach((b, i) => { bytes[i] = ~b & 0xff; }) : flipSign(bytes))
+    );
+    const i64 = (value: bigint) => ordered((view) => view.setBigInt64(0, value), flipSign);
+    const unf64 = (key: Buffer): number => {
+      const bytes = Uint8Array.from(key);
+      if (bytes[0] & 0x80) bytes[0] ^= 0x80;
+      else bytes.forEach((b, i) => { bytes[i] = ~b & 0xff; });
+      return new DataView(bytes.buffer).getFloat64(0);
+    };
+    const uni64 = (key: Buffer): bigint => {
+      const bytes = Uint8Array.from(key);
+      bytes[0] ^= 0x80;
+      return new DataView(bytes.buffer).getBigInt64(0);
+    };
+    const terminated = (tag: number, data: Buffer) => {
+      const out = [tag];
+      for (const b of data) out.push(...(b === 0 ? [0, 0xff] : [b]));
+      return Buffer.from([...out, 0]);
+    };
+    const output = (out: Buffer, outPtr: any[], outLen: any[]) => {
+      outPtr[0] = out;
+      outLen[0] = out.length;
+      return 0;
+    };
+
+    this.sochdb_encode_u64_key = (value: bigint, out: Buffer) => {
+      ordered((view) => view.setBigUint64(0, BigInt(value)), () => undefined).copy(out);
+      return 0;
+    };
+    this.sochdb_encode_i64_key = (value: bigint, out: Buffer) => {
+      i64(BigInt(value)).copy(out);
+      return 0;
+    };
+    this.sochdb_encode_f64_key = (value: number, out: Buffer) => {
+      if (Number.isNaN(value)) return -1;
+      f64(value).copy(out);
+      return 0;
+    };
+    this.sochdb_encode_tuple_key = (spec: Buffer, len: number, outPtr: any[], outLen: any[]) => {
+      const parts = (JSON.parse(spec.subarray(0, len).toString()) as any[]).map((element) => {
+        if ('bytes' in element) return terminated(0x01, Buffer.from(element.bytes, 'base64'));
+        if ('string' in element) return terminated(0x02, Buffer.from(element.string));
+        if ('f64' in element) return Buffer.concat([Buffer.from([0x03]), f64(element.f64 === 0 ? 0 : element.f64), i64(0n)]);
+        const n = BigInt(element.i64);
+        const approx = Number(n);
+        return Buffer.concat([Buffer.from([0x03]), f64(approx), i64(n - BigInt(approx))]);
+      });
+      return output(Buffer.concat(parts), outPtr, outLen);
+    };
+    this.sochdb_decode_tuple_key = (key: Buffer, len: number, outPtr: any[], outLen: any[]) => {
+      const elements: unknown[] = [];
+      let pos = 0;
+      while (pos < len) {
+        const tag = key[pos++];
+        if (tag === 0x03) {
+          const approx = unf64(key.subarray(pos, pos + 8));
+          const remainder = uni64(key.subarray(pos + 8, pos + 16));
+          pos += 16;
+          const exact = remainder === 0n && (!Number.isInteger(approx) || Number.isSafeInteger(approx) || approx < -(2 ** 63) || approx >= 2 ** 63);
+          elements.push(exact ? { f64: approx } : { i64: (BigInt(approx) + remainder).toString() });
+          continue;
+        }
+        const data: number[] = [];
+        while (!(key[pos] === 0 && key[pos + 1] !== 0xff)) {
+          data.push(key[pos]);
+          pos += key[pos] === 0 ? 2 : 1;
+        }
+        pos++;
+        const bytes = Buffer.from(data);
+        elements.push(tag === 0x01 ? { bytes: bytes.toString('base64') } : { string: bytes.toString() });
+      }
+      return output(Buffer.from(JSON.stringify(elements)), outPtr, outLen);
+    };
+  }
+
+  /**
+   * Install the optional path escape/validate functions, written
+   * independently of the SDK's JS implementations
+   */
+  enablePathCodec(): void {
+    const output = (text: string, outPtr: any[], outLen: any[]) => {
+      outPtr[0] = Buffer.from(text);
+      outLen[0] = outPtr[0].length;
+    };
+    this.sochdb_escape_path_segment = (bytes: Buffer, len: number, binary: boolean, outPtr: any[], outLen: any[]) => {
+      const out: number[] = [];
+      for (const b of bytes.subarray(0, len)) {
+        const escape = b === 0x25 || b === 0x2f || b < 0x20 || b === 0x7f || (binary && (b === 0x20 || b > 0x7e));
+        if (escape) out.push(...Buffer.from('%' + b.toString(16).toUpperCase().padStart(2, '0')));
+        else out.push(b);
+      }
+      output(Buffer.from(out).toString('utf8'), outPtr, outLen);
+      return 0;
+    };
+    this.sochdb_validate_path = (path: string, outPtr: any[], outLen: any[]) => {
+      const segments = path.split('/');
+      const bad = path === '' || path[0] === '/' || segments.some((segment) =>
+        segment === '' || [...segment].some((ch) => ch.charCodeAt(0) < 0x20 || ch.charCodeAt(0) === 0x7f) ||
+        segment.split('%').slice(1).some((rest) => !/^[0-9a-fA-F]{2}/.test(rest)));
+      if (!bad) return 0;
+      output('rejected by the native library', outPtr, outLen);
+      return -1;
+    };
+  }
+
+  deadlineOf(txnId: number): number | undefined {
+    return this.openTxns.get(txnId)?.deadlineMs;
+  }
+}
+
+export const native = new MockNative();
+
+export const bindingsModule = {
+  NativeBindings: {
+    getInstance: () => native,
+  },
+};
+
+export const koffi = {
+  decode: (ptr: Buffer, _type: string, len: number) => ptr.subarray(0, len),
+  out: (type: unknown) => type,
+  pointer: (...args: unknown[]) => args[0],
+  opaque: () => ({}),
+  struct: (name: string) => name,
+  array: (type: unknown) => type,
+};
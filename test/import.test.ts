@@ -0,0 +1,90 @@
+/**
+ * Tests for JSONL import conflict policies
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+function writeExport(dir: string, entries: Record<string, string>): string {
+  const file = path.join(dir, 'data.jsonl');
+  const lines = Object.entries(entries).map(([key, value]) =>
+    JSON.stringify({ key: Buffer.from(key).toString('base64'), value: Buffer.from(value).toString('base64') })
+  );
+  fs.writeFileSync(file, lines.join('\n') + '\n');
+  return file;
+}
+
+/** Native put-with-policy over the mock's committed store */
+function enablePutWithPolicy(): void {
+  native.sochdb_put_with_policy = (db: unknown, txn: any, key: Buffer, klen: number, value: Buffer, vlen: number, policy: number) => {
+    const exists = native.store.has(key.subarray(0, klen).toString('hex'));
+    if (exists && policy === 1) return 2;
+    if (exists && policy === 3) return 3;
+    const res = native.sochdb_put(db, txn, key, klen, value, vlen);
+    return res < 0 ? res : exists ? 1 : 0;
+  };
+}
+
+describe('JSONL Import', () => {
+  let db: EmbeddedDatabase;
+  let dir: string;
+  let file: string;
+
+  beforeEach(async () => {
+    native.reset();
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-import-'));
+    file = writeExport(dir, { a: 'new-a', b: 'new-b' });
+    db = EmbeddedDatabase.open('import-db');
+    await db.put('a', 'old-a');
+  });
+
+  afterEach(() => {
+    db.close();
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  test('applies the native conflict policy per existing key', async () => {
+    enablePutWithPolicy();
+    const result = await db.importJsonl(file, { conflict: 'skip' });
+    expect(result).toMatchObject({ inserted: 1, skipped: 1, overwritten: 0 });
+    expect((await db.get('a'))?.toString()).toBe('old-a');
+    expect((await db.get('b'))?.toString()).toBe('new-b');
+  });
+
+  test('requires native support for named policies', async () => {
+    await expect(db.importJsonl(file, { conflict: 'overwrite' })).rejects.toThrow(
+      'Import conflict policies are not supported by the loaded SochDB native library'
+    );
+    expect(await db.get('b')).toBeNull();
+  });
+
+  test('rejects unknown policies', async () => {
+    enablePutWithPolicy();
+    await expect(db.importJsonl(file, { conflict: 'replace' as any })).rejects.toBeInstanceOf(DatabaseError);
+    expect(await db.get('b')).toBeNull();
+  });
+
+  test('rejects a batchSize that is not a positive integer', async () => {
+    enablePutWithPolicy();
+    for (const batchSize of [0, -1, 1.5, NaN]) {
+      await expect(db.importJsonl(file, { batchSize })).rejects.toThrow(
+        `batchSize must be a positive integer, got ${batchSize}`
+      );
+    }
+    expect(await db.get('b')).toBeNull();
+  });
+
+  test('a dry run with the fail policy reports conflicts instead of throwing', async () => {
+    const result = await db.importJsonl(file, { conflict: 'fail', dryRun: true });
+    expect(result).toMatchObject({ inserted: 1, conflicts: 1, dryRun: true });
+    expect(result.conflictKeys.map(String)).toEqual(['a']);
+    expect(await db.get('b')).toBeNull();
+  });
+});
@@ -0,0 +1,56 @@
+/**
+ * Tests for per-read I/O profiles
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase, ReadOptions } from '../src/embedded/database';
+import { Profiled } from '../src/embedded/io-profile';
+import { native } from './helpers/mock-native';
+
+describe('I/O Profiles', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(async () => {
+    native.reset();
+    native.sochdb_txn_io_stats = () => ({
+      blocks_read: 2, cache_hits: 1, cache_misses: 1, bytes_from_cache: 10, bytes_from_disk: 20,
+    });
+    db = EmbeddedDatabase.open('profile-db');
+    await db.put('k', 'v');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('the return type follows the profile option', async () => {
+    const plain: Buffer | null = await db.get('k');
+    expect(plain?.toString()).toBe('v');
+
+    const profiled: Profiled<Buffer | null> = await db.get('k', { profile: true });
+    expect(profiled.value?.toString()).toBe('v');
+    expect(profiled.profile.cacheMisses).toBe(1);
+
+    // Only known at runtime: callers have to handle both shapes
+    const options: ReadOptions = { profile: Math.random() < 2 };
+    const either = await db.getPath('k', options);
+    expect(either).toHaveProperty('profile');
+  });
+
+  test('scans reject profile instead of ignoring it', async () => {
+    const scan = async () => {
+      for await (const _ of db.scanPrefix('', { profile: true } as any)) {
+        // drain
+      }
+    };
+    await expect(scan()).rejects.toThrow('Scans do not support `profile`');
+
+    const profiles: unknown[] = [];
+    for await (const _ of db.scanPrefix('', { onProfile: (p) => profiles.push(p) })) {
+      // drain
+    }
+    expect(profiles).toHaveLength(1);
+  });
+});
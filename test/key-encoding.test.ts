@@ -0,0 +1,125 @@
+/**
+ * Tests for ordered key encoders
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+
+import {
+  encodeU64Key,
+  decodeU64Key,
+  encodeI64Key,
+  decodeI64Key,
+  encodeF64Key,
+  decodeF64Key,
+  encodeTupleKey,
+  decodeTupleKey,
+  useNativeKeyCodec,
+  TupleElement,
+} from '../src/embedded/key-encoding';
+import { native } from './helpers/mock-native';
+
+function isSorted(keys: Buffer[]): boolean {
+  for (let i = 1; i < keys.length; i++) {
+    if (Buffer.compare(keys[i - 1], keys[i]) >= 0) return false;
+  }
+  return true;
+}
+
+describe('Ordered Key Encoding', () => {
+  test('u64 keys sort numerically', () => {
+    const values = [0n, 1n, 255n, 256n, 2n ** 40n, 2n ** 64n - 1n];
+    expect(isSorted(values.map(encodeU64Key))).toBe(true);
+    expect(decodeU64Key(encodeU64Key(2n ** 40n))).toBe(2n ** 40n);
+    expect(() => encodeU64Key(-1)).toThrow();
+  });
+
+  test('i64 keys sort numerically across zero', () => {
+    const values = [-(2n ** 63n), -1000n, -1n, 0n, 1n, 1000n, 2n ** 63n - 1n];
+    expect(isSorted(values.map(encodeI64Key))).toBe(true);
+    expect(decodeI64Key(encodeI64Key(-1000))).toBe(-1000n);
+  });
+
+  test('f64 keys sort numerically', () => {
+    const values = [-Infinity, -1e10, -1.5, -0.25, 0, 0.25, 1.5, 1e10, Infinity];
+    expect(isSorted(values.map(encodeF64Key))).toBe(true);
+    expect(decodeF64Key(encodeF64Key(-1.5))).toBe(-1.5);
+    expect(() => encodeF64Key(NaN)).toThrow();
+  });
+
+  test('tuple keys sort element-wise and round-trip', () => {
+    const keys = [
+      encodeTupleKey(['events']),
+      encodeTupleKey(['events', 2]),
+      encodeTupleKey(['events', 2, 'click']),
+      encodeTupleKey(['events', 10]),
+      encodeTupleKey(['eventsz']),
+    ];
+    expect(isSorted(keys)).toBe(true);
+
+    const tuple = ['a\u0000b', -7, 1.25, Buffer.from([0, 1, 2])];
+    const decoded = decodeTupleKey(encodeTupleKey(tuple));
+    expect(decoded[0]).toBe('a\u0000b');
+    expect(decoded[1]).toBe(-7);
+    expect(decoded[2]).toBe(1.25);
+    expect(decoded[3]).toEqual(Buffer.from([0, 1, 2]));
+  });
+
+  test('tuple numbers sort by value across integers, doubles and bigints', () => {
+    const values = [-Infinity, -(2n ** 62n), -3, -1.5, 0, 0.5, 1, 1.5, 2n, 2.5, 3, 2 ** 53, 2n ** 53n + 1n, 2 ** 53 + 2, 2n ** 63n - 1n, Infinity];
+    const keys = values.map((v) => encodeTupleKey(['n', v, 'tail']));
+    expect(isSorted(keys)).toBe(true);
+    expect(Buffer.compare(encodeTupleKey([1.5]), encodeTupleKey([2]))).toBeLessThan(0);
+
+    expect(decodeTupleKey(encodeTupleKey([2n ** 53n + 1n]))).toEqual([2n ** 53n + 1n]);
+    expect(decodeTupleKey(encodeTupleKey([2n ** 63n - 1n]))).toEqual([2n ** 63n - 1n]);
+    expect(decodeTupleKey(encodeTupleKey([7n, 1.5, -0]))).toEqual([7, 1.5, 0]);
+    expect(() => encodeTupleKey([NaN])).toThrow();
+  });
+
+  describe('against the native codec', () => {
+    const tuples: TupleElement[][] = [
+      [],
+      ['events', 1700000000, 'click'],
+      ['a\u0000b', Buffer.from([0, 0xff, 0]), ''],
+      [-0, -1.5, 0.25, Number.MAX_VALUE, -Number.MIN_VALUE],
+      [2n ** 53n + 1n, -(2n ** 63n), 2n ** 63n - 1n, 2 ** 60, -7n],
+    ];
+    const u64s = [0n, 1n, 2n ** 40n, 2n ** 64n - 1n];
+    const i64s = [-(2n ** 63n), -1n, 0n, 2n ** 63n - 1n];
+    const f64s = [-Infinity, -1.5, -0, 0, Number.MIN_VALUE, 1e300, Infinity];
+
+    afterEach(() => {
+      useNativeKeyCodec(null);
+      native.reset();
+    });
+
+    test('the JS encoders produce the same bytes', () => {
+      const js = {
+        tuples: tuples.map(encodeTupleKey),
+        u64: u64s.map(encodeU64Key),
+        i64: i64s.map(encodeI64Key),
+        f64: f64s.map(encodeF64Key),
+        decoded: tuples.map((t) => decodeTupleKey(encodeTupleKey(t))),
+      };
+
+      native.enableKeyCodec();
+      useNativeKeyCodec(native as any);
+      expect(tuples.map(encodeTupleKey)).toEqual(js.tuples);
+      expect(u64s.map(encodeU64Key)).toEqual(js.u64);
+      expect(i64s.map(encodeI64Key)).toEqual(js.i64);
+      expect(f64s.map(encodeF64Key)).toEqual(js.f64);
+      expect(js.tuples.map(decodeTupleKey)).toEqual(js.decoded);
+    });
+
+    test('is only used once bindings are passed in', () => {
+      native.enableKeyCodec();
+      const encode = jest.spyOn(native, 'sochdb_encode_u64_key');
+      encodeU64Key(1n);
+      expect(encode).not.toHaveBeenCalled();
+
+      useNativeKeyCodec(native as any);
+      encodeU64Key(1n);
+      expect(encode).toHaveBeenCalledTimes(1);
+    });
+  });
+});
@@ -0,0 +1,72 @@
+/**
+ * Tests for typed key prefixes
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { KeyPrefixRegistry } from '../src/embedded/key-prefix';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Key Prefix Registry', () => {
+  test('should build keys from registered prefixes', () => {
+    const registry = new KeyPrefixRegistry();
+    registry.add('user', 'u/');
+    registry.add('order', 'o/');
+
+    expect(registry.keys.user(42).toString()).toBe('u/42');
+    expect(registry.keys.order(42, 7).toString()).toBe('o/42/7');
+  });
+
+  test('should reject overlapping prefixes', () => {
+    const registry = new KeyPrefixRegistry();
+    registry.validate('user', 'u/');
+    registry.add('user', 'u/');
+
+    expect(() => registry.validate('user_admin', 'u/admin/')).toThrow(DatabaseError);
+    expect(() => registry.validate('u', 'u')).toThrow(DatabaseError);
+    expect(() => registry.validate('user', 'x/')).toThrow(DatabaseError);
+    expect(() => registry.validate('bad-name', 'b/')).toThrow(DatabaseError);
+  });
+
+  test('should route and parse keys', () => {
+    const registry = new KeyPrefixRegistry();
+    const user = registry.add('user', 'u/');
+
+    const key = registry.keys.user('alice');
+    expect(registry.route(key)?.name).toBe('user');
+    expect(user.parse(key)).toBe('alice');
+    expect(registry.route(Buffer.from('x/1'))).toBeUndefined();
+    expect(() => user.key('')).toThrow(DatabaseError);
+  });
+});
+
+describe('definePrefix', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    native.reset();
+    db = EmbeddedDatabase.open('prefix-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('requires the native validator', () => {
+    expect(() => db.definePrefix('user', 'u/')).toThrow(
+      'Key prefixes are not supported by the loaded SochDB native library'
+    );
+    expect(db.prefix('user')).toBeUndefined();
+  });
+
+  test('registers prefixes the native engine accepts', () => {
+    native.sochdb_register_prefix = (_db: unknown, _name: string, prefix: string) => (prefix === 'bad/' ? 1 : 0);
+    db.definePrefix('user', 'u/');
+    expect(db.keys.user(42).toString()).toBe('u/42');
+    expect(() => db.definePrefix('other', 'bad/')).toThrow('Native engine rejected prefix');
+    expect(db.prefix('other')).toBeUndefined();
+  });
+});
@@ -0,0 +1,119 @@
+/**
+ * Tests for keyspaces
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { toNativeKeyspaceConfig } from '../src/embedded/keyspace';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Keyspaces', () => {
+  let db: EmbeddedDatabase;
+  let configured: Array<[string, Record<string, any>]>;
+
+  beforeEach(() => {
+    native.reset();
+    configured = [];
+    db = EmbeddedDatabase.open('keyspace-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  function enableKeyspaceConfig(): void {
+    native.sochdb_keyspace_configure = (_db: unknown, name: string, config: Record<string, any>) => {
+      configured.push([name, config]);
+      return 0;
+    };
+  }
+
+  test('encodes only the settings that were given', () => {
+    expect(toNativeKeyspaceConfig({ compression: 'zstd', cachePriority: 'low' })).toEqual({
+      compression: 2,
+      compression_set: true,
+      cache_priority: 0,
+      cache_priority_set: true,
+      bloom_bits_per_key: 0,
+      bloom_bits_per_key_set: false,
+    });
+  });
+
+  test('configures each keyspace natively with its own settings', () => {
+    enableKeyspaceConfig();
+    db.keyspace('blobs', { compression: 'zstd', cachePriority: 'low', bloomFilterBitsPerKey: 0 });
+    db.keyspace('index', { compression: 'none', cachePriority: 'high', bloomFilterBitsPerKey: 10 });
+
+    expect(configured.map(([name]) => name)).toEqual(['blobs', 'index']);
+    expect(configured[0][1]).toMatchObject({ compression: 2, cache_priority: 0, bloom_bits_per_key_set: true });
+    expect(configured[1][1]).toMatchObject({ compression: 0, cache_priority: 2, bloom_bits_per_key: 10 });
+  });
+
+  test('keyspaces without options need no native support', () => {
+    expect(() => db.keyspace('plain')).not.toThrow();
+    expect(() => db.keyspace('tuned', { compression: 'lz4' })).toThrow(
+      'Per-keyspace settings are not supported by the loaded SochDB native library'
+    );
+    expect(() => db.keyspace('a/b')).toThrow(DatabaseError);
+  });
+
+  test('keys are isolated per keyspace and scans strip the keyspace prefix', async () => {
+    const blobs = db.keyspace('blobs');
+    const index = db.keyspace('index');
+    await blobs.put('k1', 'blob');
+    await index.put('k1', 'entry');
+
+    expect((await blobs.get('k1'))?.toString()).toBe('blob');
+    expect((await index.get('k1'))?.toString()).toBe('entry');
+
+    const scanned: string[] = [];
+    for await (const [key, value] of blobs.scanPrefix('')) {
+      scanned.push(`${key}=${value}`);
+    }
+    expect(scanned).toEqual(['k1=blob']);
+
+    await blobs.delete('k1');
+    expect(await blobs.get('k1')).toBeNull();
+    expect((await index.get('k1'))?.toString()).toBe('entry');
+  });
+
+  test('transaction views of several keyspaces commit together', async () => {
+    const blobs = db.keyspace('blobs');
+    const index = db.keyspace('index');
+
+    const txn = db.transaction();
+    await txn.keyspace(blobs).put('img:1', 'data');
+    await txn.keyspace(index).put('by_name:cat', 'img:1');
+    expect(await blobs.get('img:1')).toBeNull();
+    await txn.commit();
+
+    expect((await blobs.get('img:1'))?.toString()).toBe('data');
+    expect((await index.get('by_name:cat'))?.toString()).toBe('img:1');
+  });
+
+  test('write batches span keyspaces atomically and can be retried after a failure', async () => {
+    const blobs = db.keyspace('blobs');
+    const index = db.keyspace('index');
+    await db.put('pending:img:1', 'yes');
+
+    const batch = db.batch()
+      .put('img:1', 'data', { keyspace: blobs })
+      .put('by_name:cat', 'img:1', { keyspace: index })
+      .delete('pending:img:1');
+    expect(batch.length).toBe(3);
+
+    native.failNext('sochdb_put', -1);
+    await expect(batch.write()).rejects.toThrow();
+    expect(await index.get('by_name:cat')).toBeNull();
+    expect((await db.get('pending:img:1'))?.toString()).toBe('yes');
+
+    await batch.write();
+    expect((await blobs.get('img:1'))?.toString()).toBe('data');
+    expect((await index.get('by_name:cat'))?.toString()).toBe('img:1');
+    expect(await db.get('pending:img:1')).toBeNull();
+    await expect(batch.write()).rejects.toThrow('Write batch has already been written');
+  });
+});
@@ -0,0 +1,43 @@
+/**
+ * Tests for key/value size limits
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { checkEntrySize, resolveSizeLimits, DEFAULT_MAX_KEY_BYTES } from '../src/embedded/limits';
+import { KeyTooLargeError, ValueTooLargeError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Size Limits', () => {
+  test('applies defaults and rejects invalid limits', () => {
+    expect(resolveSizeLimits().maxKeyBytes).toBe(DEFAULT_MAX_KEY_BYTES);
+    expect(resolveSizeLimits({ maxValueBytes: 10 }).maxValueBytes).toBe(10);
+    expect(() => resolveSizeLimits({ maxKeyBytes: 0 })).toThrow();
+    expect(() => resolveSizeLimits({ maxValueBytes: 1.5 })).toThrow();
+  });
+
+  test('reports the limit and actual size', () => {
+    const limits = resolveSizeLimits({ maxKeyBytes: 4, maxValueBytes: 8 });
+    expect(() => checkEntrySize(Buffer.from('key'), Buffer.alloc(8), limits)).not.toThrow();
+
+    const oversized = () => checkEntrySize(Buffer.from('key'), Buffer.alloc(9), limits);
+    expect(oversized).toThrow(ValueTooLargeError);
+    expect(oversized).toThrow(expect.objectContaining({ size: 9, limit: 8 }));
+
+    expect(() => checkEntrySize(Buffer.from('long key'), Buffer.alloc(0), limits)).toThrow(KeyTooLargeError);
+  });
+
+  test('oversized writes reject before reaching the engine', async () => {
+    native.reset();
+    const db = EmbeddedDatabase.open('limits-db', { maxValueBytes: 8 });
+    try {
+      await expect(db.put('key', Buffer.alloc(9))).rejects.toThrow(ValueTooLargeError);
+      await expect(db.put('key', Buffer.alloc(9))).rejects.toMatchObject({ size: 9, limit: 8 });
+      expect(await db.get('key')).toBeNull();
+    } finally {
+      db.close();
+    }
+  });
+});
@@ -0,0 +1,95 @@
+/**
+ * Tests for reporting native memory to V8 as external memory
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('External memory reporting', () => {
+  const adjust = jest.fn((delta: number) => delta);
+  let addonDir: string;
+  let dlopen: jest.SpyInstance;
+  let totalBytes: number;
+
+  beforeAll(() => {
+    addonDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sochdb-addon-'));
+    const addonPath = path.join(addonDir, 'sochdb_external_memory.node');
+    fs.writeFileSync(addonPath, '');
+    process.env.SOCHDB_EXTERNAL_MEMORY_ADDON = addonPath;
+    dlopen = jest.spyOn(process, 'dlopen').mockImplementation((module: any) => {
+      module.exports.adjust = adjust;
+    });
+  });
+
+  afterAll(() => {
+    dlopen.mockRestore();
+    delete process.env.SOCHDB_EXTERNAL_MEMORY_ADDON;
+    fs.rmSync(addonDir, { recursive: true, force: true });
+  });
+
+  beforeEach(() => {
+    jest.useFakeTimers();
+    native.reset();
+    adjust.mockClear();
+    totalBytes = 1000;
+    native.sochdb_memory_usage = () => ({
+      memtable_bytes: totalBytes,
+      block_cache_bytes: 0,
+      pinned_value_bytes: 0,
+      other_bytes: 0,
+    });
+  });
+
+  afterEach(() => {
+    jest.useRealTimers();
+  });
+
+  test('reports growth and shrinkage as deltas and gives it all back on close', () => {
+    const db = EmbeddedDatabase.open('memory-db', { externalMemoryIntervalMs: 100 });
+    expect(adjust.mock.calls).toEqual([[1000]]);
+
+    totalBytes = 1500;
+    jest.advanceTimersByTime(100);
+    totalBytes = 1200;
+    jest.advanceTimersByTime(100);
+    jest.advanceTimersByTime(100);
+    expect(adjust.mock.calls).toEqual([[1000], [500], [-300]]);
+
+    db.close();
+    expect(adjust).toHaveBeenLastCalledWith(-1200);
+    jest.advanceTimersByTime(1000);
+    expect(adjust).toHaveBeenCalledTimes(4);
+  });
+
+  test('externalMemoryIntervalMs: 0 disables reporting', () => {
+    const db = EmbeddedDatabase.open('memory-db', { externalMemoryIntervalMs: 0 });
+    jest.advanceTimersByTime(5000);
+    expect(adjust).not.toHaveBeenCalled();
+    db.close();
+  });
+
+  test('is skipped when the native library has no memory accounting', () => {
+    delete native.sochdb_memory_usage;
+    const db = EmbeddedDatabase.open('memory-db');
+    jest.advanceTimersByTime(5000);
+    expect(adjust).not.toHaveBeenCalled();
+    db.close();
+  });
+
+  test('rejects an invalid interval before opening a handle', () => {
+    const open = jest.spyOn(native, 'sochdb_open');
+    expect(() => EmbeddedDatabase.open('memory-db', { externalMemoryIntervalMs: -1 }))
+      .toThrow(DatabaseError);
+    expect(() => EmbeddedDatabase.open('memory-db', { externalMemoryIntervalMs: NaN }))
+      .toThrow('externalMemoryIntervalMs must be a non-negative number, got NaN');
+    expect(open).not.toHaveBeenCalled();
+    open.mockRestore();
+  });
+});
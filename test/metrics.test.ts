@@ -0,0 +1,104 @@
+/**
+ * Tests for engine metrics
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+/** Native function that writes `text` to its out-pointer */
+function returning(text: string) {
+  return (_db: unknown, outPtr: any[], outLen: any[]) => {
+    outPtr[0] = Buffer.from(text);
+    outLen[0] = outPtr[0].length;
+    return 0;
+  };
+}
+
+describe('Engine metrics', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    native.reset();
+    db = EmbeddedDatabase.open('metrics-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('metrics() converts the native histogram JSON', async () => {
+    native.sochdb_metrics_json = returning(JSON.stringify({
+      uptime_secs: 12,
+      operations: {
+        get: { count: 10, errors: 1, p50_us: 3, p90_us: 8, p99_us: 20, p999_us: 40, max_us: 55, throughput_per_sec: 2.5 },
+        commit: { count: 4 },
+      },
+    }));
+
+    expect(await db.metrics()).toEqual({
+      uptimeSecs: 12,
+      operations: {
+        get: { count: 10, errors: 1, p50Us: 3, p90Us: 8, p99Us: 20, p999Us: 40, maxUs: 55, throughputPerSec: 2.5 },
+        commit: { count: 4, errors: 0, p50Us: 0, p90Us: 0, p99Us: 0, p999Us: 0, maxUs: 0, throughputPerSec: 0 },
+      },
+    });
+  });
+
+  test('metricsPrometheus() returns the native exposition text', async () => {
+    const text = 'sochdb_op_latency_us{op="get",quantile="0.5"} 3\n';
+    native.sochdb_metrics_prometheus = returning(text);
+    expect(await db.metricsPrometheus()).toBe(text);
+  });
+
+  test('fails clearly when the native library has no metrics', async () => {
+    await expect(db.metrics()).rejects.toThrow(
+      'metrics() is not supported by the loaded SochDB native library'
+    );
+    native.sochdb_metrics_prometheus = () => -1;
+    await expect(db.metricsPrometheus()).rejects.toThrow('metricsPrometheus() failed (Code -1)');
+  });
+});
+
+describe('Write stall diagnostics', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    native.reset();
+    db = EmbeddedDatabase.open('metrics-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('decodes the reason bitmask and 64-bit durations', () => {
+    native.sochdb_stall_info = () => ({
+      stalled: true,
+      reasons: 1 | 4,
+      stalled_for_ms: 250n,
+      total_stalls: 3n,
+      total_stall_ms: 1200n,
+    });
+    expect(db.stallInfo()).toEqual({
+      stalled: true,
+      reasons: ['memtable_full', 'compaction_debt'],
+      stalledForMs: 250,
+      totalStalls: 3,
+      totalStallMs: 1200,
+    });
+  });
+
+  test('reports no reasons when writes flow', () => {
+    native.sochdb_stall_info = () => ({ stalled: false, reasons: 0, stalled_for_ms: 0n, total_stalls: 0n, total_stall_ms: 0n });
+    expect(db.stallInfo()).toMatchObject({ stalled: false, reasons: [] });
+  });
+
+  test('fails clearly when the native library has no stall diagnostics', () => {
+    expect(() => db.stallInfo()).toThrow(
+      'Stall diagnostics are not supported by the loaded SochDB native library'
+    );
+  });
+});
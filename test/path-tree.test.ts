@@ -0,0 +1,109 @@
+/**
+ * Tests for path-tree listings and summaries
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+function output(value: unknown, outPtr: any[], outLen: any[]): number {
+  outPtr[0] = Buffer.from(JSON.stringify(value));
+  outLen[0] = outPtr[0].length;
+  return 0;
+}
+
+describe('Path trees', () => {
+  let db: EmbeddedDatabase;
+  let listedIn: number[];
+  let summaryDepths: number[];
+
+  /** Listing over committed paths, recording the transaction it ran in */
+  function enablePathTree(): void {
+    native.sochdb_list_path = (_db: unknown, txn: { txn_id: number }, path: string, outPtr: any[], outLen: any[]) => {
+      listedIn.push(txn.txn_id);
+      const base = path.endsWith('/') ? path : `${path}/`;
+      const children = new Map<string, { name: string; has_value: boolean; has_children: boolean; value_len: number }>();
+      for (const [hexKey, value] of native.store) {
+        const key = Buffer.from(hexKey, 'hex').toString();
+        if (!key.startsWith(base)) continue;
+        const [name, ...rest] = key.slice(base.length).split('/');
+        const child = children.get(name) ?? { name, has_value: false, has_children: false, value_len: 0 };
+        if (rest.length > 0) child.has_children = true;
+        else Object.assign(child, { has_value: true, value_len: value.length });
+        children.set(name, child);
+      }
+      return output([...children.values()], outPtr, outLen);
+    };
+    native.sochdb_tree_summary = (_db: unknown, _txn: unknown, _path: string, maxDepth: number, outPtr: any[], outLen: any[]) => {
+      summaryDepths.push(maxDepth);
+      return output({ documents: 3, directories: 1, total_bytes: 42, depth: 2 }, outPtr, outLen);
+    };
+  }
+
+  beforeEach(async () => {
+    native.reset();
+    listedIn = [];
+    summaryDepths = [];
+    db = EmbeddedDatabase.open('tree-db');
+    await db.putPath('users/alice', 'a');
+    await db.putPath('users/bob/profile', 'bob!');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('lists direct children with their full paths', async () => {
+    enablePathTree();
+    const entries = await db.listPath('users');
+    expect(entries).toEqual([
+      { name: 'alice', path: 'users/alice', hasValue: true, hasChildren: false, size: 1 },
+      { name: 'bob', path: 'users/bob', hasValue: false, hasChildren: true, size: 0 },
+    ]);
+    expect((await db.listPath('users/')).map((e) => e.path)).toEqual(['users/alice', 'users/bob']);
+  });
+
+  test('summarizes a subtree with an optional depth limit', async () => {
+    enablePathTree();
+    expect(await db.treeSummary('users', { maxDepth: 1 })).toEqual({
+      path: 'users',
+      documents: 3,
+      directories: 1,
+      totalBytes: 42,
+      depth: 2,
+    });
+    await db.treeSummary('users');
+    expect(summaryDepths).toEqual([1, 0]);
+  });
+
+  test('a snapshot listing runs in the same transaction as reads through the snapshot', async () => {
+    enablePathTree();
+    const readIn: number[] = [];
+    const getPath = native.sochdb_get_path;
+    native.sochdb_get_path = (h: unknown, txn: { txn_id: number }, ...rest: any[]) => {
+      readIn.push(txn.txn_id);
+      return getPath(h, txn, ...rest);
+    };
+
+    const snap = db.snapshot();
+    try {
+      for (const entry of await db.listPath('users', { snapshot: snap })) {
+        if (entry.hasValue) await snap.getPath(entry.path);
+      }
+      await snap.listPath('users');
+    } finally {
+      snap.release();
+    }
+    expect(readIn).toHaveLength(1);
+    expect(listedIn).toEqual([readIn[0], readIn[0]]);
+  });
+
+  test('fails clearly when the native library has no listings', async () => {
+    await expect(db.listPath('users')).rejects.toThrow(
+      'Path listing is not supported by the loaded SochDB native library'
+    );
+    await expect(db.treeSummary('users')).rejects.toThrow('Tree summary is not supported');
+  });
+});
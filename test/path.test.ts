@@ -0,0 +1,128 @@
+/**
+ * Tests for path segment escaping
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+import {
+  PathBuilder,
+  escapeSegment,
+  unescapeSegment,
+  unescapeSegmentBytes,
+  useNativePathCodec,
+  validatePath,
+} from '../src/embedded/path';
+import { InvalidPathError } from '../src/errors';
+
+describe('Path Segments', () => {
+  test('escapes separators and round-trips', () => {
+    expect(escapeSegment('a/b')).toBe('a%2Fb');
+    expect(escapeSegment('50%')).toBe('50%25');
+    expect(escapeSegment('héllo')).toBe('héllo');
+    expect(unescapeSegment('a%2Fb')).toBe('a/b');
+    expect(unescapeSegment(escapeSegment('tab\there'))).toBe('tab\there');
+  });
+
+  test('binary segments are byte-exact', () => {
+    const bytes = Buffer.from([0x00, 0x2f, 0x41, 0xff]);
+    expect(escapeSegment(bytes)).toBe('%00%2FA%FF');
+    expect(unescapeSegmentBytes(escapeSegment(bytes)).equals(bytes)).toBe(true);
+  });
+
+  test('PathBuilder keeps one tree level per segment', () => {
+    const path = PathBuilder.of('users', 'a/b').child('files', 42);
+    expect(path.toString()).toBe('users/a%2Fb/files/42');
+    expect(PathBuilder.parse(path.toString()).segments).toEqual(['users', 'a/b', 'files', '42']);
+    expect(path.parent?.toString()).toBe('users/a%2Fb/files');
+    expect(PathBuilder.of('users').parent).toBeNull();
+  });
+
+  test('validatePath rejects ambiguous paths', () => {
+    expect(() => validatePath('users/alice/email')).not.toThrow();
+    expect(() => validatePath('')).toThrow(InvalidPathError);
+    expect(() => validatePath('users//alice')).toThrow(InvalidPathError);
+    expect(() => validatePath('users/alice/')).toThrow(InvalidPathError);
+    expect(() => validatePath('discount/50%')).toThrow('a literal "%" must be written as %25');
+    expect(() => validatePath('/users/alice')).toThrow('drop the leading "/"');
+    expect(() => validatePath('a\u0000b')).toThrow(InvalidPathError);
+    expect(() => escapeSegment('')).toThrow(InvalidPathError);
+  });
+});
+
+describe('Path Listings', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    native.reset();
+    native.sochdb_list_path = (_db: unknown, _txn: unknown, _path: string, outPtr: any[], outLen: any[]) => {
+      const json = Buffer.from(JSON.stringify([{ name: 'b', has_value: true, has_children: false, value_len: 3 }]));
+      outPtr[0] = json;
+      outLen[0] = json.length;
+      return 0;
+    };
+    db = EmbeddedDatabase.open('path-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('putPath rejects paths that were accepted before validation', async () => {
+    await expect(db.putPath('/users/alice', 'x')).rejects.toBeInstanceOf(InvalidPathError);
+    await expect(db.putPath('discount/50%', 'x')).rejects.toBeInstanceOf(InvalidPathError);
+    await db.putPath(PathBuilder.of('discount', '50%').toString(), 'x');
+    expect(native.store.has(Buffer.from('discount/50%25').toString('hex'))).toBe(true);
+  });
+
+  test('component order requires the native ordered listing', async () => {
+    const txn = db.transaction();
+    expect(await txn.listPath('a')).toEqual([{ name: 'b', path: 'a/b', hasValue: true, hasChildren: false, size: 3 }]);
+    await expect(txn.listPath('a', 'components')).rejects.toThrow(
+      'Ordered path listing is not supported by the loaded SochDB native library'
+    );
+    await txn.abort();
+  });
+
+  describe('against the native path functions', () => {
+    const segments = ['users', 'a/b', '50%', 'héllo', 'tab\there', 42, 7n, Buffer.from([0x00, 0x2f, 0x41, 0x20, 0xff])];
+    const paths = ['users/alice', 'a%2Fb/c', '', '/users', 'a//b', 'a/b\u0001', 'a/50%', 'a/%zz', 'a/%2'];
+
+    afterEach(() => {
+      useNativePathCodec(null);
+      native.reset();
+    });
+
+    const validity = (path: string) => {
+      try {
+        validatePath(path);
+        return true;
+      } catch (error) {
+        expect(error).toBeInstanceOf(InvalidPathError);
+        return false;
+      }
+    };
+
+    test('the JS implementations agree with the native ones', () => {
+      const escaped = segments.map(escapeSegment);
+      const valid = paths.map(validity);
+
+      native.enablePathCodec();
+      useNativePathCodec(native as any);
+      expect(segments.map(escapeSegment)).toEqual(escaped);
+      expect(paths.map(validity)).toEqual(valid);
+      expect(PathBuilder.of('users', 'a/b').toString()).toBe('users/a%2Fb');
+    });
+
+    test('opening a database switches to the native functions', () => {
+      native.enablePathCodec();
+      const validate = jest.spyOn(native, 'sochdb_validate_path');
+      const db = EmbeddedDatabase.open('path-native-db');
+      expect(() => validatePath('a//b')).toThrow('rejected by the native library');
+      expect(validate).toHaveBeenCalledWith('a//b', expect.anything(), expect.anything());
+      db.close();
+    });
+  });
+});
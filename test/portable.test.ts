@@ -0,0 +1,29 @@
+/**
+ * Tests for backend selection
+ */
+
+import { selectBackend } from '../src/portable';
+import { DatabaseError } from '../src/errors';
+
+describe('Backend Selection', () => {
+  test('SOCHDB_BACKEND forces a backend', () => {
+    expect(selectBackend({ SOCHDB_BACKEND: 'portable' })).toBe('portable');
+    expect(selectBackend({ SOCHDB_BACKEND: 'native' })).toBe('native');
+  });
+
+  test('an invalid SOCHDB_BACKEND is rejected when selecting, not when loading', () => {
+    expect(() => selectBackend({ SOCHDB_BACKEND: 'wasm' })).toThrow(DatabaseError);
+    expect(() => selectBackend({ SOCHDB_BACKEND: 'wasm' })).toThrow("Invalid SOCHDB_BACKEND 'wasm'");
+
+    const previous = process.env.SOCHDB_BACKEND;
+    process.env.SOCHDB_BACKEND = 'wasm';
+    try {
+      jest.isolateModules(() => {
+        expect(() => require('../src/portable')).not.toThrow();
+      });
+    } finally {
+      if (previous === undefined) delete process.env.SOCHDB_BACKEND;
+      else process.env.SOCHDB_BACKEND = previous;
+    }
+  });
+});
@@ -0,0 +1,47 @@
+/**
+ * Tests for projected scans
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+async function collect<T>(iter: AsyncIterable<T>): Promise<T[]> {
+  const out: T[] = [];
+  for await (const item of iter) out.push(item);
+  return out;
+}
+
+describe('Projected Scans', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(async () => {
+    native.reset();
+    db = EmbeddedDatabase.open('projection-db');
+    await db.put('docs/1', JSON.stringify({ title: 'one' }));
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('decodes the projection computed by the engine', async () => {
+    const length = Buffer.alloc(8);
+    length.writeBigUInt64LE(42n);
+    native.sochdb_scan_prefix_projected = () => ({ entries: [[Buffer.from('docs/1'), length]], pos: 0 });
+
+    const txn = db.transaction();
+    expect(await collect(txn.scanProjected('docs/', 'length'))).toEqual([{ key: Buffer.from('docs/1'), length: 42 }]);
+    await txn.abort();
+  });
+
+  test('requires native support instead of projecting in JS', async () => {
+    const txn = db.transaction();
+    await expect(collect(txn.scanProjected('docs/', { jsonField: 'title' }))).rejects.toThrow(
+      'Projected scans are not supported by the loaded SochDB native library'
+    );
+    await txn.abort();
+  });
+});
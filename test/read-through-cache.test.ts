@@ -0,0 +1,97 @@
+/**
+ * Tests for the read-through cache
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { ManualClock } from '../src/embedded/clock';
+import { ReadThroughCache } from '../src/read-through-cache';
+import { native } from './helpers/mock-native';
+
+/** Let a background refresh run to completion */
+const settle = () => new Promise((resolve) => setImmediate(resolve));
+
+describe('Read-Through Cache', () => {
+  let db: EmbeddedDatabase;
+  let clock: ManualClock;
+  let version: number;
+  let loads: number;
+  let failLoads: boolean;
+  let gate: Promise<void>;
+  let refreshErrors: unknown[];
+  let cache: ReadThroughCache<string>;
+
+  beforeEach(() => {
+    native.reset();
+    clock = new ManualClock(1_000_000);
+    db = EmbeddedDatabase.open('read-through-db', { clock });
+    version = 1;
+    loads = 0;
+    failLoads = false;
+    gate = Promise.resolve();
+    refreshErrors = [];
+    cache = new ReadThroughCache<string>(db, 'users', {
+      loader: async (key) => {
+        loads++;
+        await gate;
+        if (failLoads) throw new Error('upstream down');
+        return `${key}@v${version}`;
+      },
+      ttlMs: 1_000,
+      staleWhileRevalidateMs: 500,
+      onRefreshError: (_key, error) => refreshErrors.push(error),
+    });
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('misses load synchronously and are not counted as refreshes', async () => {
+    expect(await cache.get('u1')).toBe('u1@v1');
+    expect(await cache.get('u1')).toBe('u1@v1');
+    expect(cache.stats()).toMatchObject({ misses: 1, hits: 1, staleHits: 0, refreshes: 0 });
+  });
+
+  test('a stale hit serves the old value and refreshes it once in the background', async () => {
+    await cache.get('u1');
+    version = 2;
+    clock.advance(1_200);
+
+    // Hold the refresh so the second read also finds the entry stale
+    let release!: () => void;
+    gate = new Promise((resolve) => (release = resolve));
+    expect(await cache.get('u1')).toBe('u1@v1');
+    expect(await cache.get('u1')).toBe('u1@v1');
+    release();
+    await settle();
+    expect(loads).toBe(2);
+    expect(cache.stats()).toMatchObject({ staleHits: 2, refreshes: 1, refreshErrors: 0 });
+
+    expect(await cache.get('u1')).toBe('u1@v2');
+    expect(cache.stats().hits).toBe(1);
+  });
+
+  test('a failed background refresh is reported and keeps the stale value', async () => {
+    await cache.get('u1');
+    failLoads = true;
+    clock.advance(1_200);
+
+    expect(await cache.get('u1')).toBe('u1@v1');
+    await settle();
+    expect(cache.stats()).toMatchObject({ refreshes: 1, refreshErrors: 1 });
+    expect(refreshErrors).toHaveLength(1);
+    expect(await cache.get('u1')).toBe('u1@v1');
+  });
+
+  test('past the stale window the loader blocks the read', async () => {
+    await cache.get('u1');
+    version = 2;
+    clock.advance(1_600);
+
+    expect(await cache.get('u1')).toBe('u1@v2');
+    expect(cache.stats()).toMatchObject({ misses: 2, refreshes: 0 });
+  });
+});
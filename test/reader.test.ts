@@ -0,0 +1,96 @@
+/**
+ * Tests for reader handles
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Reader Handles', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(async () => {
+    native.reset();
+    native.enableReadOnlyTransactions();
+    db = EmbeddedDatabase.open('reader-db');
+    await db.put('a/1', 'one');
+    await db.put('a/2', 'two');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('reads go through read-only transactions', async () => {
+    const begun: boolean[] = [];
+    const begin = native.sochdb_begin_txn;
+    const beginReadOnly = native.sochdb_begin_txn_readonly;
+    native.sochdb_begin_txn = (...args: unknown[]) => (begun.push(false), begin(...args));
+    native.sochdb_begin_txn_readonly = (...args: unknown[]) => (begun.push(true), beginReadOnly(...args));
+
+    const reader = db.readerHandle();
+    expect((await reader.get('a/1'))?.toString()).toBe('one');
+    const keys: string[] = [];
+    for await (const [key] of reader.scanPrefix('a/')) {
+      keys.push(key.toString());
+    }
+    expect(keys).toEqual(['a/1', 'a/2']);
+    expect(begun).toEqual([true, true]);
+    expect(native.openTxns.size).toBe(0);
+  });
+
+  test('released snapshots are dropped from the handle', async () => {
+    const reader = db.readerHandle();
+    const snap = reader.snapshot();
+    await db.put('a/3', 'three');
+    expect(await reader.get('a/3', { snapshot: snap })).toBeNull();
+
+    snap.release();
+    expect((reader as any).snapshots.size).toBe(0);
+
+    const kept = reader.snapshot();
+    reader.close();
+    expect(kept.isReleased).toBe(true);
+    expect(native.openTxns.size).toBe(0);
+    await expect(reader.get('a/1')).rejects.toBeInstanceOf(DatabaseError);
+  });
+
+  test('closing mid-scan stops the scan', async () => {
+    const reader = db.readerHandle();
+    const scan = async () => {
+      for await (const _ of reader.scanPrefix('a/')) {
+        reader.close();
+      }
+    };
+    await expect(scan()).rejects.toThrow('Reader handle is closed');
+    expect(native.openTxns.size).toBe(0);
+  });
+
+  test('reads report their I/O profile and scans reject profile', async () => {
+    native.sochdb_txn_io_stats = () => ({
+      blocks_read: 2, cache_hits: 1, cache_misses: 1, bytes_from_cache: 10, bytes_from_disk: 20,
+    });
+    const reader = db.readerHandle();
+    const { value, profile } = await reader.get('a/1', { profile: true });
+    expect(value?.toString()).toBe('one');
+    expect(profile.cacheMisses).toBe(1);
+
+    const snap = reader.snapshot();
+    const atSnapshot = await reader.getPath('a/2', { snapshot: snap, profile: true });
+    expect(atSnapshot.value?.toString()).toBe('two');
+    expect(atSnapshot.profile).toEqual(expect.objectContaining({ blocksRead: 0 }));
+    snap.release();
+
+    const scan = async () => {
+      for await (const _ of reader.scanPrefix('a/', { profile: true } as any)) {
+        // unreachable
+      }
+    };
+    await expect(scan()).rejects.toThrow('Scans do not support `profile`');
+    expect(native.openTxns.size).toBe(0);
+    reader.close();
+  });
+});
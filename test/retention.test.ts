@@ -0,0 +1,40 @@
+/**
+ * Tests for retention duration parsing and policy validation
+ */
+
+import { parseDuration, toNativeRetention } from '../src/embedded/retention';
+import { DatabaseError } from '../src/errors';
+
+describe('Retention Durations', () => {
+  test('parses unit suffixes', () => {
+    expect(parseDuration('500ms')).toBe(500);
+    expect(parseDuration('90s')).toBe(90_000);
+    expect(parseDuration('12h')).toBe(12 * 3_600_000);
+    expect(parseDuration('30d')).toBe(30 * 86_400_000);
+    expect(parseDuration('1.5h')).toBe(5_400_000);
+  });
+
+  test('passes numbers through and rejects garbage', () => {
+    expect(parseDuration(1234)).toBe(1234);
+    expect(() => parseDuration(-1)).toThrow();
+    expect(() => parseDuration('30 days')).toThrow();
+    expect(() => parseDuration('d')).toThrow();
+  });
+});
+
+describe('Retention Policies', () => {
+  test('converts policies to native limits, 0 meaning unlimited', () => {
+    expect(toNativeRetention({ maxAge: '30d', maxBytes: 1024 })).toEqual({ maxAgeMs: 30 * 86_400_000, maxBytes: 1024 });
+    expect(toNativeRetention({})).toEqual({ maxAgeMs: 0, maxBytes: 0 });
+  });
+
+  test('rejects a maxAge that rounds to 0ms instead of disabling the age limit', () => {
+    expect(() => toNativeRetention({ maxAge: 0 })).toThrow(DatabaseError);
+    expect(() => toNativeRetention({ maxAge: 0.4 })).toThrow(DatabaseError);
+    expect(() => toNativeRetention({ maxAge: '0.0001s' })).toThrow(DatabaseError);
+  });
+
+  test.each([0, -1, 1.5, NaN])('rejects maxBytes %p', (maxBytes) => {
+    expect(() => toNativeRetention({ maxBytes })).toThrow(DatabaseError);
+  });
+});
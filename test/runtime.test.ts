@@ -0,0 +1,14 @@
+/**
+ * Tests for runtime detection
+ */
+
+import { detectRuntime } from '../src/embedded/runtime';
+
+describe('Runtime Detection', () => {
+  test('identifies the runtime from process.versions', () => {
+    const node = { node: '20.11.0', modules: '115' } as NodeJS.ProcessVersions;
+    expect(detectRuntime(node)).toBe('node');
+    expect(detectRuntime({ ...node, electron: '29.1.0', modules: '121' } as NodeJS.ProcessVersions)).toBe('electron');
+    expect(detectRuntime({ ...node, bun: '1.1.0' } as NodeJS.ProcessVersions)).toBe('bun');
+  });
+});
@@ -0,0 +1,80 @@
+/**
+ * Tests for scoped views
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError, ReadOnlyError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+async function keys(source: { scanPrefix(prefix: string): AsyncGenerator<[Buffer, Buffer]> }, prefix = ''): Promise<string[]> {
+  const out: string[] = [];
+  for await (const [key] of source.scanPrefix(prefix)) {
+    out.push(key.toString());
+  }
+  return out;
+}
+
+describe('Scoped Views', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    native.reset();
+    db = EmbeddedDatabase.open('scope-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('a prefix without a trailing "/" is normalized, so sibling prefixes stay out of reach', async () => {
+    await db.put('tenants/ab/secret', 'x');
+    const tenant = db.scope('tenants/a');
+    expect(tenant.prefix).toBe('tenants/a/');
+
+    await tenant.put('users/1', 'Alice');
+    expect((await db.get('tenants/a/users/1'))?.toString()).toBe('Alice');
+    expect(await keys(tenant)).toEqual(['users/1']);
+    expect(tenant.scope('users').prefix).toBe('tenants/a/users/');
+  });
+
+  test('scoped scans reject a snapshot', async () => {
+    const tenant = db.scope('tenants/a/');
+    const snap = db.snapshot();
+    const scan = async () => {
+      for await (const _ of tenant.scanPrefix('', { snapshot: snap })) {
+        // unreachable
+      }
+    };
+    await expect(scan()).rejects.toThrow(DatabaseError);
+    await expect(scan()).rejects.toThrow('Scoped scans do not support `snapshot`');
+    expect(native.openTxns.size).toBe(1);
+    snap.release();
+  });
+
+  test('read-only scopes reject writes natively and narrower scopes inherit it', async () => {
+    native.enableReadOnlyTransactions();
+    await db.put('plugins/search/config', 'on');
+    const plugin = db.scope('plugins/search', { readOnly: true });
+    expect(plugin.readOnly).toBe(true);
+
+    expect((await plugin.get('config'))?.toString()).toBe('on');
+    await expect(plugin.put('config', 'off')).rejects.toThrow(ReadOnlyError);
+    await expect(plugin.delete('config')).rejects.toThrow(ReadOnlyError);
+
+    const nested = plugin.scope('cache', { readOnly: false });
+    expect(nested.readOnly).toBe(true);
+    await expect(nested.putPath('a', 'b')).rejects.toThrow(ReadOnlyError);
+    expect((await db.get('plugins/search/config'))?.toString()).toBe('on');
+    expect(native.openTxns.size).toBe(0);
+  });
+
+  test('read-only scopes need native read-only transactions', async () => {
+    const plugin = db.scope('plugins/search', { readOnly: true });
+    await expect(plugin.get('config')).rejects.toThrow(
+      'Read-only transactions are not supported by the loaded SochDB native library'
+    );
+  });
+});
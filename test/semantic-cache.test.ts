@@ -0,0 +1,38 @@
+/**
+ * Tests for semantic cache expiry
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { ManualClock } from '../src/embedded/clock';
+import { SemanticCache } from '../src/semantic-cache';
+import { native } from './helpers/mock-native';
+
+describe('Semantic Cache Expiry', () => {
+  let db: EmbeddedDatabase;
+  let clock: ManualClock;
+
+  beforeEach(() => {
+    native.reset();
+    clock = new ManualClock(1_000_000);
+    db = EmbeddedDatabase.open('semantic-cache-db', { clock });
+  });
+
+  afterEach(() => {
+    db.close();
+    jest.restoreAllMocks();
+  });
+
+  test('jitter shortens the TTL by a fraction of its length in seconds', async () => {
+    jest.spyOn(Math, 'random').mockReturnValue(1);
+    const cache = new SemanticCache(db, 'answers', { ttlJitter: 0.5 });
+    await cache.put('q', 'a', [1, 0], 1);
+
+    clock.advance(499);
+    expect(await cache.get([1, 0])).not.toBeNull();
+    clock.advance(2);
+    expect(await cache.get([1, 0])).toBeNull();
+  });
+});
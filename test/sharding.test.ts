@@ -0,0 +1,43 @@
+/**
+ * Tests for client-side sharding
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { shardFor } from '../src/embedded/sharding';
+import { native } from './helpers/mock-native';
+
+describe('shardFor', () => {
+  let calls: Array<[string, number, number]>;
+
+  beforeEach(() => {
+    native.reset();
+    calls = [];
+    native.sochdb_shard_for = (key: Buffer, len: number, shards: number) => {
+      calls.push([key.subarray(0, len).toString(), len, shards]);
+      return key[0] % shards;
+    };
+  });
+
+  test('routes the key bytes through the native hash', () => {
+    expect(shardFor('user/42', 16)).toBe('u'.charCodeAt(0) % 16);
+    expect(shardFor(Buffer.from([7, 1]), 4)).toBe(3);
+    expect(calls[0]).toEqual(['user/42', 7, 16]);
+    expect(calls[1][1]).toBe(2);
+  });
+
+  test('rejects shard counts that are not positive 32-bit integers', () => {
+    for (const shards of [0, -1, 1.5, NaN, 2 ** 32]) {
+      expect(() => shardFor('k', shards)).toThrow(`Shard count must be a positive 32-bit integer, got ${shards}`);
+    }
+    expect(calls).toEqual([]);
+  });
+
+  test('fails clearly when the native library has no consistent hash', () => {
+    delete native.sochdb_shard_for;
+    expect(() => shardFor('k', 2)).toThrow(
+      'Consistent hashing is not supported by the loaded SochDB native library'
+    );
+  });
+});
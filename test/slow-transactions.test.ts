@@ -0,0 +1,55 @@
+/**
+ * Tests for slow transaction reporting
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { SlowTransactionEvent } from '../src/embedded/transaction-stats';
+import { native } from './helpers/mock-native';
+
+describe('Slow Transactions', () => {
+  let warn: jest.SpyInstance;
+
+  beforeEach(() => {
+    jest.useFakeTimers();
+    native.reset();
+    warn = jest.spyOn(console, 'warn').mockImplementation(() => undefined);
+  });
+
+  afterEach(() => {
+    warn.mockRestore();
+    jest.useRealTimers();
+  });
+
+  test('a transaction that never finishes is reported while open, once', async () => {
+    const db = EmbeddedDatabase.open('slow-db', { slowTransactionMs: 100 });
+    const events: SlowTransactionEvent[] = [];
+    db.on('transaction:slow', (event: SlowTransactionEvent) => events.push(event));
+
+    const txn = db.transaction({ label: 'stuck' });
+    jest.advanceTimersByTime(99);
+    expect(events).toEqual([]);
+    jest.advanceTimersByTime(500);
+    expect(events).toEqual([expect.objectContaining({ label: 'stuck', outcome: 'open' })]);
+    expect(warn).toHaveBeenCalledWith(expect.stringContaining("Slow transaction 'stuck' has been open"));
+
+    await txn.abort();
+    expect(events.map((e) => e.outcome)).toEqual(['open', 'aborted']);
+    db.close();
+  });
+
+  test('openConcurrent applies slowTransactionMs too', () => {
+    native.isConcurrentModeAvailable = () => true;
+    native.sochdb_open_concurrent = () => ({ db: true });
+    const db = EmbeddedDatabase.openConcurrent('slow-concurrent-db', { slowTransactionMs: 50 });
+    const events: SlowTransactionEvent[] = [];
+    db.on('transaction:slow', (event: SlowTransactionEvent) => events.push(event));
+
+    db.transaction({ label: 'stuck' });
+    jest.advanceTimersByTime(100);
+    expect(events.map((e) => e.label)).toEqual(['stuck']);
+    db.close();
+  });
+});
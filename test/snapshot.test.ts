@@ -0,0 +1,135 @@
+/**
+ * Tests for transactions started from snapshots
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { Collection } from '../src/namespace';
+import { TransactionError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Snapshot Transactions', () => {
+  let db: EmbeddedDatabase;
+  let beganAt: bigint[];
+
+  beforeEach(async () => {
+    native.reset();
+    beganAt = [];
+    native.sochdb_begin_txn_at = (handle: unknown, ts: bigint) => {
+      beganAt.push(ts);
+      return native.sochdb_begin_txn(handle);
+    };
+    db = EmbeddedDatabase.open('snapshot-db');
+    await db.put('k', 'v');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('begins at the snapshot LSN', async () => {
+    const snap = db.snapshot();
+    const txn = db.transaction({ snapshot: snap });
+    expect(beganAt).toEqual([snap.lsn]);
+    await txn.abort();
+    snap.release();
+  });
+
+  test('rejects a released snapshot', () => {
+    const snap = db.snapshot();
+    snap.release();
+    expect(() => db.transaction({ snapshot: snap })).toThrow('Cannot begin a transaction from a released snapshot');
+    expect(beganAt).toEqual([]);
+  });
+
+  test('only reads made through the transaction are validated at commit', async () => {
+    // Minimal SSI: a commit conflicts when a key it read was committed after its snapshot
+    const reads = new Map<number, Set<string>>();
+    const committedAt = new Map<string, number>();
+    const get = native.sochdb_get;
+    native.sochdb_get = (h: unknown, txn: { txn_id: number }, key: Buffer, klen: number, ...out: any[]) => {
+      if (!reads.has(txn.txn_id)) reads.set(txn.txn_id, new Set());
+      reads.get(txn.txn_id)!.add(key.subarray(0, klen).toString('hex'));
+      return get(h, txn, key, klen, ...out);
+    };
+    const commit = native.sochdb_commit;
+    native.sochdb_commit = (h: unknown, handle: { txn_id: number }) => {
+      const txn = native.openTxns.get(handle.txn_id)!;
+      const stale = [...(reads.get(handle.txn_id) ?? [])].some((key) => (committedAt.get(key) ?? 0) > txn.snapshot_ts);
+      if (stale) {
+        native.openTxns.delete(handle.txn_id);
+        return { commit_ts: 0, error_code: -2 };
+      }
+      const written = [...txn.writes.keys()];
+      const result = commit(h, handle);
+      for (const key of written) committedAt.set(key, result.commit_ts);
+      return result;
+    };
+
+    // Read through the snapshot: not part of the transaction's read set
+    const snap = db.snapshot();
+    const seen = await db.get('k', { snapshot: snap });
+    const txn = db.transaction({ snapshot: snap });
+    await db.put('k', 'changed');
+    await txn.put('copy', seen!);
+    await expect(txn.commit()).resolves.toBeUndefined();
+    snap.release();
+
+    // Read through the transaction: validated
+    const snap2 = db.snapshot();
+    const validated = db.transaction({ snapshot: snap2 });
+    const value = await validated.get('k');
+    await db.put('k', 'changed again');
+    await validated.put('copy', value!);
+    await expect(validated.commit()).rejects.toBeInstanceOf(TransactionError);
+    snap2.release();
+  });
+
+  test('snapshot({ lsn }) pins the state at an earlier LSN', async () => {
+    const lsn = BigInt(native.lsn);
+    await db.put('k', 'later');
+    const snap = db.snapshot({ lsn });
+    expect(beganAt).toEqual([lsn]);
+    snap.release();
+  });
+
+  test('collection search through a snapshot ignores vectors written after it', async () => {
+    // Minimal MVCC: scans in a transaction begun at an LSN see the store as it was then
+    const frozen = new Map<number, Map<string, Buffer>>();
+    native.sochdb_begin_txn_at = (handle: unknown, ts: bigint) => {
+      beganAt.push(ts);
+      const txn = native.sochdb_begin_txn(handle);
+      frozen.set(txn.txn_id, new Map(native.store));
+      return txn;
+    };
+    const scan = native.sochdb_scan_prefix;
+    native.sochdb_scan_prefix = (h: unknown, txn: { txn_id: number }, ...rest: any[]) => {
+      const view = frozen.get(txn.txn_id);
+      if (!view) return scan(h, txn, ...rest);
+      const live = native.store;
+      native.store = view;
+      try {
+        return scan(h, txn, ...rest);
+      } finally {
+        native.store = live;
+      }
+    };
+
+    const docs = new Collection(db, 'ns', 'docs', { name: 'docs', dimension: 2 });
+    await docs.insert([1, 0], { title: 'first' }, 'a');
+    const snap = db.snapshot({ lsn: BigInt(native.lsn) });
+    await docs.insert([0, 1], { title: 'second' }, 'b');
+
+    try {
+      const query = { queryVector: [0, 1], k: 1, includeMetadata: true };
+      expect((await docs.search(query)).map((r) => r.id)).toEqual(['b']);
+      const hits = await docs.search({ ...query, snapshot: snap });
+      expect(hits.map((r) => [r.id, r.metadata])).toEqual([['a', { title: 'first' }]]);
+      expect((await docs.search({ ...query, snapshot: snap })).map((r) => r.id)).toEqual(['a']);
+    } finally {
+      snap.release();
+    }
+  });
+});
@@ -0,0 +1,50 @@
+/**
+ * Tests for commit-derived state tokens
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+describe('State tokens', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(() => {
+    native.reset();
+    db = EmbeddedDatabase.open('token-db');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('changes when a transaction commits, not when one aborts', async () => {
+    native.sochdb_last_committed_lsn = () => BigInt(native.lsn);
+    const initial = db.stateToken();
+    expect(initial).toBe('0000000000000000');
+
+    await db.put('k', 'v');
+    const afterPut = db.stateToken();
+    expect(afterPut).not.toBe(initial);
+
+    const txn = db.transaction();
+    await txn.put('k', 'w');
+    await txn.abort();
+    expect(db.stateToken()).toBe(afterPut);
+  });
+
+  test('sorts in commit order as fixed-width hex', () => {
+    native.sochdb_last_committed_lsn = () => 0xabcdn;
+    expect(db.stateToken()).toBe('000000000000abcd');
+    native.sochdb_last_committed_lsn = () => 0x10000n;
+    expect(db.stateToken() > '000000000000abcd').toBe(true);
+  });
+
+  test('fails clearly when the native library has no commit LSN', () => {
+    expect(() => db.stateToken()).toThrow(
+      'State tokens are not supported by the loaded SochDB native library'
+    );
+  });
+});
@@ -0,0 +1,86 @@
+/**
+ * Tests for per-call timeouts
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { DatabaseError, DurabilityTimeoutError, TimeoutError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+const TIMED_OUT = -7;
+
+describe('Operation Timeouts', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(async () => {
+    native.reset();
+    native.enableDeadlines();
+    db = EmbeddedDatabase.open('timeout-db');
+    await db.put('k', 'v');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('reads, writes and commits past their deadline reject with TimeoutError', async () => {
+    native.failNext('sochdb_get', TIMED_OUT);
+    await expect(db.get('k', { timeoutMs: 50 })).rejects.toMatchObject({ name: 'TimeoutError', timeoutMs: 50 });
+
+    native.failNext('sochdb_delete', TIMED_OUT);
+    await expect(db.delete('k', { timeoutMs: 50 })).rejects.toBeInstanceOf(TimeoutError);
+
+    native.failNext('sochdb_commit', TIMED_OUT);
+    await expect(db.put('k', 'new', { timeoutMs: 50 })).rejects.toThrow('Commit exceeded its 50ms deadline');
+    expect((await db.get('k'))?.toString()).toBe('v');
+  });
+
+  test('scans past their deadline reject with TimeoutError', async () => {
+    const txn = db.transaction({ timeoutMs: 50 });
+    native.failNext('sochdb_iterator_next', TIMED_OUT);
+    const scan = async () => {
+      for await (const _ of txn.scanPrefix('')) {
+        // drain
+      }
+    };
+    await expect(scan()).rejects.toBeInstanceOf(TimeoutError);
+    await txn.abort();
+  });
+
+  test('a snapshot read restores the deadline the snapshot already had', async () => {
+    const snap = db.snapshot();
+    snap.getTransaction().setDeadline(10_000);
+    const [txnId] = [...native.openTxns.keys()];
+
+    await db.get('k', { snapshot: snap, timeoutMs: 5 });
+    expect(native.deadlineOf(txnId)).toBeGreaterThan(9_000);
+
+    snap.getTransaction().setDeadline(0);
+    await db.get('k', { snapshot: snap, timeoutMs: 5 });
+    expect(native.deadlineOf(txnId)).toBe(0);
+    snap.release();
+  });
+
+  test('a commit that is applied but not yet durable is reported distinctly', async () => {
+    native.enableAckLevels(() => undefined);
+    const txn = db.transaction();
+    const ran: string[] = [];
+    txn.onCommit(() => void ran.push('commit'));
+    txn.onRollback(() => void ran.push('rollback'));
+    await txn.put('k', 'durable?');
+
+    const commit = txn.commit({ ack: 'fsynced', timeoutMs: 20 });
+    await expect(commit).rejects.toBeInstanceOf(DurabilityTimeoutError);
+    await expect(commit).rejects.not.toBeInstanceOf(TimeoutError);
+    expect(ran).toEqual(['rollback']);
+    expect((await db.get('k'))?.toString()).toBe('durable?');
+  });
+
+  test('timeouts require native support', async () => {
+    delete native.sochdb_txn_set_deadline;
+    await expect(db.get('k', { timeoutMs: 50 })).rejects.toBeInstanceOf(DatabaseError);
+    expect(native.openTxns.size).toBe(0);
+  });
+});
@@ -0,0 +1,44 @@
+/**
+ * Tests for per-label transaction stats
+ */
+
+import { TransactionStatsRegistry, UNLABELED } from '../src/embedded/transaction-stats';
+
+describe('Transaction Stats Registry', () => {
+  test('aggregates outcomes and durations by label', () => {
+    const registry = new TransactionStatsRegistry();
+    registry.record('checkout', 'committed', 10, false);
+    registry.record('checkout', 'conflict', 30, true);
+    registry.record('checkout', 'aborted', 5, false);
+    registry.record(undefined, 'committed', 2, false);
+
+    const [checkout, unlabeled] = registry.snapshot();
+    expect(checkout).toEqual({
+      label: 'checkout',
+      committed: 1,
+      conflicts: 1,
+      failed: 0,
+      aborted: 1,
+      slow: 1,
+      totalDurationMs: 45,
+      maxDurationMs: 30,
+    });
+    expect(unlabeled.label).toBe(UNLABELED);
+    expect(unlabeled.committed).toBe(1);
+  });
+
+  test('orders labels by conflicts and returns copies', () => {
+    const registry = new TransactionStatsRegistry();
+    registry.record('a', 'committed', 1, false);
+    registry.record('b', 'conflict', 1, false);
+
+    const stats = registry.snapshot();
+    expect(stats.map((s) => s.label)).toEqual(['b', 'a']);
+
+    stats[0].conflicts = 99;
+    expect(registry.snapshot()[0].conflicts).toBe(1);
+
+    registry.reset();
+    expect(registry.snapshot()).toEqual([]);
+  });
+});
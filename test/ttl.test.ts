@@ -0,0 +1,43 @@
+/**
+ * Tests for per-key TTLs
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { ManualClock } from '../src/embedded/clock';
+import { DatabaseError } from '../src/errors';
+import { native } from './helpers/mock-native';
+
+describe('Key Expiry', () => {
+  let db: EmbeddedDatabase;
+  let deadlines: Map<string, bigint>;
+
+  beforeEach(() => {
+    native.reset();
+    deadlines = new Map();
+    native.sochdb_expire = (_db: unknown, _txn: unknown, key: Buffer, klen: number, expiresAt: bigint) => {
+      deadlines.set(key.subarray(0, klen).toString(), expiresAt);
+      return 0;
+    };
+    native.sochdb_touch_prefix = () => 0;
+    db = EmbeddedDatabase.open('ttl-db', { clock: new ManualClock(1_000) });
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('sets the deadline from the database clock', async () => {
+    await db.put('session/a', 'v', { ttlMs: 500 });
+    expect(deadlines.get('session/a')).toBe(1_500n);
+  });
+
+  test.each([NaN, -1, 1.5, Infinity])('rejects ttlMs %p without writing', async (ttlMs) => {
+    await expect(db.put('session/a', 'v', { ttlMs })).rejects.toBeInstanceOf(DatabaseError);
+    await expect(db.touchPrefix('session/', ttlMs)).rejects.toBeInstanceOf(DatabaseError);
+    expect(await db.get('session/a')).toBeNull();
+    expect(deadlines.size).toBe(0);
+  });
+});
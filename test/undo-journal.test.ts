@@ -0,0 +1,136 @@
+/**
+ * Tests for the admin undo journal
+ */
+
+jest.mock('koffi', () => require('./helpers/mock-native').koffi);
+jest.mock('../src/embedded/ffi/bindings', () => require('./helpers/mock-native').bindingsModule);
+
+import { ManualClock } from '../src/embedded/clock';
+import { EmbeddedDatabase } from '../src/embedded/database';
+import { native } from './helpers/mock-native';
+
+async function keys(db: EmbeddedDatabase, prefix = ''): Promise<string[]> {
+  const out: string[] = [];
+  for await (const [key] of db.scanPrefix(prefix)) {
+    out.push(key.toString());
+  }
+  return out;
+}
+
+describe('Undo Journal', () => {
+  let db: EmbeddedDatabase;
+
+  beforeEach(async () => {
+    native.reset();
+    db = EmbeddedDatabase.open('undo-db', { undoJournal: true });
+    await db.put('a/1', 'one');
+    await db.put('a/2', 'two');
+    await db.put('b/1', 'uno');
+  });
+
+  afterEach(() => {
+    db.close();
+  });
+
+  test('undoes a successful prefix delete and hides the journal from scans', async () => {
+    await db.deletePrefix('a/');
+    expect(await keys(db)).toEqual(['b/1']);
+
+    const undone = await db.undoLastAdminOp();
+    expect(undone?.op).toBe('deletePrefix');
+    expect(undone?.restored).toBe(2);
+    expect(await keys(db)).toEqual(['a/1', 'a/2', 'b/1']);
+    expect((await db.get('a/2'))?.toString()).toBe('two');
+    expect(await db.undoLastAdminOp()).toBeNull();
+  });
+
+  test('an aborted operation leaves no journal entry behind', async () => {
+    await db.deletePrefix('a/');
+
+    native.failNext('sochdb_delete', -1);
+    await expect(db.deletePrefix('b/')).rejects.toThrow('Failed to delete value');
+    expect(await keys(db)).toEqual(['b/1']);
+
+    // The aborted delete is not the last op; the successful one is
+    const undone = await db.undoLastAdminOp();
+    expect(undone?.details).toEqual({ prefix: Buffer.from('a/').toString('base64') });
+    expect(await keys(db)).toEqual(['a/1', 'a/2', 'b/1']);
+  });
+
+  test('deleting every key keeps the journal so the delete can be undone', async () => {
+    await db.deletePrefix('a/');
+    const result = await db.deletePrefix('');
+    expect(result.count).toBe(1);
+    expect(await keys(db)).toEqual([]);
+    expect(await keys(db, '_admin/undo/')).not.toEqual([]);
+
+    await db.undoLastAdminOp();
+    expect(await keys(db)).toEqual(['b/1']);
+    await db.undoLastAdminOp();
+    expect(await keys(db)).toEqual(['a/1', 'a/2', 'b/1']);
+  });
+
+  test('refuses to undo over keys changed since the operation, unless forced', async () => {
+    await db.deletePrefix('a/');
+    await db.put('a/1', 'rewritten');
+
+    await expect(db.undoLastAdminOp()).rejects.toThrow(
+      'Cannot undo deletePrefix: 1 key(s) changed since it ran (a/1)'
+    );
+    expect((await db.get('a/1'))?.toString()).toBe('rewritten');
+    expect(await keys(db)).toEqual(['a/1', 'b/1']);
+
+    const undone = await db.undoLastAdminOp({ force: true });
+    expect(undone).toEqual(expect.objectContaining({ restored: 2, diverged: 1 }));
+    expect((await db.get('a/1'))?.toString()).toBe('one');
+  });
+
+  test('restores in transactions of batchSize keys', async () => {
+    await db.deletePrefix('a/');
+    const commit = native.sochdb_commit;
+    let commits = 0;
+    native.sochdb_commit = (...args: any[]) => {
+      commits++;
+      return commit(...args);
+    };
+
+    expect((await db.undoLastAdminOp({ batchSize: 1 }))?.restored).toBe(2);
+    // One transaction per key, then one that finds nothing left and drops the op
+    expect(commits).toBe(3);
+    expect(await keys(db)).toEqual(['a/1', 'a/2', 'b/1']);
+    await expect(db.undoLastAdminOp({ batchSize: 0 })).rejects.toThrow('batchSize must be a positive integer, got 0');
+  });
+
+  test('op ids follow a persisted sequence, not the clock', async () => {
+    const clock = new ManualClock(5_000);
+    const local = EmbeddedDatabase.open('undo-seq-db', { undoJournal: true, clock });
+    await local.put('x', '1');
+    await local.deletePrefix('x');
+    clock.set(1_000);
+    await local.put('x', '2');
+    await local.deletePrefix('x');
+
+    expect(await keys(local, '_admin/undo/ops/')).toEqual([
+      '_admin/undo/ops/0000000000000001',
+      '_admin/undo/ops/0000000000000002',
+    ]);
+    // The newest op is undone first even though its clock reading is older
+    await local.undoLastAdminOp();
+    expect((await local.get('x'))?.toString()).toBe('2');
+    local.close();
+  });
+
+  test('pruning drops expired operations and keeps live ones', async () => {
+    const clock = new ManualClock(0);
+    const local = EmbeddedDatabase.open('undo-prune-db', { undoJournal: { retentionMs: 1_000 }, clock });
+    await local.put('x', '1');
+    await local.deletePrefix('x');
+    clock.set(5_000);
+    await local.put('y', '1');
+    await local.deletePrefix('y');
+
+    expect(await keys(local, '_admin/undo/ops/')).toEqual(['_admin/undo/ops/0000000000000002']);
+    expect(await keys(local, '_admin/undo/e/')).toEqual([`_admin/undo/e/0000000000000002/${Buffer.from('y').toString('hex')}`]);
+    local.close();
+  });
+});